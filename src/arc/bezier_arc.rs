@@ -0,0 +1,69 @@
+use crate::bezier::*;
+use crate::bezier::path::*;
+
+use std::f64;
+
+///
+/// Approximates an arc using the specified number of bezier curves
+///
+/// `point_at_angle` returns the point at an angle around the arc, and `derivative_at_angle` returns the rate that the point
+/// changes with respect to the angle (for a circle, this is the unit tangent multiplied by the radius). Each curve covers
+/// an equal part of the arc, and the last curve ends exactly at `end_angle`.
+///
+pub (crate) fn arc_bezier_curves<Curve: BezierCurveFactory>(start_angle: f64, end_angle: f64, num_curves: usize, point_at_angle: impl Fn(f64) -> Curve::Point, derivative_at_angle: impl Fn(f64) -> Curve::Point) -> Vec<Curve> {
+    let curve_angle = (end_angle - start_angle) / (num_curves as f64);
+    let k           = 4.0/3.0 * (curve_angle/4.0).tan();
+
+    (0..num_curves)
+        .map(|curve_num| {
+            let angle1 = start_angle + curve_angle*(curve_num as f64);
+            let angle2 = if curve_num == num_curves-1 { end_angle } else { angle1 + curve_angle };
+
+            let (p1, p2) = (point_at_angle(angle1), point_at_angle(angle2));
+            let (d1, d2) = (derivative_at_angle(angle1), derivative_at_angle(angle2));
+
+            Curve::from_points(p1, (p1 + d1*k, p2 - d2*k), p2)
+        })
+        .collect()
+}
+
+///
+/// Returns the number of curves needed to approximate an arc with one curve for every 90 degrees
+///
+pub (crate) fn arc_quarter_curve_count(sweep: f64) -> usize {
+    ((sweep.abs() / (f64::consts::PI/2.0) - 1e-9).ceil() as usize).max(1)
+}
+
+///
+/// Returns the number of curves needed to approximate an arc so that it's within `max_error` of the true arc, where
+/// `max_radius` is the largest radius of the arc
+///
+/// Each curve covers at most 90 degrees of the arc.
+///
+pub (crate) fn arc_curve_count(sweep: f64, max_radius: f64, max_error: f64) -> usize {
+    // The error for approximating a circular arc of angle theta with a cubic curve is r * 2/27 * sin^6(theta/4) / cos^2(theta/4)
+    let sweep           = sweep.abs();
+    let curve_error     = |num_curves: usize| {
+        let quarter_angle = sweep / (num_curves as f64) / 4.0;
+        max_radius.abs() * 2.0/27.0 * quarter_angle.sin().powi(6) / quarter_angle.cos().powi(2)
+    };
+
+    let mut num_curves  = arc_quarter_curve_count(sweep);
+    while curve_error(num_curves) > max_error && num_curves < 1024 {
+        num_curves += 1;
+    }
+
+    num_curves
+}
+
+///
+/// Creates a path from the curves approximating an arc
+///
+pub (crate) fn arc_path<P: BezierPathFactory>(curves: Vec<Curve<P::Point>>) -> P {
+    P::from_points(curves[0].start_point(), curves.into_iter().map(|curve| {
+        let (cp1, cp2)  = curve.control_points();
+        let end_point   = curve.end_point();
+
+        (cp1, cp2, end_point)
+    }))
+}
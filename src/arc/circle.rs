@@ -1,13 +1,14 @@
+use super::ellipse::*;
+use super::super::line::*;
 use super::super::bezier::*;
 use super::super::bezier::path::*;
+use super::bezier_arc::*;
 
 use std::f64;
-
-// TODO: would be nice to support generic coordinates here, though 2D circles
-// are a lot simpler mathematically.
+use std::marker::PhantomData;
 
 ///
-/// Represents a circle in 2 dimensions
+/// Represents a circle in 2 dimensions (see `Circle3` for circles in 3 dimensions)
 /// 
 #[derive(Clone, Copy)]
 pub struct Circle<Coord: Coordinate2D+Coordinate> {
@@ -21,16 +22,23 @@ pub struct Circle<Coord: Coordinate2D+Coordinate> {
 ///
 /// Represents an arc of a circle in 2 dimensions
 /// 
+/// Angles are measured in radians clockwise from the positive y axis, so the point at angle `a` is
+/// `center + radius * (sin(a), cos(a))`. The arc goes from its start angle to its end angle, so it's clockwise if the
+/// end angle is greater than the start angle and anticlockwise otherwise.
+///
 #[derive(Clone, Copy)]
 pub struct CircularArc<'a, Coord: 'a+Coordinate2D+Coordinate> {
     /// The circle that this is an arc of
-    circle: &'a Circle<Coord>,
+    circle: Circle<Coord>,
 
     /// The start point of this arc, in radians
     start_radians: f64,
 
     /// The end point of this arc, in radians
-    end_radians: f64
+    end_radians: f64,
+
+    /// Arcs used to borrow their circle: the lifetime is kept so that existing type signatures remain valid
+    lifetime: PhantomData<&'a Circle<Coord>>
 }
 
 impl<Coord: Coordinate2D+Coordinate> Circle<Coord> {
@@ -49,9 +57,10 @@ impl<Coord: Coordinate2D+Coordinate> Circle<Coord> {
     /// 
     pub fn arc(&self, start_radians: f64, end_radians: f64) -> CircularArc<'_, Coord> {
         CircularArc {
-            circle:         self,
+            circle:         *self,
             start_radians:  start_radians,
-            end_radians:    end_radians
+            end_radians:    end_radians,
+            lifetime:       PhantomData
         }
     }
 
@@ -78,18 +87,303 @@ impl<Coord: Coordinate2D+Coordinate> Circle<Coord> {
     /// Returns a path that approximates this circle
     /// 
     pub fn to_path<P: BezierPathFactory<Point=Coord>>(&self) -> P {
-        let curves = self.to_curves::<Curve<_>>();
-
-        P::from_points(curves[0].start_point(), curves.into_iter().map(|curve| {
-            let (cp1, cp2)  = curve.control_points();
-            let end_point   = curve.end_point();
-
-            (cp1, cp2, end_point)
-        }))
+        arc_path(self.to_curves::<Curve<_>>())
     }
 }
 
 impl<'a, Coord: Coordinate2D+Coordinate> CircularArc<'a, Coord> {
+    ///
+    /// Creates an arc on a circle
+    ///
+    fn from_circle(circle: Circle<Coord>, start_radians: f64, end_radians: f64) -> CircularArc<'a, Coord> {
+        CircularArc {
+            circle:         circle,
+            start_radians:  start_radians,
+            end_radians:    end_radians,
+            lifetime:       PhantomData
+        }
+    }
+
+    ///
+    /// The angle of a point relative to a center point (clockwise from the positive y axis)
+    ///
+    #[inline]
+    fn angle_of(center: Coord, point: Coord) -> f64 {
+        (point.x() - center.x()).atan2(point.y() - center.y())
+    }
+
+    ///
+    /// Creates an arc that starts at `start`, passes through `mid` and ends at `end`
+    ///
+    /// Returns `None` if the three points are on a straight line.
+    ///
+    pub fn through_points(start: Coord, mid: Coord, end: Coord) -> Option<CircularArc<'a, Coord>> {
+        // Find the circumcenter of the three points
+        let (ax, ay)    = (start.x(), start.y());
+        let (bx, by)    = (mid.x(), mid.y());
+        let (cx, cy)    = (end.x(), end.y());
+
+        let d           = 2.0 * (ax*(by - cy) + bx*(cy - ay) + cx*(ay - by));
+        let scale       = (bx-ax).abs().max((by-ay).abs()).max((cx-ax).abs()).max((cy-ay).abs());
+        if d.abs() <= 1e-12 * scale * scale {
+            return None;
+        }
+
+        let (a_sq, b_sq, c_sq) = (ax*ax + ay*ay, bx*bx + by*by, cx*cx + cy*cy);
+        let center      = Coord::from_components(&[
+            (a_sq*(by - cy) + b_sq*(cy - ay) + c_sq*(ay - by)) / d,
+            (a_sq*(cx - bx) + b_sq*(ax - cx) + c_sq*(bx - ax)) / d,
+        ]);
+        let radius      = center.distance_to(&start);
+
+        // Go around the circle in whichever direction passes through the middle point
+        let start_angle = Self::angle_of(center, start);
+        let sweep_cw    = (Self::angle_of(center, end) - start_angle).rem_euclid(2.0*f64::consts::PI);
+        let mid_cw      = (Self::angle_of(center, mid) - start_angle).rem_euclid(2.0*f64::consts::PI);
+        let end_angle   = if mid_cw <= sweep_cw { start_angle + sweep_cw } else { start_angle - (2.0*f64::consts::PI - sweep_cw) };
+
+        Some(Self::from_circle(Circle::new(center, radius), start_angle, end_angle))
+    }
+
+    ///
+    /// Creates an arc that starts at `start` heading in the direction of `tangent` and ends at `end`
+    ///
+    /// Returns `None` if the end point is directly ahead of or behind the start point, or if the tangent is 0.
+    ///
+    pub fn from_start_tangent_end(start: Coord, tangent: Coord, end: Coord) -> Option<CircularArc<'a, Coord>> {
+        let tangent_length  = tangent.magnitude();
+        if tangent_length == 0.0 {
+            return None;
+        }
+
+        // The center is on the normal at the start point, the same distance from the start and the end
+        let (tx, ty)        = (tangent.x() / tangent_length, tangent.y() / tangent_length);
+        let (nx, ny)        = (-ty, tx);
+        let (dx, dy)        = (end.x() - start.x(), end.y() - start.y());
+        let chord_sq        = dx*dx + dy*dy;
+        let chord_dot_n     = dx*nx + dy*ny;
+
+        if chord_dot_n.abs() <= 1e-12 * chord_sq.sqrt() {
+            return None;
+        }
+
+        let offset          = chord_sq / (2.0 * chord_dot_n);
+        let center          = Coord::from_components(&[start.x() + nx*offset, start.y() + ny*offset]);
+        let radius          = offset.abs();
+
+        // Increasing angles go clockwise: the arc goes in that direction if the tangent points that way at the start
+        let start_angle     = Self::angle_of(center, start);
+        let end_angle       = Self::angle_of(center, end);
+        let clockwise       = tx*start_angle.cos() - ty*start_angle.sin() > 0.0;
+        let end_angle       = if clockwise {
+            start_angle + (end_angle - start_angle).rem_euclid(2.0*f64::consts::PI)
+        } else {
+            start_angle - (start_angle - end_angle).rem_euclid(2.0*f64::consts::PI)
+        };
+
+        Some(Self::from_circle(Circle::new(center, radius), start_angle, end_angle))
+    }
+
+    ///
+    /// Creates an arc of the specified radius that rounds off the corner where two lines meet
+    ///
+    /// `line_in` is the line leading into the corner and `line_out` is the line leading away from it: the arc starts
+    /// on `line_in`, ends on `line_out` and is tangent to both. The lines are treated as extending infinitely, so the
+    /// points where the arc touches them may be beyond their ends. Returns `None` if the lines are parallel or the
+    /// radius is not positive.
+    ///
+    pub fn fillet<L1: Line<Point=Coord>, L2: Line<Point=Coord>>(line_in: &L1, line_out: &L2, radius: f64) -> Option<CircularArc<'a, Coord>> {
+        if radius <= 0.0 {
+            return None;
+        }
+
+        // Find the corner where the lines meet
+        let (in_start, in_end)      = line_in.points();
+        let (out_start, out_end)    = line_out.points();
+        let corner                  = ray_intersects_ray(&(in_start, in_end), &(out_start, out_end))?;
+
+        // Unit vectors from the corner back along the incoming line and forward along the outgoing line
+        let back        = (in_start - in_end).to_unit_vector();
+        let forward     = (out_end - out_start).to_unit_vector();
+        let angle       = back.dot(&forward).clamp(-1.0, 1.0).acos();
+
+        if angle <= 1e-9 || angle >= f64::consts::PI - 1e-9 {
+            return None;
+        }
+
+        // The arc touches the lines at the same distance from the corner on each side
+        let tangent_distance    = radius / (angle/2.0).tan();
+        let start               = corner + back * tangent_distance;
+        let end                 = corner + forward * tangent_distance;
+
+        Self::from_start_tangent_end(start, back * -1.0, end)
+    }
+
+    ///
+    /// The circle that this is an arc of
+    ///
+    pub fn circle(&self) -> &Circle<Coord> {
+        &self.circle
+    }
+
+    ///
+    /// The angle where this arc starts
+    ///
+    pub fn start_radians(&self) -> f64 {
+        self.start_radians
+    }
+
+    ///
+    /// The angle where this arc ends
+    ///
+    pub fn end_radians(&self) -> f64 {
+        self.end_radians
+    }
+
+    ///
+    /// The angle covered by this arc (negative if the arc goes anticlockwise)
+    ///
+    pub fn sweep_radians(&self) -> f64 {
+        self.end_radians - self.start_radians
+    }
+
+    ///
+    /// The point where this arc starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        self.point_at_angle(self.start_radians)
+    }
+
+    ///
+    /// The point where this arc ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        self.point_at_angle(self.end_radians)
+    }
+
+    ///
+    /// The length of this arc
+    ///
+    pub fn length(&self) -> f64 {
+        self.circle.radius * self.sweep_radians().abs()
+    }
+
+    ///
+    /// Returns this arc as an elliptical arc (whose ellipse has the same radius along both axes)
+    ///
+    pub fn to_elliptical_arc(&self) -> EllipticalArc<Coord> {
+        Ellipse::new(self.circle.center, self.circle.radius, self.circle.radius).arc(self.start_radians, self.end_radians)
+    }
+
+    ///
+    /// Returns the point at the specified angle around the circle
+    ///
+    pub fn point_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos) = angle.sin_cos();
+
+        Coord::from_components(&[self.circle.center.x() + self.circle.radius*sin, self.circle.center.y() + self.circle.radius*cos])
+    }
+
+    ///
+    /// Returns the unit tangent at the specified angle around the circle, pointing in the direction of this arc
+    ///
+    pub fn tangent_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos) = angle.sin_cos();
+
+        if self.end_radians < self.start_radians {
+            Coord::from_components(&[-cos, sin])
+        } else {
+            Coord::from_components(&[cos, -sin])
+        }
+    }
+
+    ///
+    /// True if the specified angle around the circle is part of this arc
+    ///
+    pub fn contains_angle(&self, angle: f64) -> bool {
+        self.to_elliptical_arc().contains_angle(angle)
+    }
+
+    ///
+    /// Returns the bounding box of this arc
+    ///
+    pub fn bounding_box<Bounds: BoundingBox<Point=Coord>>(&self) -> Bounds {
+        self.to_elliptical_arc().bounding_box()
+    }
+
+    ///
+    /// Finds the points where a line (extended infinitely in both directions) crosses this arc
+    ///
+    /// The result is a list of `(angle, t, point)`, where `angle` is in the range covered by the arc and `t` is the
+    /// position along the line.
+    ///
+    pub fn intersects_ray<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        self.to_elliptical_arc().intersects_ray(line)
+    }
+
+    ///
+    /// Finds the points where a line segment crosses this arc
+    ///
+    /// The result is a list of `(angle, t, point)`, where `angle` is in the range covered by the arc and `t` is the
+    /// position along the line (between 0 and 1).
+    ///
+    pub fn intersects_line<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        self.to_elliptical_arc().intersects_line(line)
+    }
+
+    ///
+    /// Finds the points where this arc crosses another arc
+    ///
+    /// The result is a list of `(angle, other_angle, point)`, where the angles are in the ranges covered by each arc. Arcs
+    /// on the same circle overlap rather than intersect, so they produce no results.
+    ///
+    pub fn intersects_arc(&self, other: &CircularArc<'_, Coord>) -> Vec<(f64, f64, Coord)> {
+        let (c1, r1)    = (self.circle.center, self.circle.radius);
+        let (c2, r2)    = (other.circle.center, other.circle.radius);
+        let (dx, dy)    = (c2.x() - c1.x(), c2.y() - c1.y());
+        let distance    = (dx*dx + dy*dy).sqrt();
+
+        // Circles that are too far apart or inside each other don't intersect
+        let tolerance   = 1e-9 * (r1 + r2);
+        if distance <= tolerance || distance > r1 + r2 + tolerance || distance < (r1 - r2).abs() - tolerance {
+            return vec![];
+        }
+
+        // The intersections are on the line perpendicular to the line between the centers
+        let along       = (r1*r1 - r2*r2 + distance*distance) / (2.0*distance);
+        let across_sq   = r1*r1 - along*along;
+        let (ux, uy)    = (dx / distance, dy / distance);
+        let (mx, my)    = (c1.x() + ux*along, c1.y() + uy*along);
+
+        let points      = if across_sq <= tolerance * r1 {
+            vec![Coord::from_components(&[mx, my])]
+        } else {
+            let across = across_sq.sqrt();
+            vec![Coord::from_components(&[mx - uy*across, my + ux*across]), Coord::from_components(&[mx + uy*across, my - ux*across])]
+        };
+
+        let arc1        = self.to_elliptical_arc();
+        let arc2        = other.to_elliptical_arc();
+
+        points.into_iter()
+            .filter_map(|point| {
+                let angle1 = arc1.arc_angle(Self::angle_of(c1, point))?;
+                let angle2 = arc2.arc_angle(Self::angle_of(c2, point))?;
+
+                Some((angle1, angle2, point))
+            })
+            .collect()
+    }
+
+    ///
+    /// Converts this arc to a set of bezier curves that are within `max_error` of the true arc
+    ///
+    /// Unlike `to_bezier_curve()`, this can be used for arcs of any size.
+    ///
+    pub fn to_bezier_curves<Curve: BezierCurveFactory<Point=Coord>>(&self, max_error: f64) -> Vec<Curve> {
+        self.to_elliptical_arc().to_bezier_curves(max_error)
+    }
+
     ///
     /// Converts this arc to a bezier curve
     /// 
@@ -147,6 +441,7 @@ impl<'a, Coord: Coordinate2D+Coordinate> CircularArc<'a, Coord> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::arc::test_util::*;
     use std::f64;
 
     #[test]
@@ -184,4 +479,168 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn arc_through_three_points() {
+        let arc = CircularArc::through_points(Coord2(0.0, 1.0), Coord2(1.0, 0.0), Coord2(0.0, -1.0)).unwrap();
+
+        assert_near(arc.circle().center, Coord2(0.0, 0.0));
+        assert!((arc.circle().radius - 1.0).abs() < 1e-9);
+        assert_near(arc.start_point(), Coord2(0.0, 1.0));
+        assert_near(arc.end_point(), Coord2(0.0, -1.0));
+
+        // Passes through the right-hand side, so goes clockwise
+        assert!((arc.sweep_radians() - f64::consts::PI).abs() < 1e-9);
+        assert!((arc.length() - f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_through_three_points_anticlockwise() {
+        let arc = CircularArc::through_points(Coord2(0.0, 1.0), Coord2(-1.0, 0.0), Coord2(1.0, 0.0)).unwrap();
+
+        assert!((arc.sweep_radians() + 3.0*f64::consts::PI/2.0).abs() < 1e-9);
+        assert!(arc.contains_angle(-f64::consts::PI/2.0));
+        assert!(!arc.contains_angle(f64::consts::PI/4.0));
+        assert_near(arc.end_point(), Coord2(1.0, 0.0));
+    }
+
+    #[test]
+    fn collinear_points_make_no_arc() {
+        assert!(CircularArc::through_points(Coord2(0.0, 0.0), Coord2(1.0, 1.0), Coord2(2.0, 2.0)).is_none());
+    }
+
+    #[test]
+    fn arc_from_start_tangent_end() {
+        // Heading right from (0, 0), ending at (1, -1): quarter circle turning clockwise
+        let arc = CircularArc::from_start_tangent_end(Coord2(0.0, 0.0), Coord2(2.0, 0.0), Coord2(1.0, -1.0)).unwrap();
+
+        assert_near(arc.circle().center, Coord2(0.0, -1.0));
+        assert!((arc.sweep_radians() - f64::consts::PI/2.0).abs() < 1e-9);
+        assert_near(arc.tangent_at_angle(arc.start_radians()), Coord2(1.0, 0.0));
+
+        // Turning anticlockwise, and going more than halfway around
+        let arc = CircularArc::from_start_tangent_end(Coord2(0.0, 0.0), Coord2(1.0, 0.0), Coord2(-1.0, 1.0)).unwrap();
+
+        assert_near(arc.circle().center, Coord2(0.0, 1.0));
+        assert!((arc.sweep_radians() + 3.0*f64::consts::PI/2.0).abs() < 1e-9);
+        assert_near(arc.tangent_at_angle(arc.start_radians()), Coord2(1.0, 0.0));
+        assert_near(arc.end_point(), Coord2(-1.0, 1.0));
+
+        assert!(CircularArc::from_start_tangent_end(Coord2(0.0, 0.0), Coord2(1.0, 0.0), Coord2(5.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn fillet_right_angle() {
+        // Going right along the x axis then up along x=10
+        let arc = CircularArc::fillet(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(10.0, 0.0), Coord2(10.0, 10.0)), 2.0).unwrap();
+
+        assert_near(arc.circle().center, Coord2(8.0, 2.0));
+        assert_near(arc.start_point(), Coord2(8.0, 0.0));
+        assert_near(arc.end_point(), Coord2(10.0, 2.0));
+        assert!((arc.sweep_radians().abs() - f64::consts::PI/2.0).abs() < 1e-9);
+        assert_near(arc.tangent_at_angle(arc.start_radians()), Coord2(1.0, 0.0));
+        assert_near(arc.tangent_at_angle(arc.end_radians()), Coord2(0.0, 1.0));
+    }
+
+    #[test]
+    fn fillet_acute_angle() {
+        let arc     = CircularArc::fillet(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(10.0, 0.0), Coord2(0.0, 5.0)), 1.0).unwrap();
+        let line2   = (Coord2(10.0, 0.0), Coord2(0.0, 5.0));
+
+        assert!((arc.start_point().y()).abs() < 1e-9);
+        assert!((line2.distance_to(&arc.end_point())).abs() < 1e-9);
+        assert!((line2.distance_to(&arc.circle().center) - 1.0).abs() < 1e-9);
+        assert!((arc.circle().center.y() - 1.0).abs() < 1e-9);
+
+        assert!(CircularArc::fillet(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(10.0, 0.0), Coord2(20.0, 0.0)), 1.0).is_none());
+    }
+
+    #[test]
+    fn arc_points_and_tangents() {
+        let circle  = Circle::new(Coord2(1.0, 1.0), 2.0);
+        let arc     = circle.arc(0.0, f64::consts::PI/2.0);
+
+        assert_near(arc.point_at_angle(0.0), Coord2(1.0, 3.0));
+        assert_near(arc.tangent_at_angle(0.0), Coord2(1.0, 0.0));
+        assert_near(circle.arc(0.0, -1.0).tangent_at_angle(0.0), Coord2(-1.0, 0.0));
+        assert!((arc.length() - f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_bounding_box() {
+        let circle              = Circle::new(Coord2(0.0, 0.0), 1.0);
+        let bounds: Bounds<_>   = circle.arc(-f64::consts::PI/4.0, f64::consts::PI/4.0).bounding_box();
+        let half                = (0.5f64).sqrt();
+
+        assert_near(bounds.min(), Coord2(-half, half));
+        assert_near(bounds.max(), Coord2(half, 1.0));
+    }
+
+    #[test]
+    fn arc_line_intersection() {
+        let circle          = Circle::new(Coord2(0.0, 0.0), 5.0);
+        let arc             = circle.arc(0.0, f64::consts::PI);
+        let intersections   = arc.intersects_line(&(Coord2(-10.0, 3.0), Coord2(10.0, 3.0)));
+
+        // Only the right-hand half of the circle is in the arc
+        assert!(intersections.len() == 1);
+        assert_near(intersections[0].2, Coord2(4.0, 3.0));
+    }
+
+    #[test]
+    fn arc_arc_intersection() {
+        let circle1         = Circle::new(Coord2(0.0, 0.0), 5.0);
+        let circle2         = Circle::new(Coord2(8.0, 0.0), 5.0);
+        let whole1          = circle1.arc(0.0, 2.0*f64::consts::PI);
+        let whole2          = circle2.arc(0.0, 2.0*f64::consts::PI);
+        let intersections   = whole1.intersects_arc(&whole2);
+
+        assert!(intersections.len() == 2);
+        for (angle1, angle2, point) in intersections.iter() {
+            assert!((point.x() - 4.0).abs() < 1e-9);
+            assert!((point.y().abs() - 3.0).abs() < 1e-9);
+            assert_near(whole1.point_at_angle(*angle1), *point);
+            assert_near(whole2.point_at_angle(*angle2), *point);
+        }
+
+        // Top half only
+        let top1 = circle1.arc(-f64::consts::PI/2.0, f64::consts::PI/2.0);
+        let top2 = circle2.arc(-f64::consts::PI/2.0, f64::consts::PI/2.0);
+        let intersections = top1.intersects_arc(&top2);
+
+        assert!(intersections.len() == 1);
+        assert_near(intersections[0].2, Coord2(4.0, 3.0));
+    }
+
+    #[test]
+    fn tangent_and_separate_arcs() {
+        let whole1  = Circle::new(Coord2(0.0, 0.0), 5.0);
+        let whole2  = Circle::new(Coord2(10.0, 0.0), 5.0);
+        let whole3  = Circle::new(Coord2(20.0, 0.0), 5.0);
+        let arc1    = whole1.arc(0.0, 2.0*f64::consts::PI);
+        let arc2    = whole2.arc(0.0, 2.0*f64::consts::PI);
+        let arc3    = whole3.arc(0.0, 2.0*f64::consts::PI);
+
+        let tangent = arc1.intersects_arc(&arc2);
+        assert!(tangent.len() == 1);
+        assert_near(tangent[0].2, Coord2(5.0, 0.0));
+
+        assert!(arc1.intersects_arc(&arc3).is_empty());
+        assert!(arc1.intersects_arc(&arc1).is_empty());
+    }
+
+    #[test]
+    fn large_arc_to_curves() {
+        let circle = Circle::new(Coord2(0.0, 0.0), 10.0);
+        let curves = circle.arc(0.0, 3.0*f64::consts::PI/2.0).to_bezier_curves::<Curve<_>>(0.01);
+
+        assert!(curves.len() >= 3);
+        assert_near(curves.last().unwrap().end_point(), Coord2(-10.0, 0.0));
+        for curve in curves.iter() {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64)/10.0);
+                assert!((p.magnitude() - 10.0).abs() < 0.01);
+            }
+        }
+    }
 }
\ No newline at end of file
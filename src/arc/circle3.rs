@@ -0,0 +1,423 @@
+use super::super::bezier::*;
+use super::super::bezier::path::*;
+use super::bezier_arc::*;
+
+use std::f64;
+
+///
+/// Returns the cross product of two 3D vectors
+///
+#[inline]
+fn cross<Coord: Coordinate3D+Coordinate>(a: &Coord, b: &Coord) -> Coord {
+    Coord::from_components(&[
+        a.y()*b.z() - a.z()*b.y(),
+        a.z()*b.x() - a.x()*b.z(),
+        a.x()*b.y() - a.y()*b.x()
+    ])
+}
+
+///
+/// Represents a circle in 3 dimensions, lying in the plane perpendicular to its normal
+///
+/// Angles are measured in radians from the circle's y axis towards its x axis, so the point at angle `a` is
+/// `center + radius * (sin(a) * x_axis + cos(a) * y_axis)`. The axes are perpendicular unit vectors in the plane of the
+/// circle, with `y_axis = normal × x_axis`, so angles increase going clockwise around the normal. This is the same
+/// convention as for `Circle`: a circle with a normal of `(0, 0, 1)` and the default axes has its points in the same
+/// places as the 2D circle.
+///
+#[derive(Clone, Copy)]
+pub struct Circle3<Coord: Coordinate3D+Coordinate> {
+    /// The center of this circle
+    pub center: Coord,
+
+    /// The radius of this circle
+    pub radius: f64,
+
+    /// The unit normal of the plane this circle is in
+    normal: Coord,
+
+    /// The unit vector in the plane of the circle that angles are measured towards
+    x_axis: Coord,
+
+    /// The unit vector in the plane of the circle that angles are measured from
+    y_axis: Coord
+}
+
+///
+/// Represents an arc of a circle in 3 dimensions
+///
+/// The arc goes from its start angle to its end angle, so it's clockwise around the circle's normal if the end angle is
+/// greater than the start angle and anticlockwise otherwise.
+///
+#[derive(Clone, Copy)]
+pub struct CircularArc3<Coord: Coordinate3D+Coordinate> {
+    /// The circle that this is an arc of
+    circle: Circle3<Coord>,
+
+    /// The start point of this arc, in radians
+    start_radians: f64,
+
+    /// The end point of this arc, in radians
+    end_radians: f64
+}
+
+impl<Coord: Coordinate3D+Coordinate> Circle3<Coord> {
+    ///
+    /// Creates a new circle with a center, a normal and a radius
+    ///
+    /// The x axis of the circle is the positive x axis projected onto the plane of the circle (or the positive y axis if
+    /// the normal is close to the x axis). Use `with_x_axis()` to choose a different direction.
+    ///
+    pub fn new(center: Coord, normal: Coord, radius: f64) -> Circle3<Coord> {
+        let normal      = normal.to_unit_vector();
+        let reference   = if normal.x().abs() < 0.9 { Coord::from_components(&[1.0, 0.0, 0.0]) } else { Coord::from_components(&[0.0, 1.0, 0.0]) };
+        let x_axis      = (reference - normal * reference.dot(&normal)).to_unit_vector();
+        let y_axis      = cross(&normal, &x_axis);
+
+        Circle3 {
+            center: center,
+            radius: radius,
+            normal: normal,
+            x_axis: x_axis,
+            y_axis: y_axis
+        }
+    }
+
+    ///
+    /// Returns a copy of this circle with its x axis pointing as close to the specified direction as possible
+    ///
+    /// The direction is projected onto the plane of the circle: this has no effect if the direction is parallel to the
+    /// normal.
+    ///
+    pub fn with_x_axis(self, direction: Coord) -> Circle3<Coord> {
+        let x_axis = direction - self.normal * direction.dot(&self.normal);
+        if x_axis.magnitude() <= 1e-12 {
+            return self;
+        }
+
+        let x_axis = x_axis.to_unit_vector();
+
+        Circle3 {
+            x_axis: x_axis,
+            y_axis: cross(&self.normal, &x_axis),
+            ..self
+        }
+    }
+
+    ///
+    /// The unit normal of the plane that this circle is in
+    ///
+    pub fn normal(&self) -> Coord {
+        self.normal
+    }
+
+    ///
+    /// The unit vector in the plane of this circle that angles are measured towards
+    ///
+    pub fn x_axis(&self) -> Coord {
+        self.x_axis
+    }
+
+    ///
+    /// The unit vector in the plane of this circle that angles are measured from
+    ///
+    pub fn y_axis(&self) -> Coord {
+        self.y_axis
+    }
+
+    ///
+    /// Returns the point at the specified angle around this circle
+    ///
+    pub fn point_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos) = angle.sin_cos();
+
+        self.center + self.x_axis * (self.radius * sin) + self.y_axis * (self.radius * cos)
+    }
+
+    ///
+    /// Returns the unit tangent at the specified angle around this circle, pointing in the direction of increasing angle
+    ///
+    pub fn tangent_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos) = angle.sin_cos();
+
+        self.x_axis * cos - self.y_axis * sin
+    }
+
+    ///
+    /// Returns the angle of the point on this circle that's closest to the specified point
+    ///
+    pub fn angle_for_point(&self, point: Coord) -> f64 {
+        let offset = point - self.center;
+
+        offset.dot(&self.x_axis).atan2(offset.dot(&self.y_axis))
+    }
+
+    ///
+    /// Returns an object representing an arc from this circle
+    ///
+    pub fn arc(&self, start_radians: f64, end_radians: f64) -> CircularArc3<Coord> {
+        CircularArc3 {
+            circle:         *self,
+            start_radians:  start_radians,
+            end_radians:    end_radians
+        }
+    }
+
+    ///
+    /// Returns a set of bezier curves that approximate this circle
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point=Coord>>(&self) -> Vec<Curve> {
+        self.arc(0.0, 2.0*f64::consts::PI).bezier_curves_with_count(4)
+    }
+
+    ///
+    /// Returns a path that approximates this circle
+    ///
+    pub fn to_path<P: BezierPathFactory<Point=Coord>>(&self) -> P {
+        arc_path(self.to_curves::<Curve<_>>())
+    }
+}
+
+impl<Coord: Coordinate3D+Coordinate> CircularArc3<Coord> {
+    ///
+    /// Creates an arc that starts at `start`, passes through `mid` and ends at `end`
+    ///
+    /// The arc's circle has its y axis pointing at the start point, so the arc starts at angle 0 and has a positive
+    /// end angle. Returns `None` if the three points are on a straight line.
+    ///
+    pub fn through_points(start: Coord, mid: Coord, end: Coord) -> Option<CircularArc3<Coord>> {
+        // Find the circumcenter of the three points
+        let a           = start - end;
+        let b           = mid - end;
+        let a_cross_b   = cross(&a, &b);
+        let cross_sq    = a_cross_b.dot(&a_cross_b);
+        let scale       = a.dot(&a).max(b.dot(&b));
+
+        if cross_sq <= 1e-24 * scale * scale {
+            return None;
+        }
+
+        let center      = end + cross(&(b * a.dot(&a) - a * b.dot(&b)), &a_cross_b) * (1.0 / (2.0 * cross_sq));
+        let radius      = center.distance_to(&start);
+
+        // Choose the normal so that going from the start through the middle point to the end increases the angle
+        let normal      = cross(&(end - mid), &(mid - start)).to_unit_vector();
+        let y_axis      = (start - center).to_unit_vector();
+        let x_axis      = cross(&y_axis, &normal);
+        let circle      = Circle3 {
+            center: center,
+            radius: radius,
+            normal: normal,
+            x_axis: x_axis,
+            y_axis: y_axis
+        };
+
+        let end_angle   = circle.angle_for_point(end).rem_euclid(2.0*f64::consts::PI);
+
+        Some(circle.arc(0.0, end_angle))
+    }
+
+    ///
+    /// The circle that this is an arc of
+    ///
+    pub fn circle(&self) -> &Circle3<Coord> {
+        &self.circle
+    }
+
+    ///
+    /// The angle where this arc starts
+    ///
+    pub fn start_radians(&self) -> f64 {
+        self.start_radians
+    }
+
+    ///
+    /// The angle where this arc ends
+    ///
+    pub fn end_radians(&self) -> f64 {
+        self.end_radians
+    }
+
+    ///
+    /// The angle covered by this arc (negative if the arc goes anticlockwise around the normal)
+    ///
+    pub fn sweep_radians(&self) -> f64 {
+        self.end_radians - self.start_radians
+    }
+
+    ///
+    /// The point where this arc starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        self.circle.point_at_angle(self.start_radians)
+    }
+
+    ///
+    /// The point where this arc ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        self.circle.point_at_angle(self.end_radians)
+    }
+
+    ///
+    /// The length of this arc
+    ///
+    pub fn length(&self) -> f64 {
+        self.circle.radius * self.sweep_radians().abs()
+    }
+
+    ///
+    /// Returns the point at the specified angle around the circle
+    ///
+    pub fn point_at_angle(&self, angle: f64) -> Coord {
+        self.circle.point_at_angle(angle)
+    }
+
+    ///
+    /// Returns the unit tangent at the specified angle around the circle, pointing in the direction of this arc
+    ///
+    pub fn tangent_at_angle(&self, angle: f64) -> Coord {
+        let tangent = self.circle.tangent_at_angle(angle);
+
+        if self.end_radians < self.start_radians { tangent * -1.0 } else { tangent }
+    }
+
+    ///
+    /// Converts this arc to bezier curves, using the specified number of curves
+    ///
+    fn bezier_curves_with_count<Curve: BezierCurveFactory<Point=Coord>>(&self, num_curves: usize) -> Vec<Curve> {
+        let radius = self.circle.radius;
+
+        arc_bezier_curves(self.start_radians, self.end_radians, num_curves, |angle| self.circle.point_at_angle(angle), |angle| self.circle.tangent_at_angle(angle) * radius)
+    }
+
+    ///
+    /// Converts this arc to a bezier curve
+    ///
+    /// If this arc covers an angle > 90 degrees, the curve will be very inaccurate.
+    ///
+    pub fn to_bezier_curve<Curve: BezierCurveFactory<Point=Coord>>(&self) -> Curve {
+        self.bezier_curves_with_count(1).remove(0)
+    }
+
+    ///
+    /// Converts this arc to a set of bezier curves that are within `max_error` of the true arc
+    ///
+    /// Each curve covers at most 90 degrees of the arc.
+    ///
+    pub fn to_bezier_curves<Curve: BezierCurveFactory<Point=Coord>>(&self, max_error: f64) -> Vec<Curve> {
+        self.bezier_curves_with_count(arc_curve_count(self.sweep_radians(), self.circle.radius, max_error))
+    }
+
+    ///
+    /// Returns a path following this arc, accurate to within `max_error`
+    ///
+    pub fn to_path<P: BezierPathFactory<Point=Coord>>(&self, max_error: f64) -> P {
+        arc_path(self.to_bezier_curves::<Curve<_>>(max_error))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arc::*;
+    use crate::arc::test_util::*;
+    use std::f64;
+
+    #[test]
+    fn flat_circle_matches_2d_circle() {
+        let circle3 = Circle3::new(Coord3(1.0, 2.0, 0.0), Coord3(0.0, 0.0, 1.0), 3.0);
+        let circle2 = Circle::new(Coord2(1.0, 2.0), 3.0);
+
+        for (curve3, curve2) in circle3.to_curves::<Curve<_>>().into_iter().zip(circle2.arc(0.0, 2.0*f64::consts::PI).to_bezier_curves::<Curve<_>>(1.0)) {
+            for t in 0..=4 {
+                let t   = (t as f64)/4.0;
+                let p3  = curve3.point_at_pos(t);
+                let p2  = curve2.point_at_pos(t);
+
+                assert_near(p3, Coord3(p2.x(), p2.y(), 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn circle_in_tilted_plane() {
+        let normal  = Coord3(1.0, 1.0, 1.0);
+        let circle  = Circle3::new(Coord3(5.0, 5.0, 5.0), normal, 2.0);
+        let path    = circle.to_path::<SimpleBezierPath3>();
+
+        assert_near(path.start_point(), path.points().last().unwrap().2);
+
+        for curve in path_to_curves::<_, Curve<_>>(&path) {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64)/10.0);
+
+                // On the sphere and in the plane
+                assert!((p.distance_to(&Coord3(5.0, 5.0, 5.0)) - 2.0).abs() < 0.01);
+                assert!((p - Coord3(5.0, 5.0, 5.0)).dot(&normal).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn angles_go_clockwise_around_normal() {
+        let circle  = Circle3::new(Coord3(0.0, 0.0, 0.0), Coord3(0.0, 0.0, 1.0), 1.0);
+
+        assert_near(circle.point_at_angle(0.0), Coord3(0.0, 1.0, 0.0));
+        assert_near(circle.point_at_angle(f64::consts::PI/2.0), Coord3(1.0, 0.0, 0.0));
+        assert_near(circle.tangent_at_angle(0.0), Coord3(1.0, 0.0, 0.0));
+
+        let flipped = Circle3::new(Coord3(0.0, 0.0, 0.0), Coord3(0.0, 0.0, -1.0), 1.0);
+        assert_near(flipped.point_at_angle(f64::consts::PI/2.0), Coord3(1.0, 0.0, 0.0));
+        assert_near(flipped.point_at_angle(0.0), Coord3(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn custom_x_axis() {
+        let circle = Circle3::new(Coord3(0.0, 0.0, 0.0), Coord3(0.0, 0.0, 1.0), 1.0).with_x_axis(Coord3(0.0, 2.0, 5.0));
+
+        assert_near(circle.x_axis(), Coord3(0.0, 1.0, 0.0));
+        assert_near(circle.point_at_angle(f64::consts::PI/2.0), Coord3(0.0, 1.0, 0.0));
+        assert!((circle.angle_for_point(Coord3(0.0, 3.0, 1.0)) - f64::consts::PI/2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_through_points() {
+        let arc = CircularArc3::through_points(Coord3(1.0, 0.0, 0.0), Coord3(0.0, 1.0, 0.0), Coord3(0.0, 0.0, 1.0)).unwrap();
+
+        let center = Coord3(1.0/3.0, 1.0/3.0, 1.0/3.0);
+        assert_near(arc.circle().center, center);
+        assert_near(arc.start_point(), Coord3(1.0, 0.0, 0.0));
+        assert_near(arc.end_point(), Coord3(0.0, 0.0, 1.0));
+        assert!((arc.sweep_radians() - 4.0*f64::consts::PI/3.0).abs() < 1e-9);
+
+        // The middle point is on the arc
+        let mid_angle = arc.circle().angle_for_point(Coord3(0.0, 1.0, 0.0));
+        assert!(mid_angle > 0.0 && mid_angle < arc.end_radians());
+
+        let curves = arc.to_bezier_curves::<Curve<_>>(0.001);
+        assert_near(curves[0].start_point(), Coord3(1.0, 0.0, 0.0));
+        assert_near(curves.last().unwrap().end_point(), Coord3(0.0, 0.0, 1.0));
+        for curve in curves.iter() {
+            for t in 0..=10 {
+                let p = curve.point_at_pos((t as f64)/10.0);
+                assert!((p.distance_to(&center) - arc.circle().radius).abs() < 0.001);
+            }
+        }
+
+        assert!(CircularArc3::through_points(Coord3(0.0, 0.0, 0.0), Coord3(1.0, 1.0, 1.0), Coord3(2.0, 2.0, 2.0)).is_none());
+    }
+
+    #[test]
+    fn arc_tangents_and_length() {
+        let circle  = Circle3::new(Coord3(0.0, 0.0, 0.0), Coord3(0.0, 1.0, 0.0), 2.0);
+        let arc     = circle.arc(0.0, -f64::consts::PI/2.0);
+
+        assert!((arc.length() - f64::consts::PI).abs() < 1e-9);
+        assert_near(arc.tangent_at_angle(0.0), circle.tangent_at_angle(0.0) * -1.0);
+
+        let path = arc.to_path::<SimpleBezierPath3>(0.01);
+        assert_near(path.start_point(), arc.start_point());
+        assert_near(path.points().last().unwrap().2, arc.end_point());
+    }
+}
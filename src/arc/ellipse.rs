@@ -0,0 +1,638 @@
+use crate::line::*;
+use crate::bezier::*;
+use crate::bezier::path::*;
+
+use super::bezier_arc::*;
+
+use std::f64;
+
+///
+/// Represents an ellipse in 2 dimensions
+///
+/// Angles around the ellipse are measured in radians clockwise from the ellipse's y axis, in the same way as for
+/// `Circle`: the point at angle `a` is `(radius_x * sin(a), radius_y * cos(a))` before the ellipse is rotated and
+/// moved to its center. For an ellipse that is not a circle, this angle is the 'eccentric anomaly' of the point,
+/// which is not generally the same as the angle between the point and the y axis.
+///
+#[derive(Clone, Copy)]
+pub struct Ellipse<Coord: Coordinate2D+Coordinate> {
+    /// The center of this ellipse
+    pub center: Coord,
+
+    /// The radius along the ellipse's x axis
+    pub radius_x: f64,
+
+    /// The radius along the ellipse's y axis
+    pub radius_y: f64,
+
+    /// The angle in radians that the axes of this ellipse are rotated by, clockwise
+    pub rotation: f64
+}
+
+///
+/// Represents an arc of an ellipse in 2 dimensions
+///
+#[derive(Clone, Copy)]
+pub struct EllipticalArc<Coord: Coordinate2D+Coordinate> {
+    /// The ellipse that this is an arc of
+    ellipse: Ellipse<Coord>,
+
+    /// The start point of this arc, in radians
+    start_angle: f64,
+
+    /// The end point of this arc, in radians
+    end_angle: f64
+}
+
+impl<Coord: Coordinate2D+Coordinate> Ellipse<Coord> {
+    ///
+    /// Creates a new axis-aligned ellipse
+    ///
+    pub fn new(center: Coord, radius_x: f64, radius_y: f64) -> Ellipse<Coord> {
+        Ellipse {
+            center:     center,
+            radius_x:   radius_x,
+            radius_y:   radius_y,
+            rotation:   0.0
+        }
+    }
+
+    ///
+    /// Returns a copy of this ellipse rotated clockwise by the specified number of radians
+    ///
+    pub fn with_rotation(self, rotation: f64) -> Ellipse<Coord> {
+        Ellipse {
+            rotation: rotation,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns an object representing an arc from this ellipse
+    ///
+    /// The arc goes from `start_angle` to `end_angle`, so it goes clockwise if the end angle is greater than the
+    /// start angle and anticlockwise otherwise.
+    ///
+    pub fn arc(&self, start_angle: f64, end_angle: f64) -> EllipticalArc<Coord> {
+        EllipticalArc {
+            ellipse:        *self,
+            start_angle:    start_angle,
+            end_angle:      end_angle
+        }
+    }
+
+    ///
+    /// Converts a point relative to the unrotated ellipse into a point in space
+    ///
+    #[inline]
+    fn local_to_world(&self, x: f64, y: f64) -> Coord {
+        let (sin, cos) = self.rotation.sin_cos();
+
+        Coord::from_components(&[self.center.x() + x*cos + y*sin, self.center.y() - x*sin + y*cos])
+    }
+
+    ///
+    /// Converts a point in space into a point relative to the unrotated ellipse
+    ///
+    #[inline]
+    fn world_to_local(&self, point: Coord) -> (f64, f64) {
+        let (sin, cos)  = self.rotation.sin_cos();
+        let (x, y)      = (point.x() - self.center.x(), point.y() - self.center.y());
+
+        (x*cos - y*sin, x*sin + y*cos)
+    }
+
+    ///
+    /// Returns the point at the specified angle around this ellipse
+    ///
+    pub fn point_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos) = angle.sin_cos();
+
+        self.local_to_world(self.radius_x * sin, self.radius_y * cos)
+    }
+
+    ///
+    /// Returns the tangent at the specified angle around this ellipse
+    ///
+    /// This is the derivative of `point_at_angle()`, so it points in the clockwise direction and is not normalized.
+    ///
+    pub fn tangent_at_angle(&self, angle: f64) -> Coord {
+        let (sin, cos)          = angle.sin_cos();
+        let (x, y)              = (self.radius_x * cos, -self.radius_y * sin);
+        let (rot_sin, rot_cos)  = self.rotation.sin_cos();
+
+        Coord::from_components(&[x*rot_cos + y*rot_sin, -x*rot_sin + y*rot_cos])
+    }
+
+    ///
+    /// Returns the angles where the x and y coordinates of this ellipse reach their extremes (two of each)
+    ///
+    fn extreme_angles(&self) -> [f64; 4] {
+        let (sin, cos)  = self.rotation.sin_cos();
+        let x_angle     = (self.radius_x * cos).atan2(self.radius_y * sin);
+        let y_angle     = (-self.radius_x * sin).atan2(self.radius_y * cos);
+
+        [x_angle, x_angle + f64::consts::PI, y_angle, y_angle + f64::consts::PI]
+    }
+
+    ///
+    /// Returns the bounding box of this ellipse
+    ///
+    pub fn bounding_box<Bounds: BoundingBox<Point=Coord>>(&self) -> Bounds {
+        Bounds::bounds_for_points(self.extreme_angles().iter().map(|angle| self.point_at_angle(*angle)))
+    }
+
+    ///
+    /// Returns a set of bezier curves that approximate this ellipse to within `max_error`
+    ///
+    /// The curves start at angle 0 and go clockwise.
+    ///
+    pub fn to_curves<Curve: BezierCurveFactory<Point=Coord>>(&self, max_error: f64) -> Vec<Curve> {
+        self.arc(0.0, 2.0*f64::consts::PI).to_bezier_curves(max_error)
+    }
+
+    ///
+    /// Returns a path that approximates this ellipse to within `max_error`
+    ///
+    pub fn to_path<P: BezierPathFactory<Point=Coord>>(&self, max_error: f64) -> P {
+        arc_path(self.to_curves::<Curve<_>>(max_error))
+    }
+
+    ///
+    /// Finds the points where a line (extended infinitely in both directions) crosses this ellipse
+    ///
+    /// The result is a list of `(angle, t, point)` where `angle` is the angle around the ellipse and `t` is the position
+    /// along the line.
+    ///
+    pub fn intersects_ray<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        if self.radius_x == 0.0 || self.radius_y == 0.0 {
+            return vec![];
+        }
+
+        // Transform the line so the ellipse becomes the unit circle
+        let (p1, p2)    = line.points();
+        let (x1, y1)    = self.world_to_local(p1);
+        let (x2, y2)    = self.world_to_local(p2);
+        let (x1, y1)    = (x1 / self.radius_x, y1 / self.radius_y);
+        let (dx, dy)    = (x2 / self.radius_x - x1, y2 / self.radius_y - y1);
+
+        // Solve |p1 + t*d| = 1
+        let a           = dx*dx + dy*dy;
+        let b           = 2.0 * (x1*dx + y1*dy);
+        let c           = x1*x1 + y1*y1 - 1.0;
+
+        if a == 0.0 {
+            return vec![];
+        }
+
+        // The discriminant is 4a(1-d^2) where d is the distance from the line to the center, so lines within a small distance of touching are tangents
+        let discriminant    = b*b - 4.0*a*c;
+        let tolerance       = 4e-9 * a;
+        let ts = if discriminant < -tolerance {
+            vec![]
+        } else if discriminant <= tolerance {
+            vec![-b / (2.0*a)]
+        } else {
+            let root = discriminant.sqrt();
+            vec![(-b - root) / (2.0*a), (-b + root) / (2.0*a)]
+        };
+
+        ts.into_iter()
+            .map(|t| {
+                let angle = (x1 + t*dx).atan2(y1 + t*dy);
+                (angle, t, self.point_at_angle(angle))
+            })
+            .collect()
+    }
+
+    ///
+    /// Finds the points where a line segment crosses this ellipse
+    ///
+    /// The result is a list of `(angle, t, point)` where `angle` is the angle around the ellipse and `t` is the position
+    /// along the line (between 0 and 1).
+    ///
+    pub fn intersects_line<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        let mut intersections = self.intersects_ray(line);
+        intersections.retain(|(_angle, t, _point)| (-1e-9..=1.0+1e-9).contains(t));
+
+        intersections
+    }
+}
+
+impl<Coord: Coordinate2D+Coordinate> EllipticalArc<Coord> {
+    ///
+    /// Creates an elliptical arc from the parameters used by the SVG 'A' command
+    ///
+    /// The ellipse has the radii `(radius_x, radius_y)` and its x-axis is rotated by `x_axis_rotation` degrees, which is
+    /// clockwise when the y axis points downwards as in SVG (and anticlockwise when it points upwards). Of the four
+    /// possible arcs from `start` to `end`, `large_arc` chooses one that covers more than 180 degrees and `sweep`
+    /// chooses one that goes in the direction of increasing SVG angle. The radii are scaled up if they are too small
+    /// for the ellipse to reach the end point.
+    ///
+    /// Returns `None` if the start and end points are the same or if either radius is 0 (SVG omits the arc in the first
+    /// case and draws a straight line in the second)
+    ///
+    pub fn from_svg_endpoints(start: Coord, (radius_x, radius_y): (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end: Coord) -> Option<EllipticalArc<Coord>> {
+        // See the 'implementation notes' section of the SVG specification for the details of this conversion
+        let (x1, y1)            = (start.x(), start.y());
+        let (x2, y2)            = (end.x(), end.y());
+        let (mut rx, mut ry)    = (radius_x.abs(), radius_y.abs());
+
+        if (x1 == x2 && y1 == y2) || rx == 0.0 || ry == 0.0 {
+            return None;
+        }
+
+        // Transform the start point to the ellipse's coordinate space
+        let phi             = x_axis_rotation.to_radians();
+        let (sin, cos)      = phi.sin_cos();
+        let (dx, dy)        = ((x1-x2)/2.0, (y1-y2)/2.0);
+        let (x1p, y1p)      = (cos*dx + sin*dy, -sin*dx + cos*dy);
+
+        // Scale up the radii if they're too small to reach the end point
+        let lambda          = (x1p*x1p)/(rx*rx) + (y1p*y1p)/(ry*ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        // Find the center
+        let numerator       = rx*rx*ry*ry - rx*rx*y1p*y1p - ry*ry*x1p*x1p;
+        let denominator     = rx*rx*y1p*y1p + ry*ry*x1p*x1p;
+        let coefficient     = (numerator/denominator).max(0.0).sqrt();
+        let coefficient     = if large_arc == sweep { -coefficient } else { coefficient };
+        let (cxp, cyp)      = (coefficient*rx*y1p/ry, -coefficient*ry*x1p/rx);
+        let (cx, cy)        = (cos*cxp - sin*cyp + (x1+x2)/2.0, sin*cxp + cos*cyp + (y1+y2)/2.0);
+
+        // Find the start angle and the angle covered by the arc (anticlockwise from the x axis)
+        fn angle_between((ux, uy): (f64, f64), (vx, vy): (f64, f64)) -> f64 {
+            (ux*vy - uy*vx).atan2(ux*vx + uy*vy)
+        }
+
+        let start_angle     = angle_between((1.0, 0.0), ((x1p-cxp)/rx, (y1p-cyp)/ry));
+        let mut sweep_angle = angle_between(((x1p-cxp)/rx, (y1p-cyp)/ry), ((-x1p-cxp)/rx, (-y1p-cyp)/ry));
+
+        if !sweep && sweep_angle > 0.0 { sweep_angle -= 2.0*f64::consts::PI; }
+        if sweep && sweep_angle < 0.0 { sweep_angle += 2.0*f64::consts::PI; }
+
+        // Convert to angles clockwise from the y axis
+        let ellipse         = Ellipse::new(Coord::from_components(&[cx, cy]), rx, ry).with_rotation(-phi);
+        let start_angle     = f64::consts::PI/2.0 - start_angle;
+
+        Some(ellipse.arc(start_angle, start_angle - sweep_angle))
+    }
+
+    ///
+    /// The ellipse that this is an arc of
+    ///
+    pub fn ellipse(&self) -> &Ellipse<Coord> {
+        &self.ellipse
+    }
+
+    ///
+    /// The angle where this arc starts
+    ///
+    pub fn start_angle(&self) -> f64 {
+        self.start_angle
+    }
+
+    ///
+    /// The angle where this arc ends
+    ///
+    pub fn end_angle(&self) -> f64 {
+        self.end_angle
+    }
+
+    ///
+    /// The angle covered by this arc (negative if the arc goes anticlockwise)
+    ///
+    pub fn sweep_angle(&self) -> f64 {
+        self.end_angle - self.start_angle
+    }
+
+    ///
+    /// The point where this arc starts
+    ///
+    pub fn start_point(&self) -> Coord {
+        self.ellipse.point_at_angle(self.start_angle)
+    }
+
+    ///
+    /// The point where this arc ends
+    ///
+    pub fn end_point(&self) -> Coord {
+        self.ellipse.point_at_angle(self.end_angle)
+    }
+
+    ///
+    /// Returns the point at the specified angle around the ellipse
+    ///
+    pub fn point_at_angle(&self, angle: f64) -> Coord {
+        self.ellipse.point_at_angle(angle)
+    }
+
+    ///
+    /// Returns the tangent at the specified angle around the ellipse, pointing in the direction of this arc
+    ///
+    pub fn tangent_at_angle(&self, angle: f64) -> Coord {
+        let tangent = self.ellipse.tangent_at_angle(angle);
+
+        if self.end_angle < self.start_angle { tangent * -1.0 } else { tangent }
+    }
+
+    ///
+    /// If an angle is within this arc, returns the angle equivalent to it in the range covered by the arc
+    ///
+    pub (crate) fn arc_angle(&self, angle: f64) -> Option<f64> {
+        const TWO_PI: f64 = 2.0*f64::consts::PI;
+        const EPSILON: f64 = 1e-9;
+
+        let sweep = self.sweep_angle();
+        if sweep.abs() >= TWO_PI - EPSILON {
+            return Some(angle);
+        }
+
+        // Distance around the ellipse from the start of the arc, in the arc's direction
+        let mut offset = if sweep >= 0.0 { (angle - self.start_angle).rem_euclid(TWO_PI) } else { (self.start_angle - angle).rem_euclid(TWO_PI) };
+        if offset > TWO_PI - EPSILON {
+            offset -= TWO_PI;
+        }
+
+        if offset <= sweep.abs() + EPSILON {
+            Some(if sweep >= 0.0 { self.start_angle + offset } else { self.start_angle - offset })
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// True if the specified angle around the ellipse is part of this arc
+    ///
+    pub fn contains_angle(&self, angle: f64) -> bool {
+        self.arc_angle(angle).is_some()
+    }
+
+    ///
+    /// Returns the bounding box of this arc
+    ///
+    pub fn bounding_box<Bounds: BoundingBox<Point=Coord>>(&self) -> Bounds {
+        let extremes = self.ellipse.extreme_angles();
+        let extremes = extremes.iter()
+            .filter(|angle| self.contains_angle(**angle))
+            .map(|angle| self.ellipse.point_at_angle(*angle));
+
+        Bounds::bounds_for_points(vec![self.start_point(), self.end_point()].into_iter().chain(extremes))
+    }
+
+    ///
+    /// Finds the points where a line (extended infinitely in both directions) crosses this arc
+    ///
+    /// The result is a list of `(angle, t, point)`, where `angle` is in the range covered by the arc and `t` is the
+    /// position along the line.
+    ///
+    pub fn intersects_ray<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        self.ellipse.intersects_ray(line).into_iter()
+            .filter_map(|(angle, t, point)| self.arc_angle(angle).map(|angle| (angle, t, point)))
+            .collect()
+    }
+
+    ///
+    /// Finds the points where a line segment crosses this arc
+    ///
+    /// The result is a list of `(angle, t, point)`, where `angle` is in the range covered by the arc and `t` is the
+    /// position along the line (between 0 and 1).
+    ///
+    pub fn intersects_line<L: Line<Point=Coord>>(&self, line: &L) -> Vec<(f64, f64, Coord)> {
+        self.ellipse.intersects_line(line).into_iter()
+            .filter_map(|(angle, t, point)| self.arc_angle(angle).map(|angle| (angle, t, point)))
+            .collect()
+    }
+
+    ///
+    /// Converts this arc to bezier curves, using the specified number of curves
+    ///
+    fn bezier_curves_with_count<Curve: BezierCurveFactory<Point=Coord>>(&self, num_curves: usize) -> Vec<Curve> {
+        arc_bezier_curves(self.start_angle, self.end_angle, num_curves, |angle| self.ellipse.point_at_angle(angle), |angle| self.ellipse.tangent_at_angle(angle))
+    }
+
+    ///
+    /// Converts this arc to bezier curves, using one curve for every 90 degrees
+    ///
+    /// The result will be accurate to around 0.03% of the larger radius
+    ///
+    pub (crate) fn quarter_bezier_curves<Curve: BezierCurveFactory<Point=Coord>>(&self) -> Vec<Curve> {
+        self.bezier_curves_with_count(arc_quarter_curve_count(self.sweep_angle()))
+    }
+
+    ///
+    /// Converts this arc to a set of bezier curves that are within `max_error` of the true arc
+    ///
+    /// Each curve covers at most 90 degrees of the arc.
+    ///
+    pub fn to_bezier_curves<Curve: BezierCurveFactory<Point=Coord>>(&self, max_error: f64) -> Vec<Curve> {
+        let max_radius = self.ellipse.radius_x.abs().max(self.ellipse.radius_y.abs());
+
+        self.bezier_curves_with_count(arc_curve_count(self.sweep_angle(), max_radius, max_error))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arc::*;
+    use crate::arc::test_util::*;
+    use std::f64;
+
+    #[test]
+    fn points_at_angles() {
+        let ellipse = Ellipse::new(Coord2(1.0, 2.0), 4.0, 2.0);
+
+        assert_near(ellipse.point_at_angle(0.0), Coord2(1.0, 4.0));
+        assert_near(ellipse.point_at_angle(f64::consts::PI/2.0), Coord2(5.0, 2.0));
+        assert_near(ellipse.point_at_angle(f64::consts::PI), Coord2(1.0, 0.0));
+        assert_near(ellipse.tangent_at_angle(0.0), Coord2(4.0, 0.0));
+    }
+
+    #[test]
+    fn rotated_ellipse_points() {
+        // Rotating clockwise by 90 degrees swaps the axes
+        let ellipse = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).with_rotation(f64::consts::PI/2.0);
+
+        assert_near(ellipse.point_at_angle(0.0), Coord2(2.0, 0.0));
+        assert_near(ellipse.point_at_angle(f64::consts::PI/2.0), Coord2(0.0, -4.0));
+    }
+
+    #[test]
+    fn circular_ellipse_matches_circle() {
+        let ellipse = Ellipse::new(Coord2(3.0, 4.0), 5.0, 5.0);
+        let circle  = Circle::new(Coord2(3.0, 4.0), 5.0);
+        let arc1    = ellipse.arc(0.3, 1.5).to_bezier_curves::<Curve<_>>(1.0);
+        let arc2    = circle.arc(0.3, 1.5).to_bezier_curve::<Curve<_>>();
+
+        assert!(arc1.len() == 1);
+        assert_near(arc1[0].start_point(), arc2.start_point());
+        assert_near(arc1[0].control_points().0, arc2.control_points().0);
+        assert_near(arc1[0].control_points().1, arc2.control_points().1);
+        assert_near(arc1[0].end_point(), arc2.end_point());
+    }
+
+    #[test]
+    fn curves_are_within_tolerance() {
+        let ellipse = Ellipse::new(Coord2(10.0, 10.0), 100.0, 40.0).with_rotation(0.4);
+
+        for max_error in [1.0, 0.1, 0.001].iter() {
+            let curves = ellipse.to_curves::<Curve<_>>(*max_error);
+
+            for curve in curves.iter() {
+                for t in 0..=20 {
+                    let pos = curve.point_at_pos((t as f64)/20.0);
+
+                    // Distance from the ellipse, by transforming into the ellipse's coordinate space
+                    let (x, y)  = ellipse.world_to_local(pos);
+                    let angle   = (x/100.0).atan2(y/40.0);
+                    let nearest = ellipse.point_at_angle(angle);
+
+                    assert!(pos.distance_to(&nearest) <= *max_error * 1.5, "{:?} {:?} {:?}", max_error, pos, nearest);
+                }
+            }
+        }
+
+        assert!(ellipse.to_curves::<Curve<_>>(1.0).len() == 4);
+        assert!(ellipse.to_curves::<Curve<_>>(0.001).len() > 4);
+    }
+
+    #[test]
+    fn ellipse_path_is_closed() {
+        let path = Ellipse::new(Coord2(0.0, 0.0), 3.0, 1.0).to_path::<SimpleBezierPath>(0.01);
+
+        assert_near(path.start_point(), Coord2(0.0, 1.0));
+        assert_near(path.points().last().unwrap().2, Coord2(0.0, 1.0));
+    }
+
+    #[test]
+    fn ellipse_bounding_box() {
+        let bounds: Bounds<_> = Ellipse::new(Coord2(1.0, 1.0), 4.0, 2.0).bounding_box();
+        assert_near(bounds.min(), Coord2(-3.0, -1.0));
+        assert_near(bounds.max(), Coord2(5.0, 3.0));
+
+        // A rotated ellipse
+        let ellipse             = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).with_rotation(f64::consts::PI/4.0);
+        let bounds: Bounds<_>   = ellipse.bounding_box();
+        let half_size           = ((16.0 + 4.0)/2.0f64).sqrt();
+        assert_near(bounds.min(), Coord2(-half_size, -half_size));
+        assert_near(bounds.max(), Coord2(half_size, half_size));
+    }
+
+    #[test]
+    fn arc_bounding_box() {
+        // Quarter arc from the top to the right
+        let arc                 = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).arc(0.0, f64::consts::PI/2.0);
+        let bounds: Bounds<_>   = arc.bounding_box();
+        assert_near(bounds.min(), Coord2(0.0, 0.0));
+        assert_near(bounds.max(), Coord2(4.0, 2.0));
+
+        // Going anticlockwise instead covers the other three quarters
+        let arc                 = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).arc(0.0, -3.0*f64::consts::PI/2.0);
+        let bounds: Bounds<_>   = arc.bounding_box();
+        assert_near(bounds.min(), Coord2(-4.0, -2.0));
+        assert_near(bounds.max(), Coord2(4.0, 2.0));
+    }
+
+    #[test]
+    fn contains_angles() {
+        let arc = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).arc(-0.5, 0.5);
+
+        assert!(arc.contains_angle(0.0));
+        assert!(arc.contains_angle(2.0*f64::consts::PI + 0.25));
+        assert!(arc.contains_angle(-0.5));
+        assert!(!arc.contains_angle(1.0));
+
+        let arc = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).arc(0.5, -0.5);
+        assert!(arc.contains_angle(0.0));
+        assert!(!arc.contains_angle(f64::consts::PI));
+    }
+
+    #[test]
+    fn line_intersections() {
+        let ellipse         = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0);
+        let intersections   = ellipse.intersects_line(&(Coord2(-10.0, 0.0), Coord2(10.0, 0.0)));
+
+        assert!(intersections.len() == 2);
+        assert_near(intersections[0].2, Coord2(-4.0, 0.0));
+        assert_near(intersections[1].2, Coord2(4.0, 0.0));
+        assert!((intersections[0].1 - 0.3).abs() < 1e-9);
+
+        // Line that ends inside the ellipse
+        assert!(ellipse.intersects_line(&(Coord2(-10.0, 0.0), Coord2(0.0, 0.0))).len() == 1);
+        assert!(ellipse.intersects_ray(&(Coord2(-10.0, 0.0), Coord2(0.0, 0.0))).len() == 2);
+
+        // Line that misses
+        assert!(ellipse.intersects_ray(&(Coord2(-10.0, 3.0), Coord2(10.0, 3.0))).is_empty());
+
+        // Tangent line
+        let tangent = ellipse.intersects_ray(&(Coord2(-10.0, 2.0), Coord2(10.0, 2.0)));
+        assert!(tangent.len() == 1);
+        assert_near(tangent[0].2, Coord2(0.0, 2.0));
+    }
+
+    #[test]
+    fn rotated_line_intersections() {
+        let ellipse         = Ellipse::new(Coord2(5.0, 5.0), 4.0, 2.0).with_rotation(0.7);
+        let intersections   = ellipse.intersects_line(&(Coord2(0.0, 0.0), Coord2(10.0, 8.0)));
+
+        assert!(intersections.len() == 2);
+        for (angle, _t, point) in intersections {
+            assert_near(point, ellipse.point_at_angle(angle));
+            assert!((point.y() - point.x()*0.8).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn arc_line_intersections() {
+        // Top half of the ellipse only
+        let arc             = Ellipse::new(Coord2(0.0, 0.0), 4.0, 2.0).arc(-f64::consts::PI/2.0, f64::consts::PI/2.0);
+        let intersections   = arc.intersects_ray(&(Coord2(1.0, -10.0), Coord2(1.0, 10.0)));
+
+        assert!(intersections.len() == 1);
+        assert!(intersections[0].2.y() > 0.0);
+        assert!(intersections[0].0 >= -f64::consts::PI/2.0 && intersections[0].0 <= f64::consts::PI/2.0);
+    }
+
+    #[test]
+    fn svg_semicircle() {
+        let arc = EllipticalArc::from_svg_endpoints(Coord2(0.0, 0.0), (10.0, 10.0), 0.0, false, true, Coord2(20.0, 0.0)).unwrap();
+
+        assert_near(arc.ellipse().center, Coord2(10.0, 0.0));
+        assert_near(arc.start_point(), Coord2(0.0, 0.0));
+        assert_near(arc.end_point(), Coord2(20.0, 0.0));
+        assert!((arc.sweep_angle().abs() - f64::consts::PI).abs() < 1e-9);
+
+        // Sweep goes in the direction of increasing SVG angle, which passes through negative y here
+        assert!(arc.point_at_angle((arc.start_angle() + arc.end_angle())/2.0).y() < 0.0);
+    }
+
+    #[test]
+    fn svg_arc_with_radius_correction() {
+        // Radius is too small, so it's scaled up to reach the end point
+        let arc = EllipticalArc::from_svg_endpoints(Coord2(0.0, 0.0), (1.0, 2.0), 0.0, false, false, Coord2(20.0, 0.0)).unwrap();
+
+        assert!((arc.ellipse().radius_x - 10.0).abs() < 1e-9);
+        assert!((arc.ellipse().radius_y - 20.0).abs() < 1e-9);
+        assert_near(arc.end_point(), Coord2(20.0, 0.0));
+    }
+
+    #[test]
+    fn svg_rotated_large_arc() {
+        let arc = EllipticalArc::from_svg_endpoints(Coord2(10.0, 0.0), (20.0, 10.0), 90.0, true, false, Coord2(0.0, 20.0)).unwrap();
+
+        assert_near(arc.ellipse().center, Coord2(0.0, 0.0));
+        assert_near(arc.start_point(), Coord2(10.0, 0.0));
+        assert_near(arc.end_point(), Coord2(0.0, 20.0));
+        assert!((arc.sweep_angle().abs() - 3.0*f64::consts::PI/2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn svg_degenerate_arcs() {
+        assert!(EllipticalArc::from_svg_endpoints(Coord2(1.0, 1.0), (10.0, 10.0), 0.0, false, true, Coord2(1.0, 1.0)).is_none());
+        assert!(EllipticalArc::from_svg_endpoints(Coord2(0.0, 0.0), (0.0, 10.0), 0.0, false, true, Coord2(1.0, 1.0)).is_none());
+    }
+}
@@ -1,12 +1,18 @@
 //!
 //! # Describing circular arcs
 //! 
-//! The `arc` module provides routines for describing circular arcs and converting them to bezier
-//! curves.
+//! The `arc` module provides routines for describing circular and elliptical arcs and converting them to
+//! bezier curves. `Circle3` and `CircularArc3` describe circles and arcs in arbitrary planes in 3D.
 //!
 
 mod circle;
+mod ellipse;
+mod circle3;
+mod bezier_arc;
 
-pub use self::circle::*;
+#[cfg(test)]
+mod test_util;
 
-// TODO: represent arcs in more than 2 dimensions
+pub use self::circle::*;
+pub use self::ellipse::*;
+pub use self::circle3::*;
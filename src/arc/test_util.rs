@@ -0,0 +1,10 @@
+use crate::geo::*;
+
+use std::fmt::Debug;
+
+///
+/// Checks that two coordinates are the same, allowing for rounding errors
+///
+pub (crate) fn assert_near<Coord: Coordinate+Debug>(actual: Coord, expected: Coord) {
+    assert!(actual.distance_to(&expected) < 1e-6, "{:?} != {:?}", actual, expected);
+}
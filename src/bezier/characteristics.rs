@@ -265,8 +265,14 @@ fn find_inflection_points(b4: (f64, f64)) -> InflectionPoints {
     let b           = 3.0-x4;
 
     if a.abs() <= f64::EPSILON {
-        // No solution
-        InflectionPoints::Zero
+        // The quadratic degenerates to the linear equation b*t - 1 = 0
+        let t = 1.0/b;
+
+        if b != 0.0 && (0.0..=1.0).contains(&t) {
+            InflectionPoints::One(t)
+        } else {
+            InflectionPoints::Zero
+        }
     } else {
         // Solve the quadratic for this curve
         let lhs = (-b)/(2.0*a);
@@ -1,8 +1,10 @@
 use super::fit::*;
 use super::walk::*;
 use super::path::*;
+use super::length::*;
 use super::curve::*;
 use super::normal::*;
+use super::tangent::*;
 use crate::geo::*;
 
 use std::iter;
@@ -84,3 +86,108 @@ where
     // Create the new path from the result
     Some(PathOut::from_points(start_point, new_points))
 }
+
+///
+/// Maps a list of shapes laid out along the x-axis onto a spine path
+///
+/// The x coordinate of each point in the shapes is treated as a distance along the spine, and the y coordinate is
+/// treated as an offset along the normal at that point (positive values are to the left of the direction of the
+/// spine, so a spine running along the x-axis leaves the shapes unchanged). Points before the start or after the end
+/// of the spine are extended along the tangent at the respective end. The warped curves are refitted by sampling at
+/// `step_len` intervals and fitting to within `max_error`.
+///
+/// Returns `None` if the spine is empty or if any of the shapes could not be refitted.
+///
+pub fn distort_along_path<PathIn, SpinePath, PathOut>(shapes: &[PathIn], spine: &SpinePath, step_len: f64, max_error: f64) -> Option<Vec<PathOut>>
+where
+    PathIn:         BezierPath,
+    PathIn::Point:  Normalize+Coordinate2D,
+    SpinePath:      BezierPath<Point=PathIn::Point>,
+    PathOut:        BezierPathFactory<Point=PathIn::Point>,
+{
+    // Minimum number of samples to take along each curve when mapping lengths to t values
+    const MIN_LENGTH_SAMPLES: usize = 16;
+
+    // Measure the length at evenly spaced t values along each curve making up the spine, so distances can be mapped to t values
+    // without measuring the curve again for every point
+    let curves          = spine.to_curves::<Curve<_>>();
+    let length_error    = max_error / 4.0;
+    let length_tables   = curves.iter()
+        .map(|curve| {
+            let num_samples     = ((curve_length(curve, length_error) / step_len).ceil() as usize).max(MIN_LENGTH_SAMPLES);
+            let mut length      = 0.0;
+            let mut table       = vec![0.0];
+
+            for sample in 0..num_samples {
+                let t1 = (sample as f64) / (num_samples as f64);
+                let t2 = ((sample+1) as f64) / (num_samples as f64);
+
+                length += curve_length(&curve.section(t1, t2), length_error);
+                table.push(length);
+            }
+
+            table
+        })
+        .collect::<Vec<_>>();
+
+    if curves.is_empty() { return None; }
+
+    let lengths         = length_tables.iter().map(|table| table[table.len()-1]).collect::<Vec<_>>();
+    let total_length    = lengths.iter().sum::<f64>();
+
+    // Converts a distance along a curve into a t value by interpolating between the samples in its length table
+    let t_for_length = |curve_idx: usize, length: f64| {
+        let table       = &length_tables[curve_idx];
+        let num_samples = table.len()-1;
+        let sample      = table.partition_point(|sample_length| *sample_length <= length).clamp(1, num_samples);
+        let (l1, l2)    = (table[sample-1], table[sample]);
+        let ratio       = if l2 > l1 { ((length - l1) / (l2 - l1)).clamp(0.0, 1.0) } else { 0.0 };
+
+        ((sample-1) as f64 + ratio) / (num_samples as f64)
+    };
+
+    // Converts a distance along the spine into a curve and a t value
+    let curve_for_length = |length: f64| {
+        let mut curve_start = 0.0;
+
+        for (curve_idx, curve_length) in lengths.iter().enumerate() {
+            if length < curve_start + curve_length || curve_idx == lengths.len()-1 {
+                return (curve_idx, t_for_length(curve_idx, length - curve_start));
+            }
+
+            curve_start += curve_length;
+        }
+
+        (lengths.len()-1, 1.0)
+    };
+
+    // Maps a point in the shape space onto the spine
+    let map_point = |point: PathIn::Point, _curve: &Curve<PathIn::Point>, _t: f64| {
+        let distance                = point.x();
+        let offset                  = point.y();
+
+        let (base_point, tangent)   = if distance < 0.0 {
+            // Extend along the starting tangent
+            let tangent = curve_direction(&curves[0], 0.0);
+            (curves[0].start_point() + tangent * distance, tangent)
+        } else if distance > total_length {
+            // Extend along the ending tangent
+            let last    = &curves[curves.len()-1];
+            let tangent = curve_direction(last, 1.0);
+            (last.end_point() + tangent * (distance - total_length), tangent)
+        } else {
+            let (curve_idx, t)  = curve_for_length(distance);
+            let curve           = &curves[curve_idx];
+
+            (curve.point_at_pos(t), curve_direction(curve, t))
+        };
+
+        let normal = PathIn::Point::from_components(&[-tangent.y(), tangent.x()]);
+        base_point + normal * offset
+    };
+
+    // Distort each of the shapes in turn
+    shapes.iter()
+        .map(|shape| distort_path(shape, map_point, step_len, max_error))
+        .collect()
+}
@@ -46,6 +46,7 @@ mod length;
 mod walk;
 mod distort;
 mod nearest_point;
+mod monotonic;
 
 pub mod path;
 pub mod vectorize;
@@ -75,5 +76,6 @@ pub use length::*;
 pub use walk::*;
 pub use distort::*;
 pub use nearest_point::*;
+pub use monotonic::*;
 
 pub use super::geo::*;
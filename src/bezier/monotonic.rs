@@ -0,0 +1,56 @@
+use super::curve::*;
+use super::section::*;
+use super::characteristics::*;
+use crate::geo::*;
+use crate::consts::*;
+
+///
+/// Finds the t values where a curve should be divided to produce sections that are monotonic in every dimension and
+/// which have no inflection points
+///
+/// The values are returned in ascending order, starting at 0.0 and ending at 1.0, so each neighbouring pair of values
+/// describes a monotonic section of the curve. Extremities are found using `find_extremities()` and inflection points
+/// using the curve's features.
+///
+pub fn monotonic_t_values<C>(curve: &C) -> Vec<f64>
+where
+    C:          BezierCurve,
+    C::Point:   Coordinate+Coordinate2D,
+{
+    // Split at the extremities (the curve is monotonic in every dimension between these points)
+    let mut t_values = curve.find_extremities();
+
+    // Also split at the inflection points, so that every section curves in only one direction
+    match curve.features(CLOSE_DISTANCE) {
+        CurveFeatures::SingleInflectionPoint(t)         => { t_values.push(t); }
+        CurveFeatures::DoubleInflectionPoint(t1, t2)    => { t_values.push(t1); t_values.push(t2); }
+        _                                               => { }
+    }
+
+    // Sort the points and remove any that are too close together to make a section
+    t_values.retain(|t| *t > SMALL_T_DISTANCE && *t < 1.0-SMALL_T_DISTANCE);
+    t_values.sort_by(|t1, t2| t1.total_cmp(t2));
+    t_values.dedup_by(|t1, t2| (*t1-*t2).abs() < SMALL_T_DISTANCE);
+
+    t_values.insert(0, 0.0);
+    t_values.push(1.0);
+
+    t_values
+}
+
+///
+/// Divides a curve into sections that are monotonic in every dimension (so each section is both x- and y-monotonic)
+/// and which have no inflection points
+///
+/// The `original_curve_t_values()` function can be used to find the range of the original curve that each section covers.
+///
+pub fn monotonic_sections<C>(curve: &C) -> Vec<CurveSection<'_, C>>
+where
+    C:          BezierCurve,
+    C::Point:   Coordinate+Coordinate2D,
+{
+    monotonic_t_values(curve)
+        .windows(2)
+        .map(|t_range| curve.section(t_range[0], t_range[1]))
+        .collect()
+}
@@ -0,0 +1,288 @@
+use super::fill_settings::*;
+use super::fill_paths::*;
+use super::super::*;
+use super::super::super::*;
+use super::super::super::super::geo::*;
+use super::super::super::super::consts::*;
+
+///
+/// A gap can only join a stroke to itself if the part of the stroke between the two ends of the gap is longer than this multiple
+/// of the maximum gap size
+///
+const MIN_SELF_GAP_LENGTH_RATIO: f64 = 2.0;
+
+///
+/// Identifies a position on one of the strokes passed to `find_stroke_gaps()`
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StrokePosition {
+    /// The start point of the stroke with the specified index
+    Start(usize),
+
+    /// The end point of the stroke with the specified index
+    End(usize),
+
+    /// A point part of the way along one of the curves in a stroke
+    Curve { path_index: usize, curve_index: usize, curve_t: f64 }
+}
+
+///
+/// Describes a gap between the end of a stroke and another stroke, and the line that can be used to bridge it
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StrokeGap<Point> {
+    /// The end of the stroke where the gap starts (this is always `StrokePosition::Start` or `StrokePosition::End`)
+    pub from: StrokePosition,
+
+    /// The position on the stroke where the gap ends
+    pub to: StrokePosition,
+
+    /// The line that bridges the gap
+    pub bridge: (Point, Point)
+}
+
+///
+/// The curves of a stroke along with their lengths
+///
+struct StrokeCurves<Point: Coordinate> {
+    curves:     Vec<Curve<Point>>,
+    bounds:     Vec<Bounds<Point>>,
+    lengths:    Vec<f64>,
+    is_closed:  bool
+}
+
+impl<Point: Coordinate+Coordinate2D> StrokeCurves<Point> {
+    ///
+    /// Reads the curves from a stroke
+    ///
+    fn from_path<Path: BezierPath<Point=Point>>(path: &Path) -> StrokeCurves<Point> {
+        let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+        let bounds      = curves.iter().map(|curve| curve.bounding_box()).collect();
+        let lengths     = curves.iter().map(|curve| curve_length(curve, 0.01)).collect();
+        let is_closed   = match (curves.first(), curves.last()) {
+            (Some(first), Some(last))   => first.start_point().is_near_to(&last.end_point(), SMALL_DISTANCE),
+            _                           => false
+        };
+
+        StrokeCurves { curves, bounds, lengths, is_closed }
+    }
+
+    ///
+    /// The start and end point of this stroke, if it has any curves
+    ///
+    fn end_points(&self) -> Option<(Point, Point)> {
+        match (self.curves.first(), self.curves.last()) {
+            (Some(first), Some(last))   => Some((first.start_point(), last.end_point())),
+            _                           => None
+        }
+    }
+
+    ///
+    /// The length along this stroke from one of its ends to a point on one of its curves
+    ///
+    fn length_from_end(&self, from_start: bool, curve_index: usize, curve_t: f64) -> f64 {
+        let curve = &self.curves[curve_index];
+
+        // Sections with no length are skipped (these can't be measured)
+        if from_start {
+            let section_length = if curve_t > 0.0 { curve_length(&curve.section(0.0, curve_t), 0.01) } else { 0.0 };
+            self.lengths[0..curve_index].iter().sum::<f64>() + section_length
+        } else {
+            let section_length = if curve_t < 1.0 { curve_length(&curve.section(curve_t, 1.0), 0.01) } else { 0.0 };
+            self.lengths[(curve_index+1)..].iter().sum::<f64>() + section_length
+        }
+    }
+}
+
+///
+/// Finds the gaps between the ends of a set of strokes and the other strokes
+///
+/// Hand-drawn line art often has small gaps where strokes were meant to meet, which will cause flood fills to leak out of the
+/// regions they were meant to fill. This finds the ends of strokes that are within `max_gap` of another stroke (or another part of
+/// the same stroke) without touching it, and returns lines that will close the gaps. Gaps to the end of another stroke are
+/// preferred over gaps to the middle of a stroke. Closed strokes don't have ends, but can still be the target of a gap.
+///
+pub fn find_stroke_gaps<Path>(strokes: &[Path], max_gap: f64) -> Vec<StrokeGap<Path::Point>>
+where
+    Path:           BezierPath,
+    Path::Point:    Coordinate+Coordinate2D,
+{
+    let strokes         = strokes.iter().map(|stroke| StrokeCurves::from_path(stroke)).collect::<Vec<_>>();
+    let mut gaps        = vec![];
+
+    // The ends of all the open strokes, as the index of the stroke, whether or not it's the start, and the position of the end
+    let ends            = strokes.iter()
+        .enumerate()
+        .filter(|(_, stroke)| !stroke.is_closed)
+        .flat_map(|(path_index, stroke)| stroke.end_points().map(|(start, end)| vec![(path_index, true, start), (path_index, false, end)]))
+        .flatten()
+        .collect::<Vec<_>>();
+    let end_position    = |path_index: usize, is_start: bool| if is_start { StrokePosition::Start(path_index) } else { StrokePosition::End(path_index) };
+
+    for (from_index, from_start, from_point) in ends.iter() {
+        let (from_index, from_start)    = (*from_index, *from_start);
+        let from                        = end_position(from_index, from_start);
+
+        // Find the nearest point on the other curves (ignoring the parts of this stroke that are near to the end we're looking at)
+        let mut nearest_curve: Option<(StrokePosition, Path::Point, f64)> = None;
+
+        for (path_index, stroke) in strokes.iter().enumerate() {
+            for (curve_index, (curve, bounds)) in stroke.curves.iter().zip(stroke.bounds.iter()).enumerate() {
+                // Skip curves that are too far away
+                let min = bounds.min() - Path::Point::from_components(&[max_gap, max_gap]);
+                let max = bounds.max() + Path::Point::from_components(&[max_gap, max_gap]);
+                if from_point.x() < min.x() || from_point.y() < min.y() || from_point.x() > max.x() || from_point.y() > max.y() { continue; }
+
+                let curve_t     = curve.nearest_t(from_point);
+                let point       = curve.point_at_pos(curve_t);
+                let distance    = point.distance_to(from_point);
+
+                if distance > max_gap { continue; }
+                if path_index == from_index && stroke.length_from_end(from_start, curve_index, curve_t) <= max_gap * MIN_SELF_GAP_LENGTH_RATIO { continue; }
+
+                if nearest_curve.as_ref().map(|(_, _, nearest_distance)| distance < *nearest_distance).unwrap_or(true) {
+                    nearest_curve = Some((StrokePosition::Curve { path_index, curve_index, curve_t }, point, distance));
+                }
+            }
+        }
+
+        // There's no gap if the end already touches another stroke (or if there's nothing nearby)
+        let nearest_curve = match nearest_curve {
+            Some(nearest_curve) => nearest_curve,
+            None                => continue
+        };
+        if nearest_curve.2 <= SMALL_DISTANCE { continue; }
+
+        // Prefer to join to the end of another stroke
+        let nearest_end = ends.iter()
+            .filter(|(to_index, to_start, _)| (*to_index, *to_start) != (from_index, from_start))
+            .filter(|(to_index, _, _)| *to_index != from_index || strokes[from_index].lengths.iter().sum::<f64>() > max_gap * MIN_SELF_GAP_LENGTH_RATIO)
+            .map(|(to_index, to_start, to_point)| (end_position(*to_index, *to_start), *to_point, to_point.distance_to(from_point)))
+            .filter(|(_, _, distance)| *distance <= max_gap)
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+
+        let (to, to_point, _) = nearest_end.unwrap_or(nearest_curve);
+
+        // Gaps between two ends will be found from both ends: only generate one bridge for them
+        if gaps.iter().any(|gap: &StrokeGap<_>| gap.from == to && gap.to == from) { continue; }
+
+        gaps.push(StrokeGap {
+            from:   from,
+            to:     to,
+            bridge: (*from_point, to_point)
+        });
+    }
+
+    gaps
+}
+
+///
+/// Returns a copy of a set of strokes with extra strokes added to close any gaps smaller than `max_gap` between them
+///
+/// See `find_stroke_gaps()` for details of how the gaps are found. The bridging strokes are straight lines that are added after
+/// the original strokes.
+///
+pub fn close_stroke_gaps<PathIn, PathOut>(strokes: &[PathIn], max_gap: f64) -> Vec<PathOut>
+where
+    PathIn:         BezierPath,
+    PathIn::Point:  Coordinate+Coordinate2D,
+    PathOut:        BezierPathFactory<Point=PathIn::Point>,
+{
+    let gaps = find_stroke_gaps(strokes, max_gap);
+
+    strokes.iter()
+        .map(|stroke| PathOut::from_path(stroke))
+        .chain(gaps.into_iter().map(|gap| {
+            let (start, end) = gap.bridge;
+            PathOut::from_points(start, vec![(start + (end-start)*(1.0/3.0), start + (end-start)*(2.0/3.0), end)])
+        }))
+        .collect()
+}
+
+///
+/// Flood-fills the region around a point enclosed by a set of strokes, closing any gaps smaller than `max_gap` between them
+///
+/// Unlike the `min_gap` setting in `FillSettings`, which stops the fill from escaping through gaps, this closes the gaps
+/// with straight lines before filling so the filled region meets up exactly with the strokes that enclose it.
+///
+pub fn flood_fill_closing_gaps<PathIn, PathOut>(center: PathIn::Point, options: &FillSettings, strokes: &[PathIn], max_gap: f64) -> Option<Vec<PathOut>>
+where
+    PathIn:         BezierPath,
+    PathIn::Point:  Coordinate+Coordinate2D,
+    PathOut:        BezierPathFactory<Point=PathIn::Point>,
+{
+    let closed_strokes = close_stroke_gaps::<_, (PathIn::Point, Vec<(PathIn::Point, PathIn::Point, PathIn::Point)>)>(strokes, max_gap);
+
+    flood_fill_concave_paths(center, options, &closed_strokes)
+}
+
+///
+/// Divides the curves of a stroke at any of a set of points that are part of the way along them
+///
+fn divide_stroke<Path>(stroke: &Path, points: &[Path::Point]) -> (Path::Point, Vec<(Path::Point, Path::Point, Path::Point)>)
+where
+    Path:           BezierPath,
+    Path::Point:    Coordinate+Coordinate2D,
+{
+    let mut divided = vec![];
+
+    for curve in path_to_curves::<_, Curve<_>>(stroke) {
+        let bounds      = curve.bounding_box::<Bounds<_>>();
+        let min         = bounds.min() - Path::Point::from_components(&[SMALL_DISTANCE, SMALL_DISTANCE]);
+        let max         = bounds.max() + Path::Point::from_components(&[SMALL_DISTANCE, SMALL_DISTANCE]);
+
+        let mut curve_ts = points.iter()
+            .filter(|point| point.x() >= min.x() && point.y() >= min.y() && point.x() <= max.x() && point.y() <= max.y())
+            .map(|point| (point, curve.nearest_t(point)))
+            .filter(|(point, curve_t)| *curve_t > 0.0 && *curve_t < 1.0 && curve.point_at_pos(*curve_t).is_near_to(point, SMALL_DISTANCE))
+            .map(|(_, curve_t)| curve_t)
+            .collect::<Vec<_>>();
+        curve_ts.sort_by(|a, b| a.total_cmp(b));
+
+        let mut last_t = 0.0;
+        for curve_t in curve_ts.into_iter().chain(Some(1.0)) {
+            let (_, (cp1, cp2), end_point) = curve.section(last_t, curve_t).all_points();
+            divided.push((cp1, cp2, end_point));
+
+            last_t = curve_t;
+        }
+    }
+
+    (stroke.start_point(), divided)
+}
+
+///
+/// Finds the regions enclosed by a set of strokes, closing any gaps smaller than `max_gap` between them
+///
+/// The strokes and the bridging strokes from `close_stroke_gaps()` are collided into a `GraphPath`, and every bounded
+/// region that they form is returned as a face (see `GraphPath::faces()`). The edges around each face are labelled with the index
+/// of the stroke they came from in the result of `close_stroke_gaps()`: indexes greater than or equal to `strokes.len()`
+/// are bridges. Parts of strokes that don't separate two regions (such as ends that overshoot a corner) are not part of any face.
+///
+/// A paint bucket fill can use the face that contains the point that was clicked, which will meet up exactly with the strokes
+/// around it.
+///
+pub fn stroke_regions_closing_gaps<PathIn, PathOut>(strokes: &[PathIn], max_gap: f64, accuracy: f64) -> Vec<GraphPathFace<PathOut, usize>>
+where
+    PathIn:         BezierPath,
+    PathIn::Point:  Coordinate+Coordinate2D,
+    PathOut:        BezierPathFactory<Point=PathIn::Point>,
+{
+    let bridges         = close_stroke_gaps::<_, (PathIn::Point, Vec<(PathIn::Point, PathIn::Point, PathIn::Point)>)>(strokes, max_gap).split_off(strokes.len());
+
+    // Colliding the graph won't find where the end of a stroke or a bridge touches the middle of another stroke, so the strokes are divided there
+    let end_points      = strokes.iter()
+        .map(|stroke| (stroke.start_point(), stroke.points().last().map(|(_, _, end_point)| end_point)))
+        .chain(bridges.iter().map(|(start_point, points)| (*start_point, points.last().map(|(_, _, end_point)| *end_point))))
+        .flat_map(|(start_point, end_point)| Some(start_point).into_iter().chain(end_point))
+        .collect::<Vec<_>>();
+    let divided_strokes = strokes.iter().map(|stroke| divide_stroke(stroke, &end_points));
+
+    // Strokes don't enclose anything by themselves, so they're added to the graph without closing them
+    let mut graph       = divided_strokes.chain(bridges)
+        .enumerate()
+        .fold(GraphPath::new(), |graph, (stroke_index, stroke)| graph.merge(GraphPath::from_open_path(&stroke, stroke_index)));
+    graph.self_collide(accuracy);
+
+    graph.faces()
+}
@@ -0,0 +1,226 @@
+use super::fill_settings::*;
+use super::fill_convex::*;
+use super::fill_concave::*;
+use super::super::*;
+use super::super::super::*;
+use super::super::super::super::geo::*;
+use super::super::super::super::consts::*;
+
+use std::ops::{Range};
+
+/// Maximum number of curves to store in a leaf node of the ray index
+const MAX_CURVES_PER_NODE: usize = 4;
+
+///
+/// Describes where a ray hit one of the paths in a `PathRayIndex`
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathRayCollision {
+    /// The index of the path that the ray hit
+    pub path_index: usize,
+
+    /// The index of the curve within the path
+    pub curve_index: usize,
+
+    /// The t value along the curve where the ray hit it
+    pub curve_t: f64
+}
+
+///
+/// A node in the bounding box hierarchy used by the ray index
+///
+struct RayIndexNode<Coord: Coordinate> {
+    /// The bounds of all the curves in this node
+    bounds: Bounds<Coord>,
+
+    /// The curves in this node (indexes into the `curves` list of the ray index)
+    curves: Range<usize>,
+
+    /// The child nodes of this node, if it's not a leaf node
+    children: Option<(usize, usize)>
+}
+
+///
+/// An index of the curves in a set of bezier paths, which can be used to quickly find where rays hit them
+///
+/// This can be used to generate the ray casting function for `flood_fill_concave()` and `flood_fill_convex()`, which makes
+/// it possible to use the flood fill algorithms to find the regions enclosed by a drawing made out of bezier paths. The
+/// curves are stored in a bounding box hierarchy, so casting a ray only needs to test the curves it is near to.
+///
+pub struct PathRayIndex<Coord: Coordinate> {
+    /// The curves in the paths, along with their bounds, the index of the path they're from and their index in that path
+    curves: Vec<(Curve<Coord>, Bounds<Coord>, usize, usize)>,
+
+    /// The nodes of the bounding box hierarchy (the root node is the first node in this list)
+    nodes: Vec<RayIndexNode<Coord>>
+}
+
+impl<Coord: Coordinate+Coordinate2D> PathRayIndex<Coord> {
+    ///
+    /// Creates a ray index for a set of paths
+    ///
+    /// Paths can be open or closed: rays collide with the curves that make up each path.
+    ///
+    pub fn new<'a, Path, PathIter>(paths: PathIter) -> PathRayIndex<Coord>
+    where
+        Path:       'a+BezierPath<Point=Coord>,
+        PathIter:   IntoIterator<Item=&'a Path>,
+    {
+        // Gather the curves from all of the paths
+        let mut curves = paths.into_iter()
+            .enumerate()
+            .flat_map(|(path_index, path)| {
+                path_to_curves::<_, Curve<_>>(path)
+                    .enumerate()
+                    .map(move |(curve_index, curve)| (curve, curve.bounding_box::<Bounds<_>>(), path_index, curve_index))
+            })
+            .collect::<Vec<_>>();
+
+        // Build the bounding box hierarchy
+        let mut nodes = vec![];
+        if !curves.is_empty() {
+            let num_curves = curves.len();
+            Self::build_node(&mut curves, 0..num_curves, &mut nodes);
+        }
+
+        PathRayIndex { curves, nodes }
+    }
+
+    ///
+    /// Adds the node for a range of curves to the bounding box hierarchy, returning its index
+    ///
+    fn build_node(curves: &mut [(Curve<Coord>, Bounds<Coord>, usize, usize)], range: Range<usize>, nodes: &mut Vec<RayIndexNode<Coord>>) -> usize {
+        // Create the node for this range
+        let bounds      = curves[range.clone()].iter()
+            .fold(curves[range.start].1, |bounds, (_, curve_bounds, _, _)| bounds.union_bounds(*curve_bounds));
+        let node_index  = nodes.len();
+
+        nodes.push(RayIndexNode { bounds, curves: range.clone(), children: None });
+
+        if range.len() > MAX_CURVES_PER_NODE {
+            // Split along the longest axis of the bounds
+            let size        = bounds.max() - bounds.min();
+            let axis        = if size.x() >= size.y() { 0 } else { 1 };
+            let center_of   = |curve_bounds: &Bounds<Coord>| curve_bounds.min().get(axis) + curve_bounds.max().get(axis);
+
+            curves[range.clone()].sort_by(|a, b| center_of(&a.1).total_cmp(&center_of(&b.1)));
+
+            let mid         = (range.start + range.end) / 2;
+            let left        = Self::build_node(curves, range.start..mid, nodes);
+            let right       = Self::build_node(curves, mid..range.end, nodes);
+
+            nodes[node_index].children = Some((left, right));
+        }
+
+        node_index
+    }
+
+    ///
+    /// True if the ray starting at `from` and moving in the direction of `direction` passes through the specified bounds
+    ///
+    fn ray_hits_bounds(from: &Coord, direction: &Coord, bounds: &Bounds<Coord>) -> bool {
+        let (min, max)          = (bounds.min(), bounds.max());
+        let mut t_min           = 0.0f64;
+        let mut t_max           = f64::MAX;
+
+        for axis in 0..2 {
+            let (start, delta)          = (from.get(axis), direction.get(axis));
+            let (axis_min, axis_max)    = (min.get(axis) - SMALL_DISTANCE, max.get(axis) + SMALL_DISTANCE);
+
+            if delta == 0.0 {
+                // Ray is parallel to this axis, so it has to start within the bounds
+                if start < axis_min || start > axis_max { return false; }
+            } else {
+                let t1 = (axis_min - start) / delta;
+                let t2 = (axis_max - start) / delta;
+
+                t_min = t_min.max(t1.min(t2));
+                t_max = t_max.min(t1.max(t2));
+
+                if t_min > t_max { return false; }
+            }
+        }
+
+        true
+    }
+
+    ///
+    /// Finds the places where the ray from `from` through `to` hits the paths in this index
+    ///
+    /// Only collisions in the direction of the ray are returned. This has the form needed for the ray casting function
+    /// for the flood fill algorithms.
+    ///
+    pub fn cast_ray(&self, from: Coord, to: Coord) -> Vec<RayCollision<Coord, PathRayCollision>> {
+        let direction       = to - from;
+        let ray             = (from, to);
+        let mut collisions  = vec![];
+
+        if self.nodes.is_empty() || (direction.x() == 0.0 && direction.y() == 0.0) {
+            return collisions;
+        }
+
+        // Search the bounding box hierarchy for curves that the ray might hit
+        let mut to_visit = vec![0];
+
+        while let Some(node_index) = to_visit.pop() {
+            let node = &self.nodes[node_index];
+
+            if !Self::ray_hits_bounds(&from, &direction, &node.bounds) { continue; }
+
+            if let Some((left, right)) = node.children {
+                to_visit.push(left);
+                to_visit.push(right);
+            } else {
+                for (curve, curve_bounds, path_index, curve_index) in self.curves[node.curves.clone()].iter() {
+                    if !Self::ray_hits_bounds(&from, &direction, curve_bounds) { continue; }
+
+                    collisions.extend(curve_intersects_ray(curve, &ray).into_iter()
+                        .filter(|(_, line_t, _)| *line_t >= 0.0)
+                        .map(|(curve_t, _, position)| RayCollision::new(position, PathRayCollision {
+                            path_index:     *path_index,
+                            curve_index:    *curve_index,
+                            curve_t:        curve_t
+                        })));
+                }
+            }
+        }
+
+        collisions
+    }
+}
+
+///
+/// Flood-fills the region around a point that is enclosed by a set of paths
+///
+/// This is the same as `flood_fill_concave()`, except the ray casting function is generated from the paths (which can be
+/// open or closed). The result is `None` if the region could not be traced (for example, if the point is not enclosed by the
+/// paths and `options` does not allow the fill to escape through gaps).
+///
+pub fn flood_fill_concave_paths<PathIn, PathOut>(center: PathIn::Point, options: &FillSettings, paths: &[PathIn]) -> Option<Vec<PathOut>>
+where
+    PathIn:             BezierPath,
+    PathIn::Point:      Coordinate+Coordinate2D,
+    PathOut:            BezierPathFactory<Point=PathIn::Point>,
+{
+    let index = PathRayIndex::new(paths.iter());
+
+    flood_fill_concave(center, options, |from, to| index.cast_ray(from, to))
+}
+
+///
+/// Flood-fills the region around a point that is enclosed by a set of paths, only filling the parts of the region that can
+/// be reached by a straight line from the center point
+///
+/// This is the same as `flood_fill_convex()`, except the ray casting function is generated from the paths (which can be open
+/// or closed).
+///
+pub fn flood_fill_convex_paths<PathIn, PathOut>(center: PathIn::Point, options: &FillSettings, paths: &[PathIn]) -> Option<PathOut>
+where
+    PathIn:             BezierPath,
+    PathIn::Point:      Coordinate+Coordinate2D,
+    PathOut:            BezierPathFactory<Point=PathIn::Point>,
+{
+    let index = PathRayIndex::new(paths.iter());
+
+    flood_fill_convex(center, options, |from, to| index.cast_ray(from, to))
+}
@@ -1,7 +1,11 @@
 mod fill_convex;
 mod fill_concave;
 mod fill_settings;
+mod fill_paths;
+mod close_gaps;
 
 pub use self::fill_convex::*;
 pub use self::fill_concave::*;
 pub use self::fill_settings::*;
+pub use self::fill_paths::*;
+pub use self::close_gaps::*;
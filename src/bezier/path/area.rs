@@ -0,0 +1,331 @@
+use super::path::*;
+use super::point::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::super::geo::*;
+use super::super::super::line::*;
+
+use smallvec::*;
+
+use std::ops::{Add, Neg};
+
+///
+/// The area and the first and second moments of area of a region enclosed by one or more paths
+///
+/// The moments are the integrals over the region of `x`, `y`, `x^2`, `y^2` and `xy`. They're signed in the same way as
+/// the area: a region enclosed by a path with an anticlockwise ordering (where the y axis points upwards) has a positive
+/// area.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathMoments {
+    /// The area of the region
+    pub area: f64,
+
+    /// The first moment of area in the x direction (the integral of x over the region)
+    pub moment_x: f64,
+
+    /// The first moment of area in the y direction (the integral of y over the region)
+    pub moment_y: f64,
+
+    /// The integral of `x^2` over the region
+    pub moment_xx: f64,
+
+    /// The integral of `y^2` over the region
+    pub moment_yy: f64,
+
+    /// The integral of `xy` over the region
+    pub moment_xy: f64,
+}
+
+///
+/// The principal axes of the second moments of area of a region
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrincipalAxes {
+    /// The angle of the axis with the smallest moment of inertia, in radians anticlockwise from the x axis (this is the direction the shape is most stretched out in)
+    pub angle: f64,
+
+    /// The second moment of area about the axis at `angle` through the centroid
+    pub min_moment: f64,
+
+    /// The second moment of area about the axis perpendicular to `angle` through the centroid
+    pub max_moment: f64,
+}
+
+impl PathMoments {
+    ///
+    /// The moments of a region with no area
+    ///
+    pub fn zero() -> PathMoments {
+        PathMoments { area: 0.0, moment_x: 0.0, moment_y: 0.0, moment_xx: 0.0, moment_yy: 0.0, moment_xy: 0.0 }
+    }
+
+    ///
+    /// Returns the centroid of the region, or `None` if the region has no area
+    ///
+    pub fn centroid(&self) -> Option<Coord2> {
+        if self.area == 0.0 {
+            None
+        } else {
+            Some(Coord2(self.moment_x / self.area, self.moment_y / self.area))
+        }
+    }
+
+    ///
+    /// Returns the second moments of area relative to the centroid, as `(xx, yy, xy)`
+    ///
+    /// These are the integrals of `(x-cx)^2`, `(y-cy)^2` and `(x-cx)(y-cy)` over the region, where `(cx, cy)` is the centroid.
+    ///
+    pub fn central_moments(&self) -> (f64, f64, f64) {
+        if self.area == 0.0 {
+            (0.0, 0.0, 0.0)
+        } else {
+            (
+                self.moment_xx - self.moment_x*self.moment_x/self.area,
+                self.moment_yy - self.moment_y*self.moment_y/self.area,
+                self.moment_xy - self.moment_x*self.moment_y/self.area,
+            )
+        }
+    }
+
+    ///
+    /// Returns the inertia tensor of the region about its centroid (with a unit density)
+    ///
+    /// This is the matrix `[[Ixx, -Ixy], [-Ixy, Iyy]]`, where `Ixx` is the second moment about the x axis (the integral of
+    /// `(y-cy)^2`), `Iyy` is the second moment about the y axis and `Ixy` is the product moment.
+    ///
+    pub fn inertia_tensor(&self) -> [[f64; 2]; 2] {
+        let (xx, yy, xy) = self.central_moments();
+
+        [[yy, -xy], [-xy, xx]]
+    }
+
+    ///
+    /// Finds the principal axes of the region through its centroid
+    ///
+    pub fn principal_axes(&self) -> PrincipalAxes {
+        let (xx, yy, xy)    = self.central_moments();
+
+        // Eigenvalues of the covariance matrix [[xx, xy], [xy, yy]]: the larger one corresponds to the direction the region is spread out in
+        let mean            = (xx + yy) * 0.5;
+        let radius          = (((xx - yy) * 0.5).powi(2) + xy*xy).sqrt();
+        let angle           = 0.5 * f64::atan2(2.0*xy, xx - yy);
+
+        PrincipalAxes {
+            angle:      angle,
+            min_moment: mean - radius,
+            max_moment: mean + radius,
+        }
+    }
+}
+
+impl Add for PathMoments {
+    type Output = PathMoments;
+
+    fn add(self, other: PathMoments) -> PathMoments {
+        PathMoments {
+            area:       self.area + other.area,
+            moment_x:   self.moment_x + other.moment_x,
+            moment_y:   self.moment_y + other.moment_y,
+            moment_xx:  self.moment_xx + other.moment_xx,
+            moment_yy:  self.moment_yy + other.moment_yy,
+            moment_xy:  self.moment_xy + other.moment_xy,
+        }
+    }
+}
+
+impl Neg for PathMoments {
+    type Output = PathMoments;
+
+    fn neg(self) -> PathMoments {
+        PathMoments {
+            area:       -self.area,
+            moment_x:   -self.moment_x,
+            moment_y:   -self.moment_y,
+            moment_xx:  -self.moment_xx,
+            moment_yy:  -self.moment_yy,
+            moment_xy:  -self.moment_xy,
+        }
+    }
+}
+
+///
+/// Returns the coefficients of one component of a cubic bezier curve in the power basis (lowest power first)
+///
+#[inline]
+fn power_basis<Point: Coordinate>(dimension: usize, w1: &Point, w2: &Point, w3: &Point, w4: &Point) -> [f64; 4] {
+    let w1 = w1.get(dimension);
+    let w2 = w2.get(dimension);
+    let w3 = w3.get(dimension);
+    let w4 = w4.get(dimension);
+
+    [
+        w1,
+        3.0*(w2-w1),
+        3.0*(w1-2.0*w2+w3),
+        w4-3.0*w3+3.0*w2-w1
+    ]
+}
+
+///
+/// Multiplies two polynomials in the power basis
+///
+#[inline]
+fn poly_mul(a: &[f64], b: &[f64]) -> SmallVec<[f64; 12]> {
+    let mut result = smallvec![0.0; a.len()+b.len()-1];
+
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            result[i+j] += a*b;
+        }
+    }
+
+    result
+}
+
+///
+/// Integrates a polynomial in the power basis over the range `0..1`
+///
+#[inline]
+fn poly_integrate(a: &[f64]) -> f64 {
+    a.iter().enumerate()
+        .map(|(power, coeff)| coeff / ((power+1) as f64))
+        .sum()
+}
+
+///
+/// Computes the contribution of a single curve to the moments of the region enclosed by a path using Green's theorem
+///
+fn curve_moments<C>(curve: &C) -> PathMoments
+where
+    C:          BezierCurve,
+    C::Point:   Coordinate+Coordinate2D,
+{
+    let (w1, (w2, w3), w4) = curve.all_points();
+
+    // Coefficients of x(t), y(t) and their derivatives
+    let x   = power_basis(0, &w1, &w2, &w3, &w4);
+    let y   = power_basis(1, &w1, &w2, &w3, &w4);
+    let dx  = [x[1], 2.0*x[2], 3.0*x[3]];
+    let dy  = [y[1], 2.0*y[2], 3.0*y[3]];
+
+    let xx  = poly_mul(&x, &x);
+    let yy  = poly_mul(&y, &y);
+
+    // Area is 1/2 integral(x dy - y dx)
+    let area        = 0.5 * (poly_integrate(&poly_mul(&x, &dy)) - poly_integrate(&poly_mul(&y, &dx)));
+
+    // integral(x dA) = 1/2 integral(x^2 dy), integral(y dA) = -1/2 integral(y^2 dx)
+    let moment_x    = 0.5 * poly_integrate(&poly_mul(&xx, &dy));
+    let moment_y    = -0.5 * poly_integrate(&poly_mul(&yy, &dx));
+
+    // integral(x^2 dA) = 1/3 integral(x^3 dy), integral(y^2 dA) = -1/3 integral(y^3 dx), integral(xy dA) = 1/2 integral(x^2 y dy)
+    let moment_xx   = poly_integrate(&poly_mul(&poly_mul(&xx, &x), &dy)) / 3.0;
+    let moment_yy   = -poly_integrate(&poly_mul(&poly_mul(&yy, &y), &dx)) / 3.0;
+    let moment_xy   = 0.5 * poly_integrate(&poly_mul(&poly_mul(&xx, &y), &dy));
+
+    PathMoments { area, moment_x, moment_y, moment_xx, moment_yy, moment_xy }
+}
+
+///
+/// Computes the signed area and the moments of the region enclosed by a path
+///
+/// These are computed exactly from the control points using Green's theorem. The path is treated as closed: if its end
+/// point is not the same as its start point, a straight line is added to close it. Paths with an anticlockwise ordering
+/// (when the y axis points upwards) have a positive area, and clockwise paths have a negative area. Self-intersecting
+/// paths will produce the sum of the signed areas of each of their loops.
+///
+pub fn path_moments<P>(path: &P) -> PathMoments
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+{
+    let mut moments = path_to_curves::<_, Curve<_>>(path)
+        .map(|curve| curve_moments(&curve))
+        .fold(PathMoments::zero(), |total, moments| total + moments);
+
+    // Close the path if it's not already closed
+    let start_point = path.start_point();
+    let end_point   = path.points().last().map(|(_, _, end_point)| end_point).unwrap_or(start_point);
+
+    if start_point != end_point {
+        let closing_line = line_to_bezier::<Curve<_>>(&(end_point, start_point));
+        moments = moments + curve_moments(&closing_line);
+    }
+
+    moments
+}
+
+///
+/// Returns the signed area of the region enclosed by a path
+///
+/// The area is positive if the path is anticlockwise (when the y axis points upwards) and negative if it's clockwise.
+/// See `path_moments()` for details.
+///
+pub fn path_signed_area<P>(path: &P) -> f64
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+{
+    path_moments(path).area
+}
+
+///
+/// Returns the centroid of the region enclosed by a path, or `None` if the path has no area
+///
+pub fn path_centroid<P>(path: &P) -> Option<P::Point>
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+{
+    path_moments(path).centroid()
+        .map(|centroid| P::Point::from_components(&[centroid.x(), centroid.y()]))
+}
+
+///
+/// Computes the area and the moments of the region enclosed by a set of paths, treating paths that are nested inside
+/// other paths as holes
+///
+/// This follows the same conventions as the arithmetic operations: each path is a perimeter of the region and the
+/// ordering of the points in each path is ignored. A path that's inside an odd number of other paths is a hole, and
+/// a path inside an even number of other paths is a part of the region (ie, this uses the even-odd rule). The result
+/// always has a positive area unless the holes are bigger than the paths that contain them.
+///
+pub fn paths_moments<P>(paths: &[P]) -> PathMoments
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+{
+    let mut total = PathMoments::zero();
+
+    for (path_idx, path) in paths.iter().enumerate() {
+        // Always consider the path as if it were anticlockwise
+        let moments     = path_moments(path);
+        let moments     = if moments.area < 0.0 { -moments } else { moments };
+
+        // Paths inside an odd number of other paths are holes
+        let start_point = path.start_point();
+        let depth       = paths.iter().enumerate()
+            .filter(|(other_idx, other_path)| *other_idx != path_idx && path_contains_point(*other_path, &start_point))
+            .count();
+
+        if depth % 2 == 0 {
+            total = total + moments;
+        } else {
+            total = total + -moments;
+        }
+    }
+
+    total
+}
+
+///
+/// Returns the area of the region enclosed by a set of paths, treating paths that are nested inside other paths as holes
+///
+pub fn paths_area<P>(paths: &[P]) -> f64
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+{
+    paths_moments(paths).area
+}
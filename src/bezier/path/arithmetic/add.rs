@@ -1,5 +1,6 @@
 use super::ray_cast::*;
 use super::super::path::*;
+use super::super::fill_rule::*;
 use super::super::graph_path::*;
 use super::super::super::super::geo::*;
 
@@ -19,7 +20,15 @@ impl<Point: Coordinate+Coordinate2D> GraphPath<Point, PathLabel> {
     ///
     pub fn set_exterior_by_adding(&mut self) {
         // Use an even-odd winding rule (all edges are considered 'external')
-        self.set_edge_kinds_by_ray_casting(|path_crossings| (path_crossings[0]&1) != 0 || (path_crossings[1]&1) != 0);
+        self.set_exterior_by_adding_with_fill_rule(FillRule::EvenOdd);
+    }
+
+    ///
+    /// As for `set_exterior_by_adding()`, except the fill rule used to determine which points are inside each of the two
+    /// source paths can be specified
+    ///
+    pub fn set_exterior_by_adding_with_fill_rule(&mut self, fill_rule: FillRule) {
+        self.set_edge_kinds_by_ray_casting(|path_crossings| fill_rule.is_inside(path_crossings[0]) || fill_rule.is_inside(path_crossings[1]));
     }
 
     ///
@@ -27,7 +36,7 @@ impl<Point: Coordinate+Coordinate2D> GraphPath<Point, PathLabel> {
     ///
     pub fn set_exterior_by_removing_interior_points(&mut self) {
         // All points inside the path are considered 'interior' (non-zero winding rule)
-        self.set_edge_kinds_by_ray_casting(|path_crossings| path_crossings[0] != 0 || path_crossings[1] != 0);
+        self.set_exterior_by_adding_with_fill_rule(FillRule::NonZero);
     }
 }
 
@@ -73,6 +82,32 @@ where
     merged_path.exterior_paths()
 }
 
+///
+/// Generates the path formed by adding two sets of paths, using a fill rule to determine which points are inside each set
+///
+/// Unlike `path_add()`, the paths in `path1` and `path2` may overlap themselves: the result is the union of the regions that
+/// `fill_rule` considers to be inside `path1` and inside `path2`. For example, using `FillRule::NonZero` will produce the
+/// correct result for SVG or PDF content that is filled with the non-zero winding rule.
+///
+pub fn path_add_with_fill_rule<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, fill_rule: FillRule, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    // Create a graph path from both sides (preserving the direction of the edges for the fill rule), colliding every edge to allow for self-overlapping paths
+    let mut merged_path = GraphPath::from_merged_paths_with_direction(path1.iter().map(|path| (path, PathLabel(0))));
+    merged_path         = merged_path.merge(GraphPath::from_merged_paths_with_direction(path2.iter().map(|path| (path, PathLabel(1)))));
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the 'add' algorithm
+    merged_path.set_exterior_by_adding_with_fill_rule(fill_rule);
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
+
 ///
 /// Generates the path formed by removing any interior points from an existing path. This considers only the outermost edges of the 
 /// path to be the true edges, so if there are sub-paths inside an outer path, they will be removed.
@@ -81,7 +116,8 @@ where
 /// and might self-overlap: this can be passed a drawing of a loop made by overlapping the ends and it will output two non-overlapping 
 /// subpaths.
 ///
-/// See `path_remove_overlapped_points()` for a version that considers all edges within the path to be exterior edges.
+/// See `path_remove_overlapped_points()` for a version that considers all edges within the path to be exterior edges, and
+/// `path_remove_interior_points_with_fill_rule()` for a version that uses a specific fill rule.
 ///
 pub fn path_remove_interior_points<P1: BezierPath, POut: BezierPathFactory>(path: &Vec<P1>, accuracy: f64) -> Vec<POut>
 where
@@ -107,6 +143,36 @@ where
     result
 }
 
+///
+/// Generates the path formed by removing any interior points from an existing path, using a fill rule to determine which
+/// points are inside the path
+///
+/// The result is a set of non-overlapping paths that enclose the region that `fill_rule` considers to be inside the original
+/// path. Unlike `path_remove_interior_points()`, the direction of each path is preserved, so with `FillRule::NonZero` a path
+/// that runs in the opposite direction to the path surrounding it will form a hole. `FillRule::EvenOdd` produces the same
+/// result as `path_remove_overlapped_points()`.
+///
+pub fn path_remove_interior_points_with_fill_rule<P1, POut>(path: &Vec<P1>, fill_rule: FillRule, accuracy: f64) -> Vec<POut>
+where
+    P1:         BezierPath,
+    P1::Point:  Coordinate+Coordinate2D,
+    POut:       BezierPathFactory<Point=P1::Point>,
+{
+    // Create the graph path from the source side (preserving the direction of the edges for the fill rule)
+    let mut merged_path = GraphPath::from_merged_paths_with_direction(path.iter().map(|path| (path, PathLabel(0))));
+
+    // Collide the path with itself to find the intersections
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the fill rule
+    merged_path.set_exterior_by_adding_with_fill_rule(fill_rule);
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
+
 ///
 /// Generates the path formed by removing any interior points from an existing path. This considers all edges to be exterior edges
 /// and will remove those that are obscured by another part of the path.
@@ -2,6 +2,7 @@ use super::add::*;
 use super::sub::*;
 use super::chain_add::*;
 use super::intersect::*;
+use super::xor::*;
 use super::super::path::*;
 use super::super::super::super::geo::*;
 
@@ -38,7 +39,10 @@ where
     Subtract(Vec<PathCombine<P>>),
 
     /// Intersects a series a paths (with the first path)
-    Intersect(Vec<PathCombine<P>>)
+    Intersect(Vec<PathCombine<P>>),
+
+    /// Exclusive-ors a series of paths (the result is the area covered by an odd number of the paths)
+    Xor(Vec<PathCombine<P>>)
 }
 
 ///
@@ -80,5 +84,18 @@ where
 
             result
         }
+
+        PathCombine::Xor(paths)                 => {
+            let mut path_iter   = paths.into_iter();
+            let result          = path_iter.next().unwrap_or_else(|| PathCombine::Path(vec![]));
+            let mut result      = path_combine(result, accuracy);
+
+            for to_xor in path_iter {
+                let to_xor      = path_combine(to_xor, accuracy);
+                result          = path_xor(&result, &to_xor, accuracy);
+            }
+
+            result
+        }
     }
 }
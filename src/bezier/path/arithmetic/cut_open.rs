@@ -0,0 +1,269 @@
+use super::super::path::*;
+use super::super::ray::*;
+use super::super::to_curves::*;
+use super::super::fill_rule::*;
+use super::super::graph_path::*;
+use super::super::super::curve::*;
+use super::super::super::normal::*;
+use super::super::super::super::geo::*;
+
+use std::collections::{HashSet};
+
+///
+/// A section of an open path that was generated by cutting it against a region
+///
+#[derive(Clone, Debug)]
+pub struct OpenPathSection<P: BezierPathFactory> {
+    /// The part of the original path covered by this section
+    pub path: P,
+
+    /// The start of this section on the original path, as a (curve index, t-value) pair
+    pub start: (usize, f64),
+
+    /// The end of this section on the original path, as a (curve index, t-value) pair
+    pub end: (usize, f64),
+}
+
+///
+/// The result of cutting an open path against a region
+///
+#[derive(Clone, Debug)]
+pub struct OpenPathCut<P: BezierPathFactory> {
+    /// The sections of the path that were inside the region, in the order they appear on the original path
+    pub interior_sections: Vec<OpenPathSection<P>>,
+
+    /// The sections of the path that were outside the region, in the order they appear on the original path
+    pub exterior_sections: Vec<OpenPathSection<P>>,
+}
+
+///
+/// Label used for the edges of the graph generated when cutting an open path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum CutOpenLabel {
+    /// Edge that is part of the curve with the specified index in the open path
+    OpenCurve(usize),
+
+    /// Edge that is part of the region the open path is being cut against
+    Region
+}
+
+impl<Point: Coordinate+Coordinate2D> GraphPath<Point, CutOpenLabel> {
+    ///
+    /// Sets the kinds of the edges from the open path: these become `Interior` if they are inside the region, or `Exterior` if
+    /// they are outside
+    ///
+    /// Edges from the open path that overlap the edge of the region are considered to be inside it.
+    ///
+    fn set_open_edge_kinds_by_ray_casting(&mut self, fill_rule: FillRule) {
+        let open_edges = self.all_edge_refs()
+            .filter(|edge_ref| self.edge_label(*edge_ref) != CutOpenLabel::Region)
+            .collect::<Vec<_>>();
+
+        for edge_ref in open_edges {
+            let kind = if self.open_edge_is_inside(edge_ref, fill_rule) { GraphPathEdgeKind::Interior } else { GraphPathEdgeKind::Exterior };
+            self.set_edge_kind(edge_ref, kind);
+        }
+    }
+
+    ///
+    /// Determines if an edge from the open path is inside the region by casting a ray at it and counting the region edges that
+    /// the ray crosses before it reaches it
+    ///
+    fn open_edge_is_inside(&self, target_edge: GraphEdgeRef, fill_rule: FillRule) -> bool {
+        // The ray is cast at the middle of the edge, unless that hits an intersection, in which case we try other points along the edge
+        for t in [0.5, 0.25, 0.75, 0.375, 0.625] {
+            let real_edge       = self.get_edge(target_edge);
+            let point           = real_edge.point_at_pos(t);
+            let normal          = real_edge.normal_at_pos(t);
+
+            let ray             = (point - normal, point);
+            let ray_direction   = ray.1 - ray.0;
+            let collisions      = group_overlapped_collisions(self, self.ray_collisions(&ray));
+
+            // Counts the number of times a group of collisions crosses the region (in the same way as `set_edge_kinds_by_ray_casting()`)
+            let region_crossings = |group: &[(GraphRayCollision, f64, f64, Point)]| {
+                group.iter()
+                    .filter(|(collision, _, _, _)| self.edge_label(collision.edge()) == CutOpenLabel::Region)
+                    .map(|(collision, curve_t, _, _)| ray_direction.dot(&self.get_edge(collision.edge()).normal_at_pos(*curve_t)).signum() as i32)
+                    .sum::<i32>()
+            };
+
+            let mut winding_number = 0;
+
+            for group in collisions {
+                let target_collision = group.iter()
+                    .find(|(collision, curve_t, _, _)| collision.edge() == target_edge && (curve_t-t).abs() < 0.001);
+
+                match target_collision {
+                    Some((collision, _, _, _)) => {
+                        if collision.is_intersection() {
+                            // Can't tell which order the edges at an intersection are crossed in
+                            break;
+                        }
+
+                        // Edges of the region that overlap the target edge can be crossed in either order, so the edge is inside if it's inside on either side
+                        let after_group = winding_number + region_crossings(&group);
+                        return fill_rule.is_inside(winding_number) || fill_rule.is_inside(after_group);
+                    }
+
+                    None => {
+                        winding_number += region_crossings(&group);
+                    }
+                }
+            }
+        }
+
+        // Couldn't find a ray that hits the edge cleanly
+        false
+    }
+
+    ///
+    /// Returns the edges that make up the curve with the specified index in the open path, in order, along with the range of
+    /// t-values that they cover on the original curve
+    ///
+    fn open_curve_edges(&self, curve_idx: usize, curve: &Curve<Point>) -> Vec<(GraphEdgeRef, f64, f64)> {
+        let mut remaining   = self.all_edge_refs()
+            .filter(|edge_ref| self.edge_label(*edge_ref) == CutOpenLabel::OpenCurve(curve_idx))
+            .collect::<HashSet<_>>();
+        let mut edges       = vec![];
+        let mut t_start     = 0.0;
+
+        // The edges form a chain starting at the start point of the curve
+        let start_point     = curve.start_point();
+        let mut next_edge   = remaining.iter()
+            .min_by(|a, b| self.get_edge(**a).start_point().distance_to(&start_point).total_cmp(&self.get_edge(**b).start_point().distance_to(&start_point)))
+            .copied();
+
+        while let Some(edge_ref) = next_edge {
+            remaining.remove(&edge_ref);
+
+            // Find where the end of this edge is on the original curve (the t values can only increase along the chain)
+            let edge    = self.get_edge(edge_ref);
+            let end_idx = edge.end_point_index();
+            next_edge   = remaining.iter().find(|other_ref| other_ref.start_idx == end_idx).copied();
+
+            let t_end   = if next_edge.is_none() || t_start >= 1.0 {
+                1.0
+            } else {
+                t_start + curve.section(t_start, 1.0).nearest_t(&edge.end_point()) * (1.0 - t_start)
+            };
+
+            edges.push((edge_ref, t_start, t_end));
+            t_start = t_end;
+        }
+
+        edges
+    }
+}
+
+///
+/// Cuts an open path (such as a line or a brush stroke) against a region made up of a set of closed paths, returning the parts
+/// of the path that are inside the region and the parts that are outside
+///
+/// Unlike `path_cut()`, `open_path` is not treated as a perimeter: it's never closed and the sections returned are open paths
+/// that follow it. Each section also records where it starts and ends on the original path, using the same (curve index, t-value)
+/// positions as `path_intersects_path()`.
+///
+/// The region is treated in the same way as in the other arithmetic operations: it's assumed to be rendered with the even-odd
+/// winding rule, and not to overlap itself. Parts of the open path that run along the edge of the region are considered to be
+/// inside it.
+///
+pub fn path_cut_open<POut>(open_path: &impl BezierPath<Point=POut::Point>, region: &Vec<impl BezierPath<Point=POut::Point>>, accuracy: f64) -> OpenPathCut<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    let region_graph = GraphPath::from_merged_paths(region.iter().map(|path| (path, CutOpenLabel::Region)));
+
+    cut_open_path_with_graph(open_path, region_graph, FillRule::EvenOdd, accuracy)
+}
+
+///
+/// As for `path_cut_open()`, except the fill rule used to determine which points are inside the region can be specified
+///
+/// Unlike `path_cut_open()`, the paths in the region may overlap themselves.
+///
+pub fn path_cut_open_with_fill_rule<POut>(open_path: &impl BezierPath<Point=POut::Point>, region: &Vec<impl BezierPath<Point=POut::Point>>, fill_rule: FillRule, accuracy: f64) -> OpenPathCut<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    // Preserve the direction of the edges for the fill rule, and collide the region with itself to allow for self-overlapping paths
+    let mut region_graph = GraphPath::from_merged_paths_with_direction(region.iter().map(|path| (path, CutOpenLabel::Region)));
+    region_graph.self_collide(accuracy);
+
+    cut_open_path_with_graph(open_path, region_graph, fill_rule, accuracy)
+}
+
+///
+/// Cuts an open path against the graph of a region
+///
+fn cut_open_path_with_graph<POut>(open_path: &impl BezierPath<Point=POut::Point>, region_graph: GraphPath<POut::Point, CutOpenLabel>, fill_rule: FillRule, accuracy: f64) -> OpenPathCut<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    let curves = path_to_curves::<_, Curve<_>>(open_path).collect::<Vec<_>>();
+
+    // Each curve is added to the graph separately so the sections can be mapped back to the original path
+    let mut merged_path = GraphPath::new();
+    for (curve_idx, curve) in curves.iter().enumerate() {
+        let (cp1, cp2)  = curve.control_points();
+        let curve_path  = (curve.start_point(), vec![(cp1, cp2, curve.end_point())]);
+
+        merged_path = merged_path.merge(GraphPath::from_open_path(&curve_path, CutOpenLabel::OpenCurve(curve_idx)));
+    }
+
+    // Collide with the region and work out which of the edges from the open path are inside it
+    merged_path = merged_path.collide(region_graph, accuracy);
+    merged_path.round(accuracy);
+    merged_path.set_open_edge_kinds_by_ray_casting(fill_rule);
+
+    // Gather the edges into sections
+    let mut interior_sections   = vec![];
+    let mut exterior_sections   = vec![];
+
+    // The section that's currently being built up (whether or not it's inside, the start point and the curves that make it up)
+    let mut current_section: Option<(bool, (usize, f64), POut::Point, Vec<(POut::Point, POut::Point, POut::Point)>)> = None;
+    let mut last_pos            = (0, 0.0);
+
+    for (curve_idx, curve) in curves.iter().enumerate() {
+        for (edge_ref, t_start, t_end) in merged_path.open_curve_edges(curve_idx, curve) {
+            let edge                = merged_path.get_edge(edge_ref);
+            let is_inside           = edge.kind() == GraphPathEdgeKind::Interior;
+            let (cp1, cp2)          = edge.control_points();
+            let end_point           = edge.end_point();
+
+            match &mut current_section {
+                Some((section_inside, _, _, points)) if *section_inside == is_inside => {
+                    // Continue the current section
+                    points.push((cp1, cp2, end_point));
+                }
+
+                _ => {
+                    // Finish the current section and start a new one
+                    if let Some((section_inside, start, start_point, points)) = current_section.take() {
+                        let section = OpenPathSection { path: POut::from_points(start_point, points), start: start, end: last_pos };
+                        if section_inside { interior_sections.push(section) } else { exterior_sections.push(section) }
+                    }
+
+                    current_section = Some((is_inside, (curve_idx, t_start), edge.start_point(), vec![(cp1, cp2, end_point)]));
+                }
+            }
+
+            last_pos = (curve_idx, t_end);
+        }
+    }
+
+    // Finish the last section
+    if let Some((section_inside, start, start_point, points)) = current_section.take() {
+        let section = OpenPathSection { path: POut::from_points(start_point, points), start: start, end: last_pos };
+        if section_inside { interior_sections.push(section) } else { exterior_sections.push(section) }
+    }
+
+    OpenPathCut {
+        interior_sections,
+        exterior_sections
+    }
+}
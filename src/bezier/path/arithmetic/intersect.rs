@@ -1,5 +1,6 @@
 use super::ray_cast::*;
 use super::super::path::*;
+use super::super::fill_rule::*;
 use super::super::graph_path::*;
 use super::super::super::super::geo::*;
 
@@ -9,7 +10,15 @@ impl<Point: Coordinate+Coordinate2D> GraphPath<Point, PathLabel> {
     ///
     pub fn set_exterior_by_intersecting(&mut self) {
         // Use an even-odd winding rule (all edges are considered 'external')
-        self.set_edge_kinds_by_ray_casting(|path_crossings| (path_crossings[0]&1) != 0 && (path_crossings[1]&1) != 0);
+        self.set_exterior_by_intersecting_with_fill_rule(FillRule::EvenOdd);
+    }
+
+    ///
+    /// As for `set_exterior_by_intersecting()`, except the fill rule used to determine which points are inside each of the two
+    /// source paths can be specified
+    ///
+    pub fn set_exterior_by_intersecting_with_fill_rule(&mut self, fill_rule: FillRule) {
+        self.set_edge_kinds_by_ray_casting(|path_crossings| fill_rule.is_inside(path_crossings[0]) && fill_rule.is_inside(path_crossings[1]));
     }
 }
 
@@ -54,3 +63,28 @@ where
     // Produce the final result
     merged_path.exterior_paths()
 }
+
+///
+/// Generates the path formed by intersecting two sets of paths, using a fill rule to determine which points are inside each set
+///
+/// Unlike `path_intersect()`, the paths in `path1` and `path2` may overlap themselves: the result is the region that `fill_rule`
+/// considers to be inside both `path1` and `path2`.
+///
+pub fn path_intersect_with_fill_rule<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, fill_rule: FillRule, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D
+{
+    // Create a graph path from both sides (preserving the direction of the edges for the fill rule), colliding every edge to allow for self-overlapping paths
+    let mut merged_path = GraphPath::from_merged_paths_with_direction(path1.iter().map(|path| (path, PathLabel(0))));
+    merged_path         = merged_path.merge(GraphPath::from_merged_paths_with_direction(path2.iter().map(|path| (path, PathLabel(1)))));
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the 'intersect' algorithm
+    merged_path.set_exterior_by_intersecting_with_fill_rule(fill_rule);
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
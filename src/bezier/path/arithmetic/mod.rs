@@ -3,15 +3,19 @@ mod intersect;
 mod add;
 mod chain_add;
 mod sub;
+mod xor;
 mod chain;
 mod cut;
+mod cut_open;
 mod full_intersect;
 
 pub use self::ray_cast::*;
 pub use self::intersect::*;
 pub use self::add::*;
 pub use self::sub::*;
+pub use self::xor::*;
 pub use self::chain::*;
 pub use self::chain_add::*;
 pub use self::cut::*;
+pub use self::cut_open::*;
 pub use self::full_intersect::*;
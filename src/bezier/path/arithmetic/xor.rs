@@ -0,0 +1,95 @@
+use super::ray_cast::*;
+use super::super::path::*;
+use super::super::fill_rule::*;
+use super::super::graph_path::*;
+use super::super::super::super::geo::*;
+
+impl<Point: Coordinate+Coordinate2D> GraphPath<Point, PathLabel> {
+    ///
+    /// Given a labelled graph path, marks exterior edges by finding the regions that are in exactly one of `PathSource::Path1`
+    /// and `PathSource::Path2`
+    ///
+    pub fn set_exterior_by_xoring(&mut self) {
+        // Use an even-odd winding rule (all edges are considered 'external')
+        self.set_exterior_by_xoring_with_fill_rule(FillRule::EvenOdd);
+    }
+
+    ///
+    /// As for `set_exterior_by_xoring()`, except the fill rule used to determine which points are inside each of the two
+    /// source paths can be specified
+    ///
+    pub fn set_exterior_by_xoring_with_fill_rule(&mut self, fill_rule: FillRule) {
+        self.set_edge_kinds_by_ray_casting(|path_crossings| fill_rule.is_inside(path_crossings[0]) != fill_rule.is_inside(path_crossings[1]));
+    }
+}
+
+///
+/// Generates the path formed by the exclusive-or (symmetric difference) of two sets of paths
+///
+/// The result contains the regions that are inside exactly one of `path1` and `path2`: anywhere that the two paths overlap is
+/// removed.
+///
+/// Each of the two paths passed into this function is assumed not to overlap themselves. IE, this does not perform self-intersection
+/// on either `path1` or `path2`. See `path_remove_interior_points()` and `path_remove_overlapped_points()` for a way to eliminate
+/// overlaps, or `path_xor_with_fill_rule()` for a version that can process self-overlapping paths.
+///
+/// The input vectors represent the external edges of the path to combine (a single BezierPath cannot have any holes in it, so a set of them
+/// effectively represents a path intended to be rendered with an even-odd winding rule)
+///
+pub fn path_xor<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D
+{
+    // If either path is empty, short-circuit by returning the other
+    if path1.is_empty() {
+        return path2.iter()
+            .map(|path| POut::from_path(path))
+            .collect();
+    } else if path2.is_empty() {
+        return path1.iter()
+            .map(|path| POut::from_path(path))
+            .collect();
+    }
+
+    // Create the graph path from the source side
+    let mut merged_path = GraphPath::new();
+    merged_path         = merged_path.merge(GraphPath::from_merged_paths(path1.iter().map(|path| (path, PathLabel(0)))));
+
+    // Collide with the target side to generate a full path
+    merged_path         = merged_path.collide(GraphPath::from_merged_paths(path2.iter().map(|path| (path, PathLabel(1)))), accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the 'xor' algorithm
+    merged_path.set_exterior_by_xoring();
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
+
+///
+/// Generates the exclusive-or of two sets of paths, using a fill rule to determine which points are inside each set
+///
+/// Unlike `path_xor()`, the paths in `path1` and `path2` may overlap themselves: the result is the region that `fill_rule`
+/// considers to be inside exactly one of `path1` and `path2`.
+///
+pub fn path_xor_with_fill_rule<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, fill_rule: FillRule, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    // Create a graph path from both sides (preserving the direction of the edges for the fill rule), colliding every edge to allow for self-overlapping paths
+    let mut merged_path = GraphPath::from_merged_paths_with_direction(path1.iter().map(|path| (path, PathLabel(0))));
+    merged_path         = merged_path.merge(GraphPath::from_merged_paths_with_direction(path2.iter().map(|path| (path, PathLabel(1)))));
+
+    merged_path.self_collide(accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the 'xor' algorithm
+    merged_path.set_exterior_by_xoring_with_fill_rule(fill_rule);
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
@@ -0,0 +1,394 @@
+use super::path::*;
+use super::area::*;
+use super::dash::*;
+use super::stroke::*;
+use super::fill_rule::*;
+use super::arithmetic::*;
+
+use crate::geo::*;
+use crate::arc::*;
+use crate::bezier::*;
+use crate::line::*;
+
+use ::roots::{find_roots_quadratic};
+
+use std::f64;
+
+///
+/// The shape of the pen used for a calligraphic stroke
+///
+/// The nib keeps the same orientation as it moves along the path, so the width of the line depends on the direction the path is
+/// travelling in.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum PenNib<TCoord> {
+    /// An ellipse centered on the path, with the specified radii along its two axes, and rotated anticlockwise by an angle
+    /// in radians
+    Ellipse { radius_x: f64, radius_y: f64, angle: f64 },
+
+    /// A convex polygon, with its vertices specified relative to the point on the path
+    Polygon(Vec<TCoord>),
+}
+
+impl<TCoord: Coordinate+Coordinate2D> PenNib<TCoord> {
+    ///
+    /// Creates a nib that's a straight line of a particular width, rotated anticlockwise by an angle in radians (the 'chisel'
+    /// nib used for broad-edged calligraphy)
+    ///
+    pub fn chisel(width: f64, angle: f64) -> PenNib<TCoord> {
+        let half_width  = width / 2.0;
+        let (sin, cos)  = angle.sin_cos();
+
+        PenNib::Polygon(vec![
+            TCoord::from_components(&[-cos * half_width, -sin * half_width]),
+            TCoord::from_components(&[cos * half_width, sin * half_width]),
+        ])
+    }
+
+    ///
+    /// Returns the vertices of a polygonal nib in clockwise order
+    ///
+    fn clockwise_vertices(vertices: &[TCoord]) -> Vec<TCoord> {
+        let signed_area = vertices.iter().zip(vertices.iter().cycle().skip(1))
+            .map(|(p1, p2)| p1.x()*p2.y() - p2.x()*p1.y())
+            .sum::<f64>();
+
+        if signed_area > 0.0 {
+            vertices.iter().rev().copied().collect()
+        } else {
+            vertices.to_vec()
+        }
+    }
+
+    ///
+    /// Returns the point on the edge of the nib that's furthest in the specified direction
+    ///
+    fn support_point(&self, direction: TCoord) -> TCoord {
+        match self {
+            PenNib::Ellipse { radius_x, radius_y, angle } => {
+                // Rotate the direction into the ellipse's coordinate system
+                let (sin, cos)  = angle.sin_cos();
+                let dx          = direction.x() * cos + direction.y() * sin;
+                let dy          = -direction.x() * sin + direction.y() * cos;
+
+                // The point on the ellipse whose normal matches the direction
+                let length      = ((radius_x*dx).powi(2) + (radius_y*dy).powi(2)).sqrt();
+                if length <= 0.0 { return TCoord::origin(); }
+
+                let px          = radius_x*radius_x*dx / length;
+                let py          = radius_y*radius_y*dy / length;
+
+                TCoord::from_components(&[px * cos - py * sin, px * sin + py * cos])
+            }
+
+            PenNib::Polygon(vertices) => {
+                // Use the same order as clockwise_edge() so that the same vertex is picked if several are equally far
+                Self::clockwise_vertices(vertices).into_iter()
+                    .max_by(|a, b| a.dot(&direction).total_cmp(&b.dot(&direction)))
+                    .unwrap_or_else(TCoord::origin)
+            }
+        }
+    }
+
+    ///
+    /// Returns the curves that follow the edge of the nib clockwise from its support point in `start_direction` to its support
+    /// point in `end_direction`, with the nib placed at `center`
+    ///
+    fn clockwise_edge(&self, center: TCoord, start_direction: TCoord, end_direction: TCoord) -> Vec<(TCoord, TCoord, TCoord)> {
+        match self {
+            PenNib::Ellipse { radius_x, radius_y, angle } => {
+                let (radius_x, radius_y)    = (radius_x.abs(), radius_y.abs());
+                if radius_x <= 0.0 || radius_y <= 0.0 { return vec![]; }
+
+                let (sin, cos)              = angle.sin_cos();
+
+                // Angle of a point on the ellipse, measured clockwise from the y axis on the unit circle that's stretched to make the ellipse
+                let circle_angle            = |point: TCoord| {
+                    let x = (point.x() * cos + point.y() * sin) / radius_x;
+                    let y = (-point.x() * sin + point.y() * cos) / radius_y;
+                    f64::atan2(x, y)
+                };
+                let transform               = |p: TCoord| TCoord::from_components(&[
+                    center.x() + p.x()*radius_x*cos - p.y()*radius_y*sin,
+                    center.y() + p.x()*radius_x*sin + p.y()*radius_y*cos,
+                ]);
+
+                let start_point             = self.support_point(start_direction);
+                let end_point               = self.support_point(end_direction);
+                if start_point.is_near_to(&end_point, 1e-9) { return vec![]; }
+
+                let start_angle             = circle_angle(start_point);
+                let sweep                   = (circle_angle(end_point) - start_angle).rem_euclid(2.0*f64::consts::PI);
+
+                // Arcs are inaccurate if they cover more than 90 degrees
+                let unit_circle             = Circle::new(TCoord::origin(), 1.0);
+                let num_sections            = (sweep / (f64::consts::PI/2.0)).ceil().max(1.0) as usize;
+                let section_sweep           = sweep / (num_sections as f64);
+
+                (0..num_sections)
+                    .map(|section| {
+                        let section_start               = start_angle + section_sweep * (section as f64);
+                        let (_, (cp1, cp2), end_point)  = unit_circle.arc(section_start, section_start + section_sweep).to_bezier_curve::<Curve<_>>().all_points();
+
+                        (transform(cp1), transform(cp2), transform(end_point))
+                    })
+                    .collect()
+            }
+
+            PenNib::Polygon(vertices) => {
+                let vertices        = Self::clockwise_vertices(vertices);
+                let support_index   = |direction: TCoord| (0..vertices.len()).max_by(|a, b| vertices[*a].dot(&direction).total_cmp(&vertices[*b].dot(&direction)));
+
+                let (start_idx, end_idx) = match (support_index(start_direction), support_index(end_direction)) {
+                    (Some(start_idx), Some(end_idx))    => (start_idx, end_idx),
+                    _                                   => { return vec![]; }
+                };
+
+                // Walk clockwise around the vertices
+                let mut curves  = vec![];
+                let mut idx     = start_idx;
+
+                while idx != end_idx {
+                    let next_idx                    = (idx + 1) % vertices.len();
+                    let (_, (cp1, cp2), end_point)  = line_to_bezier::<Curve<_>>(&(vertices[idx] + center, vertices[next_idx] + center)).all_points();
+
+                    curves.push((cp1, cp2, end_point));
+                    idx = next_idx;
+                }
+
+                curves
+            }
+        }
+    }
+
+    ///
+    /// Returns the outline of the nib placed at a point on the path
+    ///
+    fn outline_at(&self, point: TCoord) -> Option<(TCoord, Vec<(TCoord, TCoord, TCoord)>)> {
+        let up      = TCoord::from_components(&[0.0, 1.0]);
+        let down    = TCoord::from_components(&[0.0, -1.0]);
+
+        let mut points = self.clockwise_edge(point, up, down);
+        points.extend(self.clockwise_edge(point, down, up));
+
+        let (_, _, start_point) = *points.last()?;
+        let outline             = (start_point, points);
+
+        // Nibs that have no area can't be drawn on their own
+        if path_signed_area(&outline).abs() < 1e-12 {
+            None
+        } else {
+            Some(outline)
+        }
+    }
+
+    ///
+    /// Finds the t values where the tangent of a curve is parallel to one of the edges of the nib (the support point changes at
+    /// these points)
+    ///
+    fn split_points(&self, curve: &Curve<TCoord>) -> Vec<f64> {
+        match self {
+            PenNib::Ellipse { .. } => vec![],
+
+            PenNib::Polygon(vertices) => {
+                let (cp1, cp2)      = curve.control_points();
+                let (w1, w2, w3)    = derivative4(curve.start_point(), cp1, cp2, curve.end_point());
+
+                let mut t_values    = vertices.iter().zip(vertices.iter().cycle().skip(1))
+                    .flat_map(|(v1, v2)| {
+                        // Find where the cross product of the tangent and the edge is 0
+                        let edge        = *v2 - *v1;
+                        let cross       = |w: TCoord| w.x()*edge.y() - w.y()*edge.x();
+                        let (c1, c2, c3) = (cross(w1), cross(w2), cross(w3));
+
+                        let a = c1 - 2.0*c2 + c3;
+                        let b = 2.0*(c2 - c1);
+                        let c = c1;
+
+                        if a.abs() < 1e-12 && b.abs() < 1e-12 {
+                            // Tangent is always parallel to the edge (or never is)
+                            vec![]
+                        } else {
+                            find_roots_quadratic(a, b, c).as_ref().to_vec()
+                        }
+                    })
+                    .filter(|t: &f64| *t > 1e-6 && *t < 1.0-1e-6)
+                    .collect::<Vec<_>>();
+
+                t_values.sort_by(|t1, t2| t1.total_cmp(t2));
+                t_values.dedup_by(|t1, t2| (*t1-*t2).abs() < 1e-6);
+                t_values
+            }
+        }
+    }
+}
+
+///
+/// Returns the normal pointing to the left of a direction
+///
+#[inline]
+fn left_normal<TCoord: Coordinate+Coordinate2D>(direction: TCoord) -> TCoord {
+    TCoord::from_components(&[-direction.y(), direction.x()])
+}
+
+///
+/// Offsets the left-hand side of a curve by the nib's support point in the direction of the normal
+///
+fn offset_left<TCoord>(curve: &Curve<TCoord>, nib: &PenNib<TCoord>, subdivision_options: &SubdivisionOffsetOptions) -> Option<Vec<Curve<TCoord>>>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    let support_for_t = |t: f64| {
+        let tangent = curve.tangent_at_pos(t);
+        if tangent.magnitude() < 1e-12 { return (TCoord::origin(), TCoord::origin()); }
+
+        let tangent = tangent.to_unit_vector();
+        (nib.support_point(left_normal(tangent)), tangent)
+    };
+
+    let normal_offset   = |t: f64| { let (support, tangent) = support_for_t(t); support.dot(&left_normal(tangent)) };
+    let tangent_offset  = |t: f64| { let (support, tangent) = support_for_t(t); support.dot(&tangent) };
+
+    offset_lms_subdivisions(curve, normal_offset, tangent_offset, subdivision_options)
+}
+
+///
+/// Traces the outline of the region swept by the nib along a list of curves, which should form a loop (ie, the curves for one side
+/// of the path followed by the reversed curves for the other side)
+///
+/// Where the path turns clockwise, the outline follows the edge of the nib from one side of the corner to the other, and where it
+/// turns anticlockwise it's joined with a straight line (the part that overlaps is removed later on). Where the path reverses
+/// direction (which happens at either end of the path), the outline follows the edge of the nib, which forms the cap.
+///
+fn trace_outline<TCoord>(curves: &[Curve<TCoord>], nib: &PenNib<TCoord>, subdivision_options: &SubdivisionOffsetOptions) -> Option<(TCoord, Vec<(TCoord, TCoord, TCoord)>)>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    let mut start_point = None;
+    let mut points      = vec![];
+
+    let line_to         = |points: &mut Vec<(TCoord, TCoord, TCoord)>, from: TCoord, to: TCoord| {
+        if !from.is_near_to(&to, 1e-9) {
+            let (_, (cp1, cp2), end_point) = line_to_bezier::<Curve<_>>(&(from, to)).all_points();
+            points.push((cp1, cp2, end_point));
+        }
+    };
+
+    for (curve_idx, curve) in curves.iter().enumerate() {
+        // Add the join from the previous curve
+        let previous        = &curves[(curve_idx + curves.len() - 1) % curves.len()];
+        let corner          = curve.start_point();
+        let in_direction    = curve_direction(previous, 1.0);
+        let out_direction   = curve_direction(curve, 0.0);
+        let cross           = in_direction.x()*out_direction.y() - in_direction.y()*out_direction.x();
+        let is_reversing    = in_direction.dot(&out_direction) < 0.0 && cross.abs() < 1e-6;
+
+        let join_start      = corner + nib.support_point(left_normal(in_direction));
+        let mut last_point  = points.last().map(|(_, _, end_point)| *end_point).unwrap_or(join_start);
+
+        if curve_idx == 0 {
+            start_point = Some(join_start);
+        }
+
+        line_to(&mut points, last_point, join_start);
+        last_point = join_start;
+
+        if cross < 0.0 || is_reversing {
+            // Follow the outside of the nib around the corner
+            let edge    = nib.clockwise_edge(corner, left_normal(in_direction), left_normal(out_direction));
+            last_point  = edge.last().map(|(_, _, end_point)| *end_point).unwrap_or(last_point);
+            points.extend(edge);
+        }
+
+        // Offset the curve
+        if let Some(offset_curves) = offset_left(curve, nib, subdivision_options) {
+            line_to(&mut points, last_point, offset_curves[0].start_point());
+            points.extend(offset_curves.into_iter().map(|curve| { let (_, (cp1, cp2), end_point) = curve.all_points(); (cp1, cp2, end_point) }));
+        } else {
+            let end_point = curve.end_point() + nib.support_point(left_normal(curve_direction(curve, 1.0)));
+            line_to(&mut points, last_point, end_point);
+        }
+    }
+
+    // Close the path
+    let start_point = start_point?;
+    let last_point  = points.last().map(|(_, _, end_point)| *end_point).unwrap_or(start_point);
+    line_to(&mut points, last_point, start_point);
+
+    Some((start_point, points))
+}
+
+///
+/// Generates a calligraphic stroke by sweeping a pen nib along a path
+///
+/// Unlike `stroke_path()`, the nib keeps a fixed orientation as it moves along the path, so the width of the line depends on the
+/// direction that the path is travelling in. The result is the outline of the region that the nib covers: the nib itself forms
+/// the joins and caps, so the join and cap settings in the options are not used. The accuracy and minimum sample distance settings
+/// are used in the same way as for `stroke_path()`.
+///
+/// The result never overlaps itself, but can contain holes if the path crosses itself. If the options have a dash pattern, each
+/// dash is swept separately and zero-length dashes produce a single copy of the nib.
+///
+pub fn stroke_path_with_nib<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, nib: &PenNib<TCoord>, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    path_dash_sections(path, &options.dash_array, options.dash_offset, options.accuracy)
+        .into_iter()
+        .flat_map(|dash| {
+            if dash.is_zero_length() {
+                nib.outline_at(dash.start_point).into_iter().map(|(start_point, points)| TPathFactory::from_points(start_point, points)).collect()
+            } else {
+                stroke_section_with_nib(&(dash.start_point, dash.points), nib, options)
+            }
+        })
+        .collect()
+}
+
+///
+/// Sweeps a nib along a single path section (ignoring the dash pattern)
+///
+fn stroke_section_with_nib<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, nib: &PenNib<TCoord>, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    // Use the size of the nib as a guide to the subdivision options
+    let nib_size = match nib {
+        PenNib::Ellipse { radius_x, radius_y, .. }  => radius_x.abs().max(radius_y.abs()) * 2.0,
+        PenNib::Polygon(vertices)                   => vertices.iter().map(|vertex| vertex.magnitude()).fold(0.0, f64::max) * 2.0,
+    };
+
+    let subdivision_options = SubdivisionOffsetOptions::default()
+        .with_min_distance(options.min_sample_distance)
+        .with_max_error(options.accuracy)
+        .with_max_distance((nib_size * 20.0).max(options.min_sample_distance));
+
+    // Split the curves where the support points of the nib change
+    let mut curves = vec![];
+
+    for curve in path.to_curves::<Curve<TCoord>>() {
+        let mut t_values = nib.split_points(&curve);
+        t_values.insert(0, 0.0);
+        t_values.push(1.0);
+
+        for (t1, t2) in t_values.iter().zip(t_values.iter().skip(1)) {
+            curves.push(Curve::from_curve(&curve.section(*t1, *t2)));
+        }
+    }
+
+    // Paths with no curves are just the nib
+    if curves.is_empty() {
+        return nib.outline_at(path.start_point()).into_iter().map(|(start_point, points)| TPathFactory::from_points(start_point, points)).collect();
+    }
+
+    // Trace around both sides of the path to generate the outline
+    let reversed = curves.iter().rev().map(|curve| curve.reverse::<Curve<_>>()).collect::<Vec<_>>();
+    curves.extend(reversed);
+
+    let outline = trace_outline(&curves, nib, &subdivision_options);
+
+    // The outline can overlap itself around corners (it's always clockwise, so the swept region is everywhere with a non-zero winding number)
+    path_remove_interior_points_with_fill_rule(&outline.into_iter().collect(), FillRule::NonZero, options.accuracy)
+}
@@ -0,0 +1,223 @@
+use super::path::*;
+
+use crate::geo::*;
+use crate::bezier::*;
+use crate::consts::*;
+
+///
+/// Finds the t value on a curve where the arc length from the start of the curve is `length`
+///
+pub (crate) fn t_for_length<C: BezierCurve>(curve: &C, length: f64, total_length: f64, max_error: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 32;
+
+    if length <= 0.0 { return 0.0; }
+    if length >= total_length { return 1.0; }
+
+    // Use Newton's method, falling back to bisection if the next guess is outside the range that the result must be in
+    let (cp1, cp2)      = curve.control_points();
+    let (wn1, wn2, wn3) = derivative4(curve.start_point(), cp1, cp2, curve.end_point());
+
+    let mut t_min       = 0.0;
+    let mut t_max       = 1.0;
+    let mut t           = length / total_length;
+
+    for _ in 0..MAX_ITERATIONS {
+        let error = curve_length(&curve.section(0.0, t), max_error) - length;
+
+        if error.abs() < max_error {
+            break;
+        }
+
+        if error > 0.0 { t_max = t; } else { t_min = t; }
+
+        let speed   = de_casteljau3(t, wn1, wn2, wn3).magnitude();
+        let next_t  = if speed > 1e-8 { t - error/speed } else { -1.0 };
+
+        t = if next_t > t_min && next_t < t_max { next_t } else { (t_min + t_max) * 0.5 };
+    }
+
+    t
+}
+
+///
+/// A dash generated from a path, along with where its curves are in the original path
+///
+#[derive(Clone, Debug)]
+pub (crate) struct PathDash<TCoord> {
+    /// The point where the dash starts
+    pub (crate) start_point: TCoord,
+
+    /// The curves that make up the dash
+    pub (crate) points: Vec<(TCoord, TCoord, TCoord)>,
+
+    /// For each curve in the dash, the index of the curve in the original path and the t values where it starts and ends
+    pub (crate) original_curves: Vec<(usize, f64, f64)>,
+}
+
+impl<TCoord: Coordinate+Coordinate2D> PathDash<TCoord> {
+    ///
+    /// True if this dash has no length (it's a dot where the dash pattern has a zero-length dash)
+    ///
+    pub (crate) fn is_zero_length(&self) -> bool {
+        let start_point = self.start_point;
+
+        !self.points.is_empty() && self.points.iter().all(|(cp1, cp2, end_point)| {
+            cp1.is_near_to(&start_point, SMALL_DISTANCE) && cp2.is_near_to(&start_point, SMALL_DISTANCE) && end_point.is_near_to(&start_point, SMALL_DISTANCE)
+        })
+    }
+}
+
+///
+/// True if a dash array describes a dash pattern (rather than a solid line)
+///
+fn is_valid_dash_array(dash_array: &[f64]) -> bool {
+    !dash_array.is_empty() && dash_array.iter().all(|length| *length >= 0.0) && dash_array.iter().sum::<f64>() > 0.0
+}
+
+///
+/// Splits a path into dashes
+///
+/// `dash_array` is a list of lengths, alternating between the length of a dash and the length of the gap that follows it. If there
+/// are an odd number of lengths, the list is repeated to make an even number (so `[2.0]` produces a dash of length 2 followed by a
+/// gap of length 2). `dash_offset` is the distance into the pattern where the start of the path is. Lengths are measured along the
+/// path and the dashes can continue across the boundaries between curves.
+///
+/// Dashes with a length of 0 produce a path containing a single curve where all the points are the same: these are drawn as dots
+/// by `stroke_path()` when the line has round or square caps.
+///
+/// If the path is closed, the dash that ends at the end of the path is joined to the dash that starts at the start of the path, so
+/// there's no break where the path is closed.
+///
+/// If the dash array is empty, contains negative values or has a total length of 0, the result is just the original path.
+///
+pub fn path_dashes<POut>(path: &impl BezierPath<Point=POut::Point>, dash_array: &[f64], dash_offset: f64, max_error: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    // Invalid dash patterns just generate a single path
+    if !is_valid_dash_array(dash_array) {
+        return vec![POut::from_path(path)];
+    }
+
+    path_dash_sections(path, dash_array, dash_offset, max_error)
+        .into_iter()
+        .map(|dash| POut::from_points(dash.start_point, dash.points))
+        .collect()
+}
+
+///
+/// Splits a path into dashes, tracking where each dash is in the original path
+///
+/// Invalid dash patterns produce a single dash covering the whole path.
+///
+pub (crate) fn path_dash_sections<TCoord>(path: &impl BezierPath<Point=TCoord>, dash_array: &[f64], dash_offset: f64, max_error: f64) -> Vec<PathDash<TCoord>>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    // Invalid dash patterns just generate a single path
+    if !is_valid_dash_array(dash_array) {
+        let points          = path.points().collect::<Vec<_>>();
+        let original_curves = (0..points.len()).map(|curve_idx| (curve_idx, 0.0, 1.0)).collect();
+
+        return vec![PathDash { start_point: path.start_point(), points, original_curves }];
+    }
+
+    // Patterns with an odd number of entries are repeated
+    let pattern = if dash_array.len() % 2 == 1 {
+        dash_array.iter().chain(dash_array.iter()).copied().collect::<Vec<_>>()
+    } else {
+        dash_array.to_vec()
+    };
+    let pattern_length = pattern.iter().sum::<f64>();
+
+    // Find where in the pattern the path starts (a zero-length dash exactly at the start of the path is not skipped)
+    let mut offset      = dash_offset.rem_euclid(pattern_length);
+    let mut pattern_idx = 0;
+    while offset > pattern[pattern_idx] || (offset == pattern[pattern_idx] && pattern[pattern_idx] > 0.0) {
+        offset      -= pattern[pattern_idx];
+        pattern_idx = (pattern_idx + 1) % pattern.len();
+    }
+
+    let mut remaining   = pattern[pattern_idx] - offset;
+    let starts_on_dash  = pattern_idx % 2 == 0;
+
+    // Walk along the curves, generating the dashes
+    let mut dashes      = vec![];
+    let mut current     = if starts_on_dash { Some(PathDash { start_point: path.start_point(), points: vec![], original_curves: vec![] }) } else { None };
+
+    for (curve_idx, curve) in path.to_curves::<Curve<TCoord>>().into_iter().enumerate() {
+        let curve_length    = curve_length(&curve, max_error);
+        let mut pos         = 0.0;
+        let mut t_pos       = 0.0;
+
+        // Process any pattern entries that end within this curve
+        while curve_length - pos > remaining {
+            pos         += remaining;
+            let t_end   = t_for_length(&curve, pos, curve_length, max_error);
+
+            if let Some(mut dash) = current.take() {
+                // End of a dash
+                if t_end > t_pos {
+                    let (_, (cp1, cp2), end_point) = curve.section(t_pos, t_end).all_points();
+                    dash.points.push((cp1, cp2, end_point));
+                    dash.original_curves.push((curve_idx, t_pos, t_end));
+                }
+
+                // Zero-length dashes are a single curve that stays at the same point
+                if dash.points.is_empty() {
+                    let point = dash.start_point;
+                    dash.points.push((point, point, point));
+                    dash.original_curves.push((curve_idx, t_end, t_end));
+                }
+
+                dashes.push(dash);
+            } else {
+                // Start of a dash
+                current = Some(PathDash { start_point: curve.point_at_pos(t_end), points: vec![], original_curves: vec![] });
+            }
+
+            t_pos       = t_end;
+            pattern_idx = (pattern_idx + 1) % pattern.len();
+            remaining   = pattern[pattern_idx];
+        }
+
+        // The rest of the curve is in the current pattern entry
+        if let Some(dash) = &mut current {
+            if t_pos < 1.0 {
+                let (_, (cp1, cp2), end_point) = curve.section(t_pos, 1.0).all_points();
+                dash.points.push((cp1, cp2, end_point));
+                dash.original_curves.push((curve_idx, t_pos, 1.0));
+            }
+        }
+
+        remaining -= curve_length - pos;
+    }
+
+    // The path might end in the middle of a dash
+    let ends_on_dash = if let Some(dash) = current.take() {
+        if !dash.points.is_empty() {
+            dashes.push(dash);
+            true
+        } else {
+            false
+        }
+    } else {
+        false
+    };
+
+    // On closed paths, the dashes at the start and end of the path form a single dash
+    let start_point     = path.start_point();
+    let end_point       = path.points().last().map(|(_, _, end_point)| end_point).unwrap_or(start_point);
+    let is_closed       = start_point.is_near_to(&end_point, SMALL_DISTANCE);
+
+    if is_closed && starts_on_dash && ends_on_dash && dashes.len() > 1 && !dashes[0].is_zero_length() {
+        let first_dash  = dashes.remove(0);
+        let last_dash   = dashes.last_mut().unwrap();
+
+        last_dash.points.extend(first_dash.points);
+        last_dash.original_curves.extend(first_dash.original_curves);
+    }
+
+    dashes
+}
@@ -0,0 +1,45 @@
+///
+/// The rule used to decide which points are inside a set of paths, based on their winding number
+///
+/// The winding number of a point is the number of times the paths go around it anticlockwise (when the y axis points
+/// upwards), minus the number of times they go around it clockwise. The `path_winding_number()` function can be used to
+/// find the winding number for a particular point.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FillRule {
+    /// Points with an odd winding number are inside the shape
+    EvenOdd,
+
+    /// Points with a non-zero winding number are inside the shape
+    NonZero,
+
+    /// Points with a positive winding number are inside the shape
+    Positive,
+
+    /// Points with a negative winding number are inside the shape
+    Negative,
+}
+
+impl FillRule {
+    ///
+    /// Returns true if a point with the specified winding number is inside the shape according to this rule
+    ///
+    #[inline]
+    pub fn is_inside(&self, winding_number: i32) -> bool {
+        match self {
+            FillRule::EvenOdd   => (winding_number & 1) != 0,
+            FillRule::NonZero   => winding_number != 0,
+            FillRule::Positive  => winding_number > 0,
+            FillRule::Negative  => winding_number < 0,
+        }
+    }
+}
+
+impl Default for FillRule {
+    ///
+    /// The default fill rule is the even-odd rule, which is the rule used by the arithmetic operations
+    ///
+    fn default() -> FillRule {
+        FillRule::EvenOdd
+    }
+}
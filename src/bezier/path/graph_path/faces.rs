@@ -0,0 +1,261 @@
+use super::*;
+use super::super::area::*;
+use super::super::point::*;
+use super::super::super::curve::*;
+
+use std::f64;
+use std::collections::{HashMap, HashSet};
+
+///
+/// A region of a graph path that is enclosed by its edges, and which does not have any other edges passing through it
+///
+#[derive(Clone, Debug)]
+pub struct GraphPathFace<POut, Label> {
+    /// The perimeters of this face: the first path is the outside of the face and any further paths are holes
+    pub paths: Vec<POut>,
+
+    /// The edges around the perimeters of this face, in the same order as the paths (edges may be reversed so that they follow
+    /// the direction of the perimeter)
+    pub edges: Vec<GraphEdgeRef>,
+
+    /// The labels of the edges in `edges`
+    pub labels: Vec<Label>,
+
+    /// The indexes of the other faces that share an edge with this one
+    pub adjacent_faces: Vec<usize>
+}
+
+///
+/// A loop of half-edges found while enumerating the faces of a graph
+///
+struct FaceLoop {
+    /// The half-edges in this loop
+    edges: Vec<GraphEdgeRef>,
+
+    /// The signed area of this loop (positive for the perimeter of a face, negative for the outside of a set of connected edges)
+    area: f64
+}
+
+impl<Point: Coordinate+Coordinate2D, Label: Copy> GraphPath<Point, Label> {
+    ///
+    /// Returns the half-edges for the included edges in this graph (each edge in both directions)
+    ///
+    fn all_half_edges(&self, include_edge: &[bool]) -> Vec<GraphEdgeRef> {
+        self.all_edge_refs()
+            .enumerate()
+            .filter(|(edge_num, _)| include_edge[*edge_num])
+            .flat_map(|(_, edge_ref)| vec![edge_ref, edge_ref.reversed()])
+            .collect()
+    }
+
+    ///
+    /// Finds the loops formed by always taking the next edge clockwise at every point, which are the perimeters of the faces of the
+    /// graph (with the face on the left of each edge)
+    ///
+    fn face_loops(&self, half_edges: &[GraphEdgeRef]) -> Vec<FaceLoop> {
+        // Sort the edges leaving each point anticlockwise by the angle they leave at
+        let mut leaving = vec![vec![]; self.points.len()];
+
+        for half_edge in half_edges.iter() {
+            let edge        = self.get_edge(*half_edge);
+            let start       = edge.start_point();
+            let direction   = edge.point_at_pos(1e-3) - start;
+            let angle       = direction.y().atan2(direction.x());
+
+            leaving[edge.start_point_index()].push((angle, *half_edge));
+        }
+
+        for edges in leaving.iter_mut() {
+            edges.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        }
+
+        // Follow the loops
+        let mut visited = HashSet::new();
+        let mut loops   = vec![];
+
+        for first_edge in half_edges.iter() {
+            if visited.contains(first_edge) { continue; }
+
+            let mut loop_edges  = vec![];
+            let mut edge_ref    = *first_edge;
+
+            while visited.insert(edge_ref) {
+                loop_edges.push(edge_ref);
+
+                // Find the edge that's next clockwise from the reverse of this edge at its end point
+                let end_idx     = self.get_edge(edge_ref).end_point_index();
+                let reverse     = edge_ref.reversed();
+                let edges       = &leaving[end_idx];
+                let reverse_pos = edges.iter().position(|(_, edge)| *edge == reverse).unwrap();
+
+                edge_ref        = edges[(reverse_pos + edges.len() - 1) % edges.len()].1;
+            }
+
+            let area = path_signed_area(&self.generate_face_path::<(Point, Vec<(Point, Point, Point)>)>(&loop_edges));
+            loops.push(FaceLoop { edges: loop_edges, area });
+        }
+
+        loops
+    }
+
+    ///
+    /// Creates a path from a loop of half-edges
+    ///
+    fn generate_face_path<POut: BezierPathFactory<Point=Point>>(&self, edges: &[GraphEdgeRef]) -> POut {
+        let start_point = self.get_edge(edges[0]).start_point();
+
+        POut::from_points(start_point, edges.iter().map(|edge_ref| {
+            let edge        = self.get_edge(*edge_ref);
+            let (cp1, cp2)  = edge.control_points();
+
+            (cp1, cp2, edge.end_point())
+        }))
+    }
+
+    ///
+    /// Finds the faces of this graph: the regions that are enclosed by its edges
+    ///
+    /// This treats the graph as a planar map (sometimes called an arrangement), so it should be called after the paths in it have
+    /// been collided (using `collide()` or `self_collide()`) so that edges only meet at their end points. Every bounded region of the
+    /// plane formed by the edges is returned as a face, along with the edges around it and the faces next to it. Edges that don't
+    /// separate two different regions (for example, the ends of open paths) are not part of any face. A set of edges that is entirely
+    /// inside a face (without touching its perimeter) forms a hole in that face.
+    ///
+    /// This is useful for 'live paint' style operations, where the regions formed by a set of overlapping shapes can be coloured
+    /// individually.
+    ///
+    pub fn faces<POut>(&self) -> Vec<GraphPathFace<POut, Label>>
+    where
+        POut: BezierPathFactory<Point=Point>,
+    {
+        let all_edges       = self.all_edge_refs().collect::<Vec<_>>();
+        let edge_index      = all_edges.iter().enumerate().map(|(idx, edge_ref)| (*edge_ref, idx)).collect::<HashMap<_, _>>();
+
+        // Edges that are the same as an earlier edge are ignored (eg: open paths are closed by returning along the same edges)
+        let mut include_edge    = vec![true; all_edges.len()];
+        let mut edges_between   = HashMap::<_, Vec<usize>>::new();
+
+        for (edge_num, edge_ref) in all_edges.iter().enumerate() {
+            let edge            = self.get_edge(*edge_ref);
+            let (start, end)    = (edge.start_point_index(), edge.end_point_index());
+            let similar_edges   = edges_between.entry((start.min(end), start.max(end))).or_default();
+
+            let is_duplicate    = similar_edges.iter().any(|other_num| {
+                // Edges can follow the same route with different control points, so compare points along them instead
+                let other = self.get_edge(all_edges[*other_num]);
+
+                [0.25, 0.5, 0.75].iter().all(|t| {
+                    let point = edge.point_at_pos(*t);
+                    other.point_at_pos(other.nearest_t(&point)).is_near_to(&point, CLOSE_DISTANCE)
+                })
+            });
+
+            if is_duplicate {
+                include_edge[edge_num] = false;
+            } else {
+                similar_edges.push(edge_num);
+            }
+        }
+
+        // Edges with the same loop on both sides don't separate two regions, so they're removed before finding the faces
+        for face_loop in self.face_loops(&self.all_half_edges(&include_edge)) {
+            let loop_edges = face_loop.edges.iter().cloned().collect::<HashSet<_>>();
+
+            for edge_ref in face_loop.edges.iter() {
+                if loop_edges.contains(&edge_ref.reversed()) {
+                    include_edge[edge_index[&GraphEdgeRef { reverse: false, ..*edge_ref }]] = false;
+                }
+            }
+        }
+
+        let loops           = self.face_loops(&self.all_half_edges(&include_edge));
+
+        // Find the connected sets of edges (loops from different components can't share any edges)
+        let mut component   = (0..self.points.len()).collect::<Vec<_>>();
+        fn find_component(component: &mut [usize], idx: usize) -> usize {
+            let mut idx = idx;
+            while component[idx] != idx {
+                component[idx]  = component[component[idx]];
+                idx             = component[idx];
+            }
+            idx
+        }
+
+        for (edge_num, edge_ref) in all_edges.iter().enumerate() {
+            if !include_edge[edge_num] { continue; }
+
+            let edge    = self.get_edge(*edge_ref);
+            let start   = find_component(&mut component, edge.start_point_index());
+            let end     = find_component(&mut component, edge.end_point_index());
+            component[start] = end;
+        }
+
+        // Loops with a positive area are the perimeters of faces, and the ones with a negative area are the outsides of sets of connected edges
+        let min_area        = SMALL_DISTANCE * SMALL_DISTANCE;
+        let face_loops      = (0..loops.len()).filter(|idx| loops[*idx].area > min_area).collect::<Vec<_>>();
+        let outer_loops     = (0..loops.len()).filter(|idx| loops[*idx].area < -min_area).collect::<Vec<_>>();
+        let face_paths      = face_loops.iter().map(|idx| self.generate_face_path::<(Point, Vec<(Point, Point, Point)>)>(&loops[*idx].edges)).collect::<Vec<_>>();
+
+        // Every loop belongs to a face (or to the unbounded region outside of all of the faces)
+        let mut loop_face   = vec![None; loops.len()];
+        for (face_idx, loop_idx) in face_loops.iter().enumerate() {
+            loop_face[*loop_idx] = Some(face_idx);
+        }
+
+        // The outside of a set of connected edges is a hole in the smallest face from a different component that contains it
+        let mut holes       = vec![vec![]; face_loops.len()];
+        for outer_idx in outer_loops.iter() {
+            let outer_edge      = self.get_edge(loops[*outer_idx].edges[0]);
+            let outer_point     = outer_edge.start_point();
+            let outer_component = find_component(&mut component, outer_edge.start_point_index());
+
+            let containing_face = face_loops.iter()
+                .enumerate()
+                .filter(|(_, loop_idx)| {
+                    let face_edge = self.get_edge(loops[**loop_idx].edges[0]);
+                    find_component(&mut component, face_edge.start_point_index()) != outer_component
+                })
+                .filter(|(face_idx, _)| path_contains_point(&face_paths[*face_idx], &outer_point))
+                .min_by(|(_, a), (_, b)| loops[**a].area.total_cmp(&loops[**b].area))
+                .map(|(face_idx, _)| face_idx);
+
+            if let Some(face_idx) = containing_face {
+                loop_face[*outer_idx] = Some(face_idx);
+                holes[face_idx].push(*outer_idx);
+            }
+        }
+
+        // Faces are adjacent if there's an edge with one face on one side and the other face on the other side
+        let mut half_edge_face = HashMap::new();
+        for (loop_idx, face_loop) in loops.iter().enumerate() {
+            for edge_ref in face_loop.edges.iter() {
+                half_edge_face.insert(*edge_ref, loop_face[loop_idx]);
+            }
+        }
+
+        let mut adjacent_faces = vec![vec![]; face_loops.len()];
+        for (edge_ref, face) in half_edge_face.iter() {
+            if let (Some(face), Some(Some(other_face))) = (face, half_edge_face.get(&edge_ref.reversed())) {
+                if face != other_face && !adjacent_faces[*face].contains(other_face) {
+                    adjacent_faces[*face].push(*other_face);
+                }
+            }
+        }
+
+        // Generate the faces
+        face_loops.iter()
+            .zip(holes)
+            .zip(adjacent_faces)
+            .map(|((loop_idx, holes), mut adjacent_faces)| {
+                let perimeters  = Some(*loop_idx).into_iter().chain(holes).collect::<Vec<_>>();
+                let paths       = perimeters.iter().map(|perimeter_idx| self.generate_face_path(&loops[*perimeter_idx].edges)).collect();
+                let edges       = perimeters.iter().flat_map(|perimeter_idx| loops[*perimeter_idx].edges.iter().cloned()).collect::<Vec<_>>();
+                let labels      = edges.iter().map(|edge_ref| self.edge_label(*edge_ref)).collect();
+
+                adjacent_faces.sort_unstable();
+
+                GraphPathFace { paths, edges, labels, adjacent_faces }
+            })
+            .collect()
+    }
+}
@@ -13,6 +13,7 @@ mod edge;
 mod edge_ref;
 mod ray_collision;
 mod path_collision;
+mod faces;
 
 #[cfg(test)] pub (crate) mod test;
 
@@ -20,6 +21,7 @@ pub use self::edge::*;
 pub use self::edge_ref::*;
 pub use self::ray_collision::*;
 pub use self::path_collision::*;
+pub use self::faces::*;
 
 /// Maximum number of edges to traverse when 'healing' gaps found in an external path
 const MAX_HEAL_DEPTH: usize = 3;
@@ -166,6 +168,16 @@ impl<Point: Coordinate+Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         }
     }
 
+    ///
+    /// Creates a graph path from a bezier path, keeping the edges in the same direction as the original path
+    ///
+    /// `from_path()` will always generate edges in a clockwise direction. This preserves the original direction so that
+    /// fill rules that depend on the winding number, such as `FillRule::NonZero`, can be applied to the result.
+    ///
+    pub fn from_path_with_direction(path: &impl BezierPath<Point=Point>, label: Label) -> GraphPath<Point, Label> {
+        Self::from_clockwise_path(path, label)
+    }
+
     ///
     /// Creates a graph path from a bezier path moving in an anti-clockwise direction
     /// 
@@ -242,6 +254,49 @@ impl<Point: Coordinate+Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         path
     }
 
+    ///
+    /// Creates a graph path from a bezier path without closing it
+    ///
+    /// The edges follow the direction of the original path. As the path is left open, the result doesn't enclose a region: this is
+    /// useful for finding where a line or stroke crosses a set of closed paths by colliding it with their graph.
+    ///
+    pub fn from_open_path(path: &impl BezierPath<Point=Point>, label: Label) -> GraphPath<Point, Label> {
+        let mut points = vec![];
+
+        // Push the start point
+        let start_point = path.start_point();
+        points.push(GraphPathPoint::new(start_point, smallvec![], smallvec![]));
+
+        let mut last_point_pos  = start_point;
+        let mut last_point_idx  = 0;
+
+        for (cp1, cp2, end_point) in path.points() {
+            // Ignore points that are too close to the last point
+            if end_point.is_near_to(&last_point_pos, CLOSE_DISTANCE) && cp1.is_near_to(&last_point_pos, CLOSE_DISTANCE) && cp2.is_near_to(&cp1, CLOSE_DISTANCE) {
+                continue;
+            }
+
+            // Add an edge from the last point to the new point
+            points.push(GraphPathPoint::new(end_point, smallvec![], smallvec![]));
+            points[last_point_idx].forward_edges.push(GraphPathEdge::new(GraphPathEdgeKind::Uncategorised, (cp1, cp2), last_point_idx+1, label, 0));
+
+            last_point_idx  += 1;
+            last_point_pos  = end_point;
+        }
+
+        if last_point_idx == 0 {
+            // Just a start point and no edges
+            points.pop();
+        }
+
+        let mut path = GraphPath {
+            points:             points,
+            next_path_index:    1
+        };
+        path.recalculate_reverse_connections();
+        path
+    }
+
     ///
     /// Creates a new graph path by merging (not colliding) a set of paths with their labels
     ///
@@ -258,6 +313,23 @@ impl<Point: Coordinate+Coordinate2D, Label: Copy> GraphPath<Point, Label> {
         merged_path
     }
 
+    ///
+    /// Creates a new graph path by merging (not colliding) a set of paths with their labels, keeping the edges in the same
+    /// direction as the original paths
+    ///
+    pub fn from_merged_paths_with_direction<'a, P: 'a+BezierPath<Point=Point>, PathIter: IntoIterator<Item=(&'a P, Label)>>(paths: PathIter) -> GraphPath<Point, Label> {
+        // Create an empty path
+        let mut merged_path = GraphPath::new();
+
+        // Merge each path in turn
+        for (path, label) in paths {
+            let path    = GraphPath::from_path_with_direction(path, label);
+            merged_path = merged_path.merge(path);
+        }
+
+        merged_path
+    }
+
     ///
     /// Recomputes the list of items that have connections to each point
     ///
@@ -0,0 +1,312 @@
+use super::path::*;
+use super::dash::*;
+
+use crate::geo::*;
+use crate::bezier::*;
+use crate::consts::*;
+
+use std::f64;
+
+///
+/// A position on a path where a marker (such as an arrowhead) can be placed
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MarkerPosition<TCoord> {
+    /// The point on the path
+    pub position: TCoord,
+
+    /// The direction of the path at this point, in radians anticlockwise from the x-axis
+    pub angle: f64,
+
+    /// The index of the curve within the path where this position is
+    pub curve_idx: usize,
+
+    /// The t value on the curve where this position is
+    pub t: f64,
+}
+
+impl<TCoord: Coordinate+Coordinate2D> MarkerPosition<TCoord> {
+    ///
+    /// Places a marker at this position
+    ///
+    /// The marker should be drawn with the point that attaches to the path at the origin, pointing along the positive x-axis. It's
+    /// scaled by `scale`, rotated to match the direction of the path and then moved to the position.
+    ///
+    pub fn place_marker<POut>(&self, marker: &impl BezierPath<Point=TCoord>, scale: f64) -> POut
+    where
+        POut: BezierPathFactory<Point=TCoord>,
+    {
+        let (sin, cos)  = self.angle.sin_cos();
+        let position    = self.position;
+
+        marker.map_points(|point| {
+            let x = point.x() * scale;
+            let y = point.y() * scale;
+
+            TCoord::from_components(&[position.x() + x*cos - y*sin, position.y() + x*sin + y*cos])
+        })
+    }
+}
+
+///
+/// Returns the angle of a direction vector, in radians anticlockwise from the x-axis
+///
+#[inline]
+fn direction_angle<TCoord: Coordinate+Coordinate2D>(direction: TCoord) -> f64 {
+    f64::atan2(direction.y(), direction.x())
+}
+
+///
+/// Returns the angle that bisects the directions going into and coming out of a vertex
+///
+fn bisect_angle(in_angle: f64, out_angle: f64) -> f64 {
+    let mut difference = out_angle - in_angle;
+
+    while difference > f64::consts::PI { difference -= 2.0*f64::consts::PI; }
+    while difference < -f64::consts::PI { difference += 2.0*f64::consts::PI; }
+
+    in_angle + difference / 2.0
+}
+
+///
+/// Returns the positions of markers at the start and end of a path and at each of its vertices (the points where one curve
+/// joins the next)
+///
+/// The angle at the start and end is the direction of the path, and the angle at each vertex bisects the directions of the curves
+/// on either side (this is the same as SVG's `orient="auto"` for markers). If the path is closed, the start and end markers are
+/// also treated as vertices.
+///
+pub fn path_vertex_markers<TCoord>(path: &impl BezierPath<Point=TCoord>) -> Vec<MarkerPosition<TCoord>>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    let curves = path.to_curves::<Curve<TCoord>>();
+
+    let (first_curve, last_curve) = match (curves.first(), curves.last()) {
+        (Some(first_curve), Some(last_curve))   => (first_curve, last_curve),
+        _                                       => { return vec![]; }
+    };
+
+    // Work out the start and end angles
+    let start_angle     = direction_angle(curve_direction(first_curve, 0.0));
+    let end_angle       = direction_angle(curve_direction(last_curve, 1.0));
+    let is_closed       = first_curve.start_point().is_near_to(&last_curve.end_point(), SMALL_DISTANCE);
+    let (start_angle, end_angle) = if is_closed {
+        let angle = bisect_angle(end_angle, start_angle);
+        (angle, angle)
+    } else {
+        (start_angle, end_angle)
+    };
+
+    // Start position
+    let mut markers = vec![MarkerPosition { position: first_curve.start_point(), angle: start_angle, curve_idx: 0, t: 0.0 }];
+
+    // Vertices
+    for (curve_idx, (previous, next)) in curves.iter().zip(curves.iter().skip(1)).enumerate() {
+        let in_angle    = direction_angle(curve_direction(previous, 1.0));
+        let out_angle   = direction_angle(curve_direction(next, 0.0));
+
+        markers.push(MarkerPosition { position: next.start_point(), angle: bisect_angle(in_angle, out_angle), curve_idx: curve_idx+1, t: 0.0 });
+    }
+
+    // End position
+    markers.push(MarkerPosition { position: last_curve.end_point(), angle: end_angle, curve_idx: curves.len()-1, t: 1.0 });
+
+    markers
+}
+
+///
+/// Returns the positions of markers placed at regular intervals along a path
+///
+/// The first marker is `offset` from the start of the path, and the rest are spaced `interval` apart (measured along the path). The
+/// angle of each marker is the direction of the path at that point.
+///
+pub fn path_interval_markers<TCoord>(path: &impl BezierPath<Point=TCoord>, interval: f64, offset: f64, max_error: f64) -> Vec<MarkerPosition<TCoord>>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    if interval <= 0.0 || offset < 0.0 { return vec![]; }
+
+    let mut markers     = vec![];
+    let mut next_marker = offset;
+    let mut curve_start = 0.0;
+
+    for (curve_idx, curve) in path.to_curves::<Curve<TCoord>>().into_iter().enumerate() {
+        let length = curve_length(&curve, max_error);
+
+        // Generate the markers that are within this curve
+        while next_marker <= curve_start + length {
+            let t = t_for_length(&curve, next_marker - curve_start, length, max_error);
+
+            markers.push(MarkerPosition { position: curve.point_at_pos(t), angle: direction_angle(curve_direction(&curve, t)), curve_idx, t });
+            next_marker += interval;
+        }
+
+        curve_start += length;
+    }
+
+    markers
+}
+
+///
+/// Finds the curve index and t value of the point that's a particular distance along a set of curves
+///
+fn position_for_length<TCoord>(curves: &[Curve<TCoord>], lengths: &[f64], length: f64, max_error: f64) -> (usize, f64)
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    let mut curve_start = 0.0;
+
+    for (curve_idx, (curve, curve_length)) in curves.iter().zip(lengths.iter()).enumerate() {
+        if length <= curve_start + curve_length {
+            return (curve_idx, t_for_length(curve, length - curve_start, *curve_length, max_error));
+        }
+
+        curve_start += curve_length;
+    }
+
+    (curves.len()-1, 1.0)
+}
+
+///
+/// Finds the distance along a path from one end to the point that's a straight-line distance of `chord_length` from that end
+///
+/// On a curved path, this is further than `chord_length`. If there's no such point, `chord_length` is returned.
+///
+fn length_for_chord<TCoord>(path: &impl BezierPath<Point=TCoord>, chord_length: f64, from_end: bool, max_error: f64) -> f64
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    const MAX_ITERATIONS: usize = 32;
+
+    let curves          = path.to_curves::<Curve<TCoord>>();
+    let lengths         = curves.iter().map(|curve| curve_length(curve, max_error)).collect::<Vec<_>>();
+    let total_length    = lengths.iter().sum::<f64>();
+
+    if curves.is_empty() || total_length <= chord_length { return chord_length; }
+
+    let end_point       = if from_end { curves[curves.len()-1].end_point() } else { curves[0].start_point() };
+    let chord_for       = |length: f64| {
+        let (curve_idx, t) = position_for_length(&curves, &lengths, if from_end { total_length - length } else { length }, max_error);
+        curves[curve_idx].point_at_pos(t).distance_to(&end_point)
+    };
+
+    // The straight-line distance is never more than the distance along the path, so search between the chord length and the length of the path
+    let mut min_length  = chord_length;
+    let mut max_length  = total_length;
+
+    if chord_for(max_length) < chord_length { return chord_length; }
+
+    for _ in 0..MAX_ITERATIONS {
+        let mid_length = (min_length + max_length) * 0.5;
+
+        if chord_for(mid_length) < chord_length {
+            min_length = mid_length;
+        } else {
+            max_length = mid_length;
+        }
+
+        if max_length - min_length < max_error { break; }
+    }
+
+    (min_length + max_length) * 0.5
+}
+
+///
+/// Trims the start and end of a path by a distance measured along the path
+///
+/// This can be used to shorten a path so that it ends at the back edge of a marker. Returns None if the path is not long enough
+/// to trim by the specified amount.
+///
+pub fn path_trim_by_length<POut>(path: &impl BezierPath<Point=POut::Point>, start_length: f64, end_length: f64, max_error: f64) -> Option<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    let curves          = path.to_curves::<Curve<POut::Point>>();
+    let lengths         = curves.iter().map(|curve| curve_length(curve, max_error)).collect::<Vec<_>>();
+    let total_length    = lengths.iter().sum::<f64>();
+
+    let start_length    = start_length.max(0.0);
+    let end_length      = total_length - end_length.max(0.0);
+
+    if curves.is_empty() || end_length <= start_length {
+        return None;
+    }
+
+    // Find where the trimmed path starts and ends
+    let (start_idx, start_t)    = position_for_length(&curves, &lengths, start_length, max_error);
+    let (end_idx, end_t)        = position_for_length(&curves, &lengths, end_length, max_error);
+
+    // Generate the sections of the curves between these points (a trim that ends exactly at the end of a curve leaves nothing of that curve)
+    let points = (start_idx..=end_idx)
+        .filter_map(|curve_idx| {
+            let t1 = if curve_idx == start_idx { start_t } else { 0.0 };
+            let t2 = if curve_idx == end_idx { end_t } else { 1.0 };
+
+            if t1 >= t2 { return None; }
+
+            let (_, (cp1, cp2), end_point) = curves[curve_idx].section(t1, t2).all_points();
+            Some((cp1, cp2, end_point))
+        })
+        .collect::<Vec<_>>();
+
+    Some(POut::from_points(curves[start_idx].point_at_pos(start_t), points))
+}
+
+///
+/// Places a marker (such as an arrowhead) at the end of a path, trimming the path so that it ends at the back edge of the marker
+///
+/// The marker should be drawn with its tip at the origin, pointing along the positive x-axis, with its back edge `marker_length`
+/// units along the negative x-axis. The tip of the marker is placed at the end of the path and it's rotated so that the back edge
+/// is on the path (so on a curved path, the path is trimmed by slightly more than `marker_length`). The result is the trimmed path
+/// (or None if the path is shorter than the marker) and the marker.
+///
+pub fn path_with_end_marker<POut>(path: &impl BezierPath<Point=POut::Point>, marker: &impl BezierPath<Point=POut::Point>, marker_length: f64, max_error: f64) -> (Option<POut>, POut)
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    let trim_length             = length_for_chord(path, marker_length, true, max_error);
+    let trimmed: Option<POut>   = path_trim_by_length(path, 0.0, trim_length, max_error);
+    let end_point               = path.points().last().map(|(_, _, end_point)| end_point).unwrap_or_else(|| path.start_point());
+
+    // Point the marker from the end of the trimmed path to the end of the original path (or along the path if it's too short)
+    let back_point              = trimmed.as_ref().and_then(|trimmed| trimmed.points().last().map(|(_, _, end_point)| end_point));
+    let angle                   = match back_point {
+        Some(back_point) if !back_point.is_near_to(&end_point, SMALL_DISTANCE)  => direction_angle(end_point - back_point),
+        _                                                                       => path_vertex_markers(path).last().map(|marker| marker.angle).unwrap_or(0.0),
+    };
+
+    let position    = MarkerPosition { position: end_point, angle, curve_idx: path.points().count().max(1)-1, t: 1.0 };
+    let marker      = position.place_marker(marker, 1.0);
+
+    (trimmed, marker)
+}
+
+///
+/// Places a marker (such as an arrowhead) at the start of a path, trimming the path so that it starts at the back edge of the marker
+///
+/// The marker is drawn in the same way as for `path_with_end_marker()`, and will point away from the start of the path.
+///
+pub fn path_with_start_marker<POut>(path: &impl BezierPath<Point=POut::Point>, marker: &impl BezierPath<Point=POut::Point>, marker_length: f64, max_error: f64) -> (Option<POut>, POut)
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    let trim_length             = length_for_chord(path, marker_length, false, max_error);
+    let trimmed: Option<POut>   = path_trim_by_length(path, trim_length, 0.0, max_error);
+    let start_point             = path.start_point();
+
+    // Point the marker from the start of the trimmed path to the start of the original path (or backwards along the path if it's too short)
+    let back_point              = trimmed.as_ref().map(|trimmed| trimmed.start_point());
+    let angle                   = match back_point {
+        Some(back_point) if !back_point.is_near_to(&start_point, SMALL_DISTANCE)    => direction_angle(start_point - back_point),
+        _                                                                           => path_vertex_markers(path).first().map(|marker| marker.angle + f64::consts::PI).unwrap_or(0.0),
+    };
+
+    let position    = MarkerPosition { position: start_point, angle, curve_idx: 0, t: 0.0 };
+    let marker      = position.place_marker(marker, 1.0);
+
+    (trimmed, marker)
+}
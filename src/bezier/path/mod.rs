@@ -33,6 +33,7 @@
 //!
 //! There are a few more advanced algorithms: for example, the `flood_fill_concave()` function provides a vector
 //! implementation of the flood fill algorithm, returning a path that fills a space defined by a ray-casting function.
+//! `flood_fill_concave_paths()` does the same thing for the space enclosed by a set of existing paths.
 //!
 
 mod path;
@@ -46,6 +47,14 @@ mod graph_path;
 mod is_clockwise;
 mod arithmetic;
 mod stroke;
+mod dash;
+mod offset;
+mod calligraphy;
+mod markers;
+mod svg;
+mod monotonic;
+mod area;
+mod fill_rule;
 pub mod algorithms;
 
 pub use self::path::*;
@@ -58,3 +67,11 @@ pub use self::graph_path::*;
 pub use self::is_clockwise::*;
 pub use self::arithmetic::*;
 pub use self::stroke::*;
+pub use self::dash::*;
+pub use self::offset::*;
+pub use self::calligraphy::*;
+pub use self::markers::*;
+pub use self::svg::*;
+pub use self::monotonic::*;
+pub use self::area::*;
+pub use self::fill_rule::*;
@@ -0,0 +1,47 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::monotonic::*;
+use super::super::super::geo::*;
+
+///
+/// A monotonic section of a curve from a path
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MonotonicCurve<C> {
+    /// The monotonic curve
+    pub curve: C,
+
+    /// The index of the curve in the original path that this section was taken from
+    pub curve_idx: usize,
+
+    /// The t values (t_min, t_max) of the section on the original curve
+    pub t_range: (f64, f64),
+}
+
+///
+/// Divides the curves in a path into sections that are monotonic in every dimension and which have no inflection points
+///
+/// The curves are returned in the same order as they appear in the path, along with the index of the curve in the path
+/// that they were generated from and the t values that they cover on that curve.
+///
+pub fn path_to_monotonic_curves<P, C>(path: &P) -> Vec<MonotonicCurve<C>>
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+    C:          BezierCurveFactory<Point=P::Point>,
+{
+    let mut monotonic_curves = vec![];
+
+    for (curve_idx, curve) in path_to_curves::<_, Curve<P::Point>>(path).enumerate() {
+        for section in monotonic_sections(&curve) {
+            monotonic_curves.push(MonotonicCurve {
+                curve:      C::from_curve(&section),
+                curve_idx:  curve_idx,
+                t_range:    section.original_curve_t_values(),
+            });
+        }
+    }
+
+    monotonic_curves
+}
@@ -0,0 +1,143 @@
+use super::path::*;
+use super::area::*;
+use super::point::*;
+use super::stroke::*;
+use super::fill_rule::*;
+use super::arithmetic::*;
+
+use crate::geo::*;
+use crate::bezier::*;
+use crate::line::*;
+use crate::consts::*;
+
+///
+/// The accuracy that the offset curves are fitted to and that the arithmetic operations used to tidy up the result use
+///
+const OFFSET_ACCURACY: f64 = 0.01;
+
+///
+/// Generates the path offset by `distance` to the left of a single closed path
+///
+/// The result is not tidied up: it can self-overlap at corners, or can be turned inside out if the path collapses. Joins are
+/// generated for corners where the path turns right (which are the corners on the outside of the offset)
+///
+fn offset_closed_path<TCoord>(path: &(TCoord, Vec<(TCoord, TCoord, TCoord)>), distance: f64, join: LineJoin, miter_limit: f64) -> Option<(TCoord, Vec<(TCoord, TCoord, TCoord)>)>
+where
+    TCoord: Coordinate+Coordinate2D,
+{
+    let join_fn = join.join_function();
+
+    // Generate the curves for the path, closing it if it's not already closed
+    let mut curves  = path.to_curves::<Curve<TCoord>>();
+    let start_point = path.start_point();
+    let end_point   = curves.last().map(|curve| curve.end_point()).unwrap_or(start_point);
+
+    if !start_point.is_near_to(&end_point, SMALL_DISTANCE) {
+        curves.push(line_to_bezier(&(end_point, start_point)));
+    }
+
+    // Use the distance as a guide for the subdivision options
+    let subdivision_options = SubdivisionOffsetOptions::default()
+        .with_max_error(OFFSET_ACCURACY)
+        .with_max_distance(distance.abs() * 20.0);
+
+    // Offset each curve in turn
+    let mut offset_start    = None;
+    let mut points          = vec![];
+    let join_limit          = distance * miter_limit;
+
+    for curve in curves.iter() {
+        stroke_edge(&mut offset_start, &mut points, curve, &subdivision_options, &|_| distance, join_limit, &join_fn);
+    }
+
+    // Join the end of the path back to the start
+    let (offset_start, offset_tangent)  = offset_start?;
+    let (last_point, last_tangent)      = points.last().map(|(_, cp2, ep)| (*ep, *cp2)).unwrap_or((offset_start, offset_tangent));
+    let last_curvature                  = points.last().map(|(cp1, cp2, ep)| end_curvature(*cp1, *cp2, *ep)).unwrap_or(0.0);
+    let start_curvature                 = points.first().map(|(cp1, cp2, _)| start_curvature(offset_start, *cp1, *cp2)).unwrap_or(0.0);
+
+    for (_, (cp1, cp2), ep) in join_fn(start_point, (last_point, last_tangent), (offset_start, offset_tangent), (last_curvature, start_curvature), join_limit) {
+        points.push((cp1, cp2, ep));
+    }
+
+    Some((offset_start, points))
+}
+
+///
+/// Grows or shrinks the region enclosed by a set of closed paths by a fixed distance
+///
+/// A positive `distance` will grow the region (outset) and a negative `distance` will shrink it (inset). The paths are
+/// interpreted in the same way as for the other arithmetic operations: the direction of each path is ignored, and any path
+/// that's inside an odd number of other paths is a hole (so holes will shrink when the region is grown).
+///
+/// `join` determines how the corners of the region are filled in when it's grown (or how the corners of holes are filled in when
+/// it's shrunk). `miter_limit` is the furthest that a miter join can extend from the corner as a multiple of the offset distance:
+/// sharper corners than this will be cut off.
+///
+/// Any parts of the path that overlap each other (for example, around concave corners) are resolved using the arithmetic
+/// operations, and any parts of the region that collapse when it's shrunk are removed.
+///
+pub fn path_offset<POut>(paths: &Vec<impl BezierPath<Point=POut::Point>>, distance: f64, join: LineJoin, miter_limit: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    // Offsetting by 0 just produces the original region
+    if distance == 0.0 {
+        return path_remove_overlapped_points(paths, OFFSET_ACCURACY);
+    }
+
+    // The join functions generate joins for the left-hand side of a path, so the region is arranged so it's on the right when
+    // growing it and the left when shrinking it
+    let region_on_left  = distance < 0.0;
+    let mut offset_paths = vec![];
+
+    for (path_idx, path) in paths.iter().enumerate() {
+        // Paths with no area can't be offset
+        let signed_area = path_signed_area(path);
+        if signed_area == 0.0 { continue; }
+
+        // Paths inside an odd number of other paths are holes
+        let start_point = path.start_point();
+        let depth       = paths.iter().enumerate()
+            .filter(|(other_idx, other_path)| *other_idx != path_idx && path_contains_point(*other_path, &start_point))
+            .count();
+        let is_hole     = depth % 2 != 0;
+
+        // The region is on the left of an anticlockwise path, or on the right of a clockwise one (and the reverse for holes)
+        let path        = (start_point, path.points().collect::<Vec<_>>());
+        let path        = if (signed_area > 0.0) != (region_on_left != is_hole) { path.reversed() } else { path };
+
+        if let Some(offset_path) = offset_closed_path(&path, distance.abs(), join, miter_limit) {
+            offset_paths.push(offset_path);
+        }
+    }
+
+    // The region has a winding number of 1 (or -1 when it's on the right of the paths): parts that have collapsed have the opposite sign
+    let fill_rule       = if region_on_left { FillRule::Positive } else { FillRule::Negative };
+    let offset_region   = path_remove_interior_points_with_fill_rule::<_, POut>(&offset_paths, fill_rule, OFFSET_ACCURACY);
+
+    // If the offset is far enough for the region to turn inside out, the winding number doesn't change, so pieces can be left
+    // over where it collapsed: these are closer to the original paths than the offset distance everywhere
+    let min_distance    = distance.abs() - OFFSET_ACCURACY * 10.0;
+
+    offset_region.into_iter()
+        .filter(|offset_path| max_distance_to_paths(offset_path, paths) >= min_distance)
+        .collect()
+}
+
+///
+/// Returns the furthest distance from the midpoint of a curve in `path` to the closest point on any of the paths in `to_paths`
+///
+fn max_distance_to_paths<TPath>(path: &TPath, to_paths: &Vec<impl BezierPath<Point=TPath::Point>>) -> f64
+where
+    TPath:          BezierPath,
+    TPath::Point:   Coordinate+Coordinate2D,
+{
+    path.to_curves::<Curve<_>>().into_iter()
+        .map(|curve| curve.point_at_pos(0.5))
+        .map(|midpoint| to_paths.iter()
+            .map(|to_path| path_closest_point(to_path, &midpoint).2)
+            .fold(f64::MAX, f64::min))
+        .fold(0.0, f64::max)
+}
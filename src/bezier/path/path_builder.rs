@@ -1,4 +1,7 @@
 use super::path::*;
+use super::svg::*;
+use crate::geo::*;
+use crate::consts::*;
 
 ///
 /// Used to build a bezier path
@@ -8,7 +11,13 @@ pub struct BezierPathBuilder<P: BezierPath> {
     start_point: P::Point,
 
     /// The points in the path
-    points: Vec<(P::Point, P::Point, P::Point)>
+    points: Vec<(P::Point, P::Point, P::Point)>,
+
+    /// If the last command was a cubic curve, the second control point for that curve (used for smooth curves)
+    last_curve_control_point: Option<P::Point>,
+
+    /// If the last command was a quadratic curve, the control point for that curve (used for smooth quadratic curves)
+    last_quad_control_point: Option<P::Point>
 }
 
 impl<P: BezierPathFactory> BezierPathBuilder<P> {
@@ -17,8 +26,10 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
     /// 
     pub fn start(start: P::Point) -> BezierPathBuilder<P> {
         BezierPathBuilder {
-            start_point:    start,
-            points:         vec![]
+            start_point:                start,
+            points:                     vec![],
+            last_curve_control_point:   None,
+            last_quad_control_point:    None
         }
     }
 
@@ -29,22 +40,29 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
         P::from_points(self.start_point, self.points)
     }
 
+    ///
+    /// The point where the next curve added to this path will start
+    ///
+    pub fn current_point(&self) -> P::Point {
+        self.points.last()
+            .map(|(_, _, end_point)| *end_point)
+            .unwrap_or(self.start_point)
+    }
+
     ///
     /// Adds a line to the specified point
     /// 
     pub fn line_to(mut self, point: P::Point) -> Self {
         // Get the vector from the last point to the new point
-        let distance = if self.points.is_empty() {
-            point - self.start_point
-        } else {
-            point - self.points[self.points.len()-1].2
-        };
+        let distance = point - self.current_point();
 
         // A line puts control points at 33% and 66% of the distance
         let cp1 = point - (distance*0.6666);
         let cp2 = point - (distance*0.3333);
 
         self.points.push((cp1, cp2, point));
+        self.last_curve_control_point   = None;
+        self.last_quad_control_point    = None;
 
         self
     }
@@ -54,7 +72,331 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
     /// 
     pub fn curve_to(mut self, (cp1, cp2): (P::Point, P::Point), end_point: P::Point) -> Self {
         self.points.push((cp1, cp2, end_point));
+        self.last_curve_control_point   = Some(cp2);
+        self.last_quad_control_point    = None;
+
+        self
+    }
+
+    ///
+    /// Adds a quadratic curve with a single control point to a particular point
+    ///
+    pub fn quad_to(self, cp: P::Point, end_point: P::Point) -> Self {
+        // Quadratic curves can be represented exactly as a cubic curve with control points 2/3rds of the way to the quadratic control point
+        let start_point = self.current_point();
+        let cp1         = start_point + (cp - start_point)*(2.0/3.0);
+        let cp2         = end_point + (cp - end_point)*(2.0/3.0);
+
+        let mut result  = self.curve_to((cp1, cp2), end_point);
+        result.last_curve_control_point = None;
+        result.last_quad_control_point  = Some(cp);
+
+        result
+    }
+
+    ///
+    /// Adds a curve to a particular point whose first control point is the reflection of the second control point of
+    /// the previous curve, so the path continues smoothly
+    ///
+    /// If the previous curve was not a cubic curve (including when this is the first curve in the path), the first control point
+    /// is the start point, which is the same as the SVG `S` command.
+    ///
+    pub fn smooth_curve_to(self, cp2: P::Point, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        let cp1         = self.last_curve_control_point
+            .map(|last_cp2| start_point*2.0 - last_cp2)
+            .unwrap_or(start_point);
+
+        self.curve_to((cp1, cp2), end_point)
+    }
+
+    ///
+    /// Adds a quadratic curve whose control point is the reflection of the control point of the previous quadratic curve
+    ///
+    /// If the previous curve was not a quadratic curve, the control point is the start point (so this will generate a line)
+    ///
+    pub fn smooth_quad_to(self, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        let cp          = self.last_quad_control_point
+            .map(|last_cp| start_point*2.0 - last_cp)
+            .unwrap_or(start_point);
+
+        self.quad_to(cp, end_point)
+    }
+
+    ///
+    /// Closes the path by adding a line back to the start point
+    ///
+    /// No line is added if the path already ends at its start point. A path that ends very close to its start point
+    /// is adjusted to end exactly at it instead.
+    ///
+    pub fn close(mut self) -> Self {
+        let start_point = self.start_point;
+
+        match self.points.last_mut() {
+            None                                                                                => { }
+            Some((_, _, end_point)) if *end_point == start_point                                => { }
+            Some((_, cp2, end_point)) if end_point.is_near_to(&start_point, SMALL_DISTANCE)     => {
+                *cp2        = *cp2 + (start_point - *end_point);
+                *end_point  = start_point;
+            }
+            Some(_)                                                                             => { self = self.line_to(start_point); }
+        }
+
+        self.last_curve_control_point   = None;
+        self.last_quad_control_point    = None;
+        self
+    }
+
+    ///
+    /// Adds a line to a point relative to the current point
+    ///
+    pub fn line_by(self, offset: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.line_to(start_point + offset)
+    }
+
+    ///
+    /// Adds a curve with all its points relative to the current point
+    ///
+    pub fn curve_by(self, (cp1, cp2): (P::Point, P::Point), end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.curve_to((start_point + cp1, start_point + cp2), start_point + end_point)
+    }
+
+    ///
+    /// Adds a quadratic curve with all its points relative to the current point
+    ///
+    pub fn quad_by(self, cp: P::Point, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.quad_to(start_point + cp, start_point + end_point)
+    }
+
+    ///
+    /// Adds a smooth curve with its points relative to the current point
+    ///
+    pub fn smooth_curve_by(self, cp2: P::Point, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.smooth_curve_to(start_point + cp2, start_point + end_point)
+    }
+
+    ///
+    /// Adds a smooth quadratic curve with its end point relative to the current point
+    ///
+    pub fn smooth_quad_by(self, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.smooth_quad_to(start_point + end_point)
+    }
+}
+
+impl<P: BezierPathFactory> BezierPathBuilder<P>
+where
+    P::Point: Coordinate2D,
+{
+    ///
+    /// Adds the curves for an elliptical arc, specified in the same way as for the SVG 'A' command
+    ///
+    /// The ellipse has the radii `(radius_x, radius_y)` and its x-axis is rotated by `x_axis_rotation` degrees. Of the
+    /// four possible arcs that join the current point to the end point, `large_arc` chooses the one that covers more than
+    /// 180 degrees and `sweep` chooses the one that goes in the direction of increasing angle. The radii are scaled up if
+    /// they are too small for the ellipse to reach the end point, and the arc is a straight line if either radius is 0.
+    ///
+    pub fn arc_to(mut self, (radius_x, radius_y): (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        let to_point    = |(x, y): (f64, f64)| P::Point::from_components(&[x, y]);
+
+        let curves      = svg_arc_curves((start_point.x(), start_point.y()), (radius_x, radius_y), x_axis_rotation, large_arc, sweep, (end_point.x(), end_point.y()));
+
+        for (cp1, cp2, curve_end_point) in curves {
+            self.points.push((to_point(cp1), to_point(cp2), to_point(curve_end_point)));
+        }
+
+        // Make sure that the path ends exactly at the requested point
+        if let Some((_, _, last_point)) = self.points.last_mut() {
+            *last_point = end_point;
+        }
+
+        self.last_curve_control_point   = None;
+        self.last_quad_control_point    = None;
+        self
+    }
+
+    ///
+    /// Adds a circular arc that continues in the direction the path is already going and ends at the specified point
+    ///
+    /// A line is added instead if the end point is directly ahead of the current point, or if the path has no curves
+    /// yet and so has no direction.
+    ///
+    pub fn tangent_arc_to(self, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+
+        // Find the direction that the path is travelling in at the moment (from the last curve)
+        let direction   = self.points.last().and_then(|(cp1, cp2, _)| {
+            let previous_point = if self.points.len() > 1 { self.points[self.points.len()-2].2 } else { self.start_point };
+
+            vec![start_point - *cp2, start_point - *cp1, start_point - previous_point].into_iter()
+                .find(|direction| direction.magnitude() > SMALL_DISTANCE)
+        });
+        let direction   = if let Some(direction) = direction { direction.to_unit_vector() } else { return self.line_to(end_point); };
+
+        // The chord of the arc and which side of the current direction it's on
+        let chord       = end_point - start_point;
+        let cross       = direction.x()*chord.y() - direction.y()*chord.x();
+        let dot         = direction.dot(&chord);
+
+        if cross.abs() <= SMALL_DISTANCE * chord.magnitude() && dot >= 0.0 {
+            return self.line_to(end_point);
+        }
+
+        // The center is on the normal to the current direction, equidistant from the start and end points
+        let radius      = chord.dot(&chord) / (2.0 * cross.abs());
+
+        self.arc_to((radius, radius), 0.0, dot < 0.0, cross > 0.0, end_point)
+    }
+
+    ///
+    /// Adds an elliptical arc with its end point relative to the current point
+    ///
+    pub fn arc_by(self, radius: (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.arc_to(radius, x_axis_rotation, large_arc, sweep, start_point + end_point)
+    }
+
+    ///
+    /// Adds a tangent arc with its end point relative to the current point
+    ///
+    pub fn tangent_arc_by(self, end_point: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.tangent_arc_to(start_point + end_point)
+    }
+}
+
+///
+/// Used to build a set of paths, such as a shape with holes in it, by moving between subpaths
+///
+/// Subpaths that have no curves in them are left out of the result.
+///
+pub struct CompoundPathBuilder<P: BezierPath> {
+    /// The subpaths that have been finished
+    paths: Vec<P>,
+
+    /// The subpath that is being built
+    current: BezierPathBuilder<P>
+}
+
+impl<P: BezierPathFactory> CompoundPathBuilder<P> {
+    ///
+    /// Creates a new compound path builder with its first subpath starting at the specified point
+    ///
+    pub fn start(start: P::Point) -> CompoundPathBuilder<P> {
+        CompoundPathBuilder {
+            paths:      vec![],
+            current:    BezierPathBuilder::start(start)
+        }
+    }
+
+    ///
+    /// Builds the paths for this builder
+    ///
+    pub fn build(mut self) -> Vec<P> {
+        if !self.current.points.is_empty() {
+            self.paths.push(self.current.build());
+        }
+
+        self.paths
+    }
+
+    ///
+    /// Applies an operation to the subpath that's being built
+    ///
+    fn with_current(mut self, operation: impl FnOnce(BezierPathBuilder<P>) -> BezierPathBuilder<P>) -> Self {
+        let start_point = self.current.start_point;
+        let current     = std::mem::replace(&mut self.current, BezierPathBuilder::start(start_point));
+        self.current    = operation(current);
+
+        self
+    }
+
+    ///
+    /// The point where the next curve added to this path will start
+    ///
+    pub fn current_point(&self) -> P::Point {
+        self.current.current_point()
+    }
+
+    ///
+    /// Finishes the current subpath and starts a new one at the specified point
+    ///
+    pub fn move_to(mut self, point: P::Point) -> Self {
+        let current = std::mem::replace(&mut self.current, BezierPathBuilder::start(point));
+
+        if !current.points.is_empty() {
+            self.paths.push(current.build());
+        }
 
         self
     }
-}
\ No newline at end of file
+
+    ///
+    /// Finishes the current subpath and starts a new one at a point relative to the current point
+    ///
+    pub fn move_by(self, offset: P::Point) -> Self {
+        let start_point = self.current_point();
+        self.move_to(start_point + offset)
+    }
+
+    ///
+    /// Closes the current subpath, and starts a new subpath at the same start point
+    ///
+    pub fn close(self) -> Self {
+        let start_point = self.current.start_point;
+        self.with_current(|current| current.close()).move_to(start_point)
+    }
+
+    /// Adds a line to the specified point
+    pub fn line_to(self, point: P::Point) -> Self { self.with_current(|current| current.line_to(point)) }
+
+    /// Adds a curve to a particular point
+    pub fn curve_to(self, cps: (P::Point, P::Point), end_point: P::Point) -> Self { self.with_current(|current| current.curve_to(cps, end_point)) }
+
+    /// Adds a quadratic curve to a particular point
+    pub fn quad_to(self, cp: P::Point, end_point: P::Point) -> Self { self.with_current(|current| current.quad_to(cp, end_point)) }
+
+    /// Adds a curve that continues smoothly from the previous curve
+    pub fn smooth_curve_to(self, cp2: P::Point, end_point: P::Point) -> Self { self.with_current(|current| current.smooth_curve_to(cp2, end_point)) }
+
+    /// Adds a quadratic curve that continues smoothly from the previous quadratic curve
+    pub fn smooth_quad_to(self, end_point: P::Point) -> Self { self.with_current(|current| current.smooth_quad_to(end_point)) }
+
+    /// Adds a line to a point relative to the current point
+    pub fn line_by(self, offset: P::Point) -> Self { self.with_current(|current| current.line_by(offset)) }
+
+    /// Adds a curve with all its points relative to the current point
+    pub fn curve_by(self, cps: (P::Point, P::Point), end_point: P::Point) -> Self { self.with_current(|current| current.curve_by(cps, end_point)) }
+
+    /// Adds a quadratic curve with all its points relative to the current point
+    pub fn quad_by(self, cp: P::Point, end_point: P::Point) -> Self { self.with_current(|current| current.quad_by(cp, end_point)) }
+
+    /// Adds a smooth curve with its points relative to the current point
+    pub fn smooth_curve_by(self, cp2: P::Point, end_point: P::Point) -> Self { self.with_current(|current| current.smooth_curve_by(cp2, end_point)) }
+
+    /// Adds a smooth quadratic curve with its end point relative to the current point
+    pub fn smooth_quad_by(self, end_point: P::Point) -> Self { self.with_current(|current| current.smooth_quad_by(end_point)) }
+}
+
+impl<P: BezierPathFactory> CompoundPathBuilder<P>
+where
+    P::Point: Coordinate2D,
+{
+    /// Adds an elliptical arc, specified in the same way as for the SVG 'A' command
+    pub fn arc_to(self, radius: (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end_point: P::Point) -> Self { self.with_current(|current| current.arc_to(radius, x_axis_rotation, large_arc, sweep, end_point)) }
+
+    /// Adds a circular arc that continues in the direction the path is already going
+    pub fn tangent_arc_to(self, end_point: P::Point) -> Self { self.with_current(|current| current.tangent_arc_to(end_point)) }
+
+    /// Adds an elliptical arc with its end point relative to the current point
+    pub fn arc_by(self, radius: (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end_point: P::Point) -> Self { self.with_current(|current| current.arc_by(radius, x_axis_rotation, large_arc, sweep, end_point)) }
+
+    /// Adds a tangent arc with its end point relative to the current point
+    pub fn tangent_arc_by(self, end_point: P::Point) -> Self { self.with_current(|current| current.tangent_arc_by(end_point)) }
+}
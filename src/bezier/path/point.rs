@@ -154,11 +154,9 @@ where
 }
 
 ///
-/// Returns true if a particular point is within a bezier path
+/// Counts the number of times a path winds around a point, using a ray cast from outside of the bounds of the path
 ///
-/// If checking a lot of points against a path, consider using the `PathContour` type
-/// 
-pub fn path_contains_point<P: BezierPath>(path: &P, point: &P::Point) -> bool
+fn path_ray_winding_number<P: BezierPath>(path: &P, point: &P::Point) -> i32
 where 
     P::Point: Coordinate2D,
 {
@@ -167,7 +165,7 @@ where
 
     if min_bounds.x() > point.x() || max_bounds.x() < point.x() || min_bounds.y() > point.y() || max_bounds.y() < point.y() {
         // Point is outside the bounds of the path
-        false
+        0
     } else {
         // Ray is from the top of the bounds to our point
         let ray             = (max_bounds + P::Point::from_components(&[0.01, 0.01]), *point);
@@ -195,7 +193,37 @@ where
             total_direction += direction;
         }
 
-        // Point is inside the path if the ray crosses more lines facing in a particular direction
-        total_direction != 0
+        total_direction
     }
 }
+
+///
+/// Returns true if a particular point is within a bezier path
+///
+/// If checking a lot of points against a path, consider using the `PathContour` type
+/// 
+pub fn path_contains_point<P: BezierPath>(path: &P, point: &P::Point) -> bool
+where 
+    P::Point: Coordinate2D,
+{
+    // Point is inside the path if the ray crosses more lines facing in a particular direction
+    path_ray_winding_number(path, point) != 0
+}
+
+///
+/// Returns the winding number of a point relative to a set of paths
+///
+/// This is the number of times the paths go around the point in an anticlockwise direction (when the y axis points upwards),
+/// minus the number of times they go around it in a clockwise direction. A `FillRule` can be used to determine if the point
+/// is inside the shape from the result.
+///
+/// Points that are exactly on an edge of one of the paths may be counted as either inside or outside that path.
+///
+pub fn path_winding_number<P: BezierPath>(paths: &[P], point: &P::Point) -> i32
+where 
+    P::Point: Coordinate2D,
+{
+    paths.iter()
+        .map(|path| path_ray_winding_number(path, point))
+        .sum()
+}
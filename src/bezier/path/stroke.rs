@@ -1,10 +1,12 @@
 use super::path::*;
+use super::dash::*;
 use super::arithmetic::*;
 
 use crate::geo::*;
 use crate::arc::*;
 use crate::bezier::*;
 use crate::line::*;
+use crate::consts::*;
 
 use std::f64;
 
@@ -13,9 +15,20 @@ use std::f64;
 ///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum LineJoin {
+    /// Extends the edges until they meet, or uses a bevel join if they meet further away than the miter limit (SVG `miter`)
     Miter,
+
+    /// Joins the edges with a circular arc
     Round,
+
+    /// Joins the edges with a straight line
     Bevel,
+
+    /// Extends the edges until they meet, cutting off the corner at the miter limit (SVG 2 `miter-clip`)
+    MiterClip,
+
+    /// Extends the edges with arcs that match their curvature until they meet, cutting off the corner at the miter limit (SVG 2 `arcs`)
+    Arcs,
 }
 
 ///
@@ -23,25 +36,50 @@ pub enum LineJoin {
 ///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum LineCap {
+    /// The line ends at the end of the path
     Butt,
+
+    /// The line ends with a semicircle centered on the end of the path
     Round,
+
+    /// The line is extended past the end of the path by half its width
     Square
 }
 
+///
+/// Where a stroke should be drawn relative to the path
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum StrokeAlignment {
+    /// The stroke is centered on the path
+    Center,
+
+    /// The stroke is drawn inside the region enclosed by the path
+    Inside,
+
+    /// The stroke is drawn outside the region enclosed by the path
+    Outside,
+}
+
 ///
 /// Settings for a line stroke operation
 ///
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+/// These are not `Copy` as they can contain a dash pattern: use `clone()` to make a copy.
+///
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
 pub struct StrokeOptions {
     /// How accurately to match the curves,
-    accuracy: f64,
+    pub (crate) accuracy: f64,
 
     /// The minimum distance between samples, when the minimum tangent is not reached
-    min_sample_distance: f64,
+    pub (crate) min_sample_distance: f64,
 
     /// How two lines should be joined together
     join: LineJoin,
 
+    /// The furthest that a miter join can extend from the corner, as a multiple of half the line width
+    miter_limit: f64,
+
     /// How to start the line
     start_cap: LineCap,
 
@@ -50,6 +88,15 @@ pub struct StrokeOptions {
 
     /// Set to true if the interior points should be removed from the resulting stroke (producing a path that is always non-overlapping)
     remove_interior_points: bool,
+
+    /// Where the stroke is drawn relative to the path
+    alignment: StrokeAlignment,
+
+    /// The lengths of the dashes and the gaps between them (or empty for a solid line)
+    pub (crate) dash_array: Vec<f64>,
+
+    /// The distance into the dash pattern where the line starts
+    pub (crate) dash_offset: f64,
 }
 
 impl Default for StrokeOptions {
@@ -59,9 +106,13 @@ impl Default for StrokeOptions {
             accuracy:               0.1,
             min_sample_distance:    0.1,
             join:                   LineJoin::Bevel,
+            miter_limit:            4.0,
             start_cap:              LineCap::Butt,
             end_cap:                LineCap::Butt,
             remove_interior_points: false,
+            alignment:              StrokeAlignment::Center,
+            dash_array:             vec![],
+            dash_offset:            0.0,
         }
     }
 }
@@ -99,6 +150,19 @@ impl StrokeOptions {
         self
     }
 
+    ///
+    /// Sets the miter limit for the miter, miter-clip and arcs join styles
+    ///
+    /// This is the furthest that the join can extend from the corner, as a multiple of half the line width (this is the same as
+    /// the SVG `stroke-miterlimit` property). Miter joins that are longer than this are drawn as bevel joins, and the other
+    /// styles are cut off at this distance. The default is 4.
+    ///
+    #[inline]
+    pub fn with_miter_limit(mut self, miter_limit: f64) -> Self {
+        self.miter_limit = miter_limit;
+        self
+    }
+
     ///
     /// Sets the type of start cap to generate 
     ///
@@ -129,6 +193,43 @@ impl StrokeOptions {
         self.remove_interior_points = true;
         self
     }
+
+    ///
+    /// Sets where the stroke is drawn relative to the path
+    ///
+    /// Strokes are centered on the path by default. For closed paths, `StrokeAlignment::Inside` will draw the stroke entirely
+    /// inside the region enclosed by the path and `StrokeAlignment::Outside` will draw it entirely outside, so the stroke has the
+    /// full width on one side of the path. The ends of the path are joined using the join style, and the result is always
+    /// non-overlapping. Open paths don't enclose a region, so their strokes are always centered.
+    ///
+    #[inline]
+    pub fn with_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    ///
+    /// Sets the dash pattern for the line
+    ///
+    /// The values alternate between the length of a dash and the length of the gap after it, and are measured along the path. If
+    /// there are an odd number of values, the pattern is repeated to make an even number. Each dash is stroked separately, with
+    /// its own start and end caps, and dashes with a length of 0 produce a dot when the line has round or square caps. An empty
+    /// pattern (the default) produces a solid line, as do patterns with negative values or a total length of 0.
+    ///
+    #[inline]
+    pub fn with_dash_array(mut self, dash_array: impl IntoIterator<Item=f64>) -> Self {
+        self.dash_array = dash_array.into_iter().collect();
+        self
+    }
+
+    ///
+    /// Sets the distance into the dash pattern where the line starts
+    ///
+    #[inline]
+    pub fn with_dash_offset(mut self, dash_offset: f64) -> Self {
+        self.dash_offset = dash_offset;
+        self
+    }
 }
 
 impl LineJoin {
@@ -136,7 +237,7 @@ impl LineJoin {
     /// Returns the function to use for joining line segments together for a particular join style
     ///
     #[inline]
-    fn join_function<TCoord>(&self) -> impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+    pub (crate) fn join_function<TCoord>(&self) -> impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), (f64, f64), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
     where
         TCoord: Coordinate + Coordinate2D,
     {
@@ -144,15 +245,155 @@ impl LineJoin {
             LineJoin::Miter     => miter_join,
             LineJoin::Round     => round_join,
             LineJoin::Bevel     => bevel_join,
+            LineJoin::MiterClip => miter_clip_join,
+            LineJoin::Arcs      => arcs_join,
+        }
+    }
+}
+
+impl LineCap {
+    ///
+    /// Returns the function to use for drawing the cap where the line reverses at the end of a path
+    ///
+    /// Caps have the same signature as joins: the first point is the end of the path, and the cap is drawn from the end of the edge
+    /// on one side of the line to the start of the edge on the other side.
+    ///
+    #[inline]
+    pub (crate) fn cap_function<TCoord>(&self) -> impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), (f64, f64), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+    where
+        TCoord: Coordinate + Coordinate2D,
+    {
+        match self {
+            LineCap::Butt       => butt_cap,
+            LineCap::Round      => round_cap,
+            LineCap::Square     => square_cap,
         }
     }
 }
 
+///
+/// Returns true if a join between two edges is on the outside of the corner
+///
+/// Edges where the path doubles back on itself are always considered to be on the outside of the corner, so hairpin turns are
+/// drawn using the join style. The end of a path also reverses the line, but is drawn using the end cap instead of a join.
+///
+#[inline]
+fn is_outer_corner<TCoord>(start_line: &(TCoord, TCoord), end_line: &(TCoord, TCoord)) -> bool
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    const VERY_CLOSE: f64   = 1e-5;
+    const REVERSING: f64    = 1e-6;
+
+    if start_line.0.is_near_to(&end_line.0, VERY_CLOSE) {
+        false
+    } else {
+        // Angles greater than PI are outer corners, and angles close to 0 are where the edges have reversed
+        let angle = start_line.angle_to(end_line);
+        !(REVERSING..=f64::consts::PI).contains(&angle)
+    }
+}
+
+///
+/// Returns the curvature at the start of a bezier curve (positive values indicate the curve is turning anticlockwise)
+///
+#[inline]
+pub (crate) fn start_curvature<TCoord>(start_point: TCoord, cp1: TCoord, cp2: TCoord) -> f64
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    let d1      = cp1 - start_point;
+    let d2      = cp2 - cp1;
+    let speed   = d1.magnitude();
+
+    if speed < 1e-8 { 0.0 } else { (2.0/3.0) * (d1.x()*d2.y() - d1.y()*d2.x()) / (speed*speed*speed) }
+}
+
+///
+/// Returns the curvature at the end of a bezier curve (positive values indicate the curve is turning anticlockwise)
+///
+#[inline]
+pub (crate) fn end_curvature<TCoord>(cp1: TCoord, cp2: TCoord, end_point: TCoord) -> f64
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    let d1      = cp2 - cp1;
+    let d2      = end_point - cp2;
+    let speed   = d2.magnitude();
+
+    if speed < 1e-8 { 0.0 } else { (2.0/3.0) * (d1.x()*d2.y() - d1.y()*d2.x()) / (speed*speed*speed) }
+}
+
+///
+/// Generates a set of bezier curves following a circular arc from `start_point` to `end_point`, leaving `start_point` in the
+/// direction of `start_direction`
+///
+fn arc_curves<TCoord>(center_point: TCoord, start_point: TCoord, end_point: TCoord, start_direction: TCoord) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    // Arc angles are measured clockwise from the y axis
+    let radius          = center_point.distance_to(&start_point);
+    let start_offset    = start_point - center_point;
+    let end_offset      = end_point - center_point;
+    let angle_1         = f64::atan2(start_offset.x(), start_offset.y());
+    let angle_2         = f64::atan2(end_offset.x(), end_offset.y());
+
+    // The arc should continue in the start direction
+    let clockwise       = angle_1.cos()*start_direction.x() - angle_1.sin()*start_direction.y() >= 0.0;
+    let mut sweep       = angle_2 - angle_1;
+
+    if clockwise && sweep < 0.0 { sweep += 2.0*f64::consts::PI; }
+    if !clockwise && sweep > 0.0 { sweep -= 2.0*f64::consts::PI; }
+
+    // Arcs are inaccurate if they cover more than 90 degrees, so subdivide large arcs
+    let circle          = Circle::new(center_point, radius);
+    let num_sections    = (sweep.abs() / (f64::consts::PI/2.0)).ceil().max(1.0) as usize;
+    let section_sweep   = sweep / (num_sections as f64);
+
+    (0..num_sections)
+        .map(|section| {
+            let section_start = angle_1 + section_sweep * (section as f64);
+            circle.arc(section_start, section_start + section_sweep).to_bezier_curve::<Curve<_>>().all_points()
+        })
+        .collect()
+}
+
 ///
 /// The bevel join is the simplest way to join two lines, it will just join the two coordinates together
 ///
 #[inline]
-fn bevel_join<TCoord>(_join_point: TCoord, (start_point, _start_tangent): (TCoord, TCoord), (end_point, _end_tangent): (TCoord, TCoord), _limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+fn bevel_join<TCoord>(_join_point: TCoord, (start_point, _start_tangent): (TCoord, TCoord), (end_point, _end_tangent): (TCoord, TCoord), _curvature: (f64, f64), _limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    vec![line_to_bezier::<Curve<_>>(&(start_point, end_point)).all_points()]
+}
+
+///
+/// Returns the direction that the line is travelling in at a cap running from `start_point` to `end_point`
+///
+/// The cap always crosses the line from its left-hand edge to its right-hand edge, so the direction is perpendicular to the cap
+///
+#[inline]
+fn cap_direction<TCoord>(start_point: TCoord, end_point: TCoord) -> Option<TCoord>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    let across = end_point - start_point;
+
+    if across.magnitude() < 1e-8 {
+        None
+    } else {
+        Some(TCoord::from_components(&[-across.y(), across.x()]).to_unit_vector())
+    }
+}
+
+///
+/// The butt cap ends the line at the end of the path
+///
+#[inline]
+fn butt_cap<TCoord>(_end_point: TCoord, (start_point, _start_tangent): (TCoord, TCoord), (end_point, _end_tangent): (TCoord, TCoord), _curvature: (f64, f64), _limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
 where
     TCoord: Coordinate + Coordinate2D,
 {
@@ -160,107 +401,311 @@ where
 }
 
 ///
-/// The miter join extends the lines from the two edges of the curve until they meet (or up until a particular limit)
+/// The round cap ends the line with a semicircle around the end of the path
+///
+fn round_cap<TCoord>(center_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    if let Some(direction) = cap_direction(start_line.0, end_line.0) {
+        let mut curves  = arc_curves(center_point, start_line.0, end_line.0, direction);
+
+        // The two sides can be at different distances from the end of the path if the width of the line is changing
+        let arc_end     = curves.last().map(|(_, _, ep)| *ep).unwrap_or(start_line.0);
+        if !arc_end.is_near_to(&end_line.0, 0.01) {
+            curves.push(line_to_bezier::<Curve<_>>(&(arc_end, end_line.0)).all_points());
+        }
+
+        curves
+    } else {
+        butt_cap(center_point, start_line, end_line, curvature, limit)
+    }
+}
+
+///
+/// The square cap extends the line past the end of the path by half its width
+///
+fn square_cap<TCoord>(center_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    if let Some(direction) = cap_direction(start_line.0, end_line.0) {
+        let start_corner    = start_line.0 + direction * start_line.0.distance_to(&center_point);
+        let end_corner      = end_line.0 + direction * end_line.0.distance_to(&center_point);
+
+        vec![
+            line_to_bezier::<Curve<_>>(&(start_line.0, start_corner)).all_points(),
+            line_to_bezier::<Curve<_>>(&(start_corner, end_corner)).all_points(),
+            line_to_bezier::<Curve<_>>(&(end_corner, end_line.0)).all_points(),
+        ]
+    } else {
+        butt_cap(center_point, start_line, end_line, curvature, limit)
+    }
+}
+
+///
+/// The miter join extends the lines from the two edges of the curve until they meet, falling back to a bevel join if the point
+/// where they meet is further than `limit` from the join point (this is the SVG `miter` join)
 ///
 #[inline]
-fn miter_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+fn miter_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
 where
     TCoord: Coordinate + Coordinate2D,
 {
     // Must be the outer part of the corner
-    if start_line.angle_to(&end_line) > f64::consts::PI {
-        // Find where the curves intersect
+    if is_outer_corner(&start_line, &end_line) {
+        // Find where the edges intersect
         if let Some(final_point) = ray_intersects_ray(&start_line, &end_line) {
-            if start_line.0.is_near_to(&final_point, limit) {
-                // Draw to the intersection point if the lines are shorter than the limit
-                vec![
+            if join_point.is_near_to(&final_point, limit) {
+                // Draw to the intersection point if it's within the limit
+                return vec![
                     line_to_bezier::<Curve<_>>(&(start_line.0, final_point)).all_points(),
                     line_to_bezier::<Curve<_>>(&(final_point, end_line.0)).all_points(),
-                ]
-            } else {
-                // Draw to the limit if the lines are very long
-                let start_vector    = (start_line.0 - start_line.1).to_unit_vector();
-                let end_vector      = (end_line.0 - end_line.1).to_unit_vector();
-                let start_vector    = start_vector * limit;
-                let end_vector      = end_vector * limit;
-
-                vec![
-                    line_to_bezier::<Curve<_>>(&(start_line.0, start_line.0 + start_vector)).all_points(),
-                    line_to_bezier::<Curve<_>>(&(start_line.0 + start_vector, end_line.0 + end_vector)).all_points(),
-                    line_to_bezier::<Curve<_>>(&(end_line.0 + end_vector, end_line.0)).all_points(),
-                ]
+                ];
             }
+        }
+    }
+
+    // Bevel join on the inside part of the corner, if the rays don't intersect or if the miter is too long
+    bevel_join(join_point, start_line, end_line, curvature, limit)
+}
+
+///
+/// The miter clip join extends the lines from the two edges of the curve until they meet, and cuts off the corner if the point
+/// where they meet is further than `limit` from the join point (this is the SVG 2 `miter-clip` join)
+///
+fn miter_clip_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    const VERY_SMALL: f64 = 1e-8;
+
+    // Must be the outer part of the corner
+    if !is_outer_corner(&start_line, &end_line) {
+        return bevel_join(join_point, start_line, end_line, curvature, limit);
+    }
+
+    // The direction the path is travelling in at the end of the first edge and the start of the second
+    let start_direction = (start_line.0 - start_line.1).to_unit_vector();
+    let end_direction   = (end_line.1 - end_line.0).to_unit_vector();
+
+    // The miter is clipped by a line perpendicular to the line bisecting the corner
+    let bisector        = start_direction - end_direction;
+    if bisector.magnitude() < VERY_SMALL {
+        return bevel_join(join_point, start_line, end_line, curvature, limit);
+    }
+    let bisector        = bisector.to_unit_vector();
+
+    // Use the miter point if it's inside the limit
+    if let Some(miter_point) = ray_intersects_ray(&start_line, &end_line) {
+        if (miter_point - join_point).dot(&bisector) <= limit {
+            return vec![
+                line_to_bezier::<Curve<_>>(&(start_line.0, miter_point)).all_points(),
+                line_to_bezier::<Curve<_>>(&(miter_point, end_line.0)).all_points(),
+            ];
+        }
+    }
+
+    // Find where the two edges cross the clipping line
+    let start_distance  = (start_line.0 - join_point).dot(&bisector);
+    let end_distance    = (end_line.0 - join_point).dot(&bisector);
+    let start_speed     = start_direction.dot(&bisector);
+    let end_speed       = -end_direction.dot(&bisector);
+
+    if start_distance >= limit || end_distance >= limit || start_speed < VERY_SMALL || end_speed < VERY_SMALL {
+        // The edges are already beyond the limit
+        return bevel_join(join_point, start_line, end_line, curvature, limit);
+    }
+
+    let clip_start      = start_line.0 + start_direction * ((limit - start_distance) / start_speed);
+    let clip_end        = end_line.0 - end_direction * ((limit - end_distance) / end_speed);
+
+    vec![
+        line_to_bezier::<Curve<_>>(&(start_line.0, clip_start)).all_points(),
+        line_to_bezier::<Curve<_>>(&(clip_start, clip_end)).all_points(),
+        line_to_bezier::<Curve<_>>(&(clip_end, end_line.0)).all_points(),
+    ]
+}
+
+///
+/// An edge being extended by the arcs join: either a straight line, or a circle matching the curvature of the edge
+///
+enum ArcsExtension<TCoord> {
+    Line(TCoord, TCoord),
+    Circle(TCoord, f64),
+}
+
+impl<TCoord: Coordinate+Coordinate2D> ArcsExtension<TCoord> {
+    ///
+    /// Creates the extension for an edge leaving `point` in `direction` with a particular curvature
+    ///
+    fn new(point: TCoord, direction: TCoord, curvature: f64) -> Self {
+        const MIN_CURVATURE: f64 = 1e-6;
+
+        if curvature.abs() < MIN_CURVATURE {
+            ArcsExtension::Line(point, direction)
         } else {
-            // If the rays don't intersect, use a bevel join instead
-            bevel_join(join_point, start_line, end_line, limit)
+            let left_normal = TCoord::from_components(&[-direction.y(), direction.x()]);
+            let radius      = 1.0 / curvature;
+
+            ArcsExtension::Circle(point + left_normal * radius, radius.abs())
         }
-    } else {
-        // Bevel join on the inside part of the corner
-        bevel_join(join_point, start_line, end_line, limit)
+    }
+
+    ///
+    /// Finds the points where two extensions meet
+    ///
+    fn intersections(&self, other: &ArcsExtension<TCoord>) -> Vec<TCoord> {
+        match (self, other) {
+            (ArcsExtension::Line(p1, d1), ArcsExtension::Line(p2, d2))      => ray_intersects_ray(&(*p1, *p1 + *d1), &(*p2, *p2 + *d2)).into_iter().collect(),
+            (ArcsExtension::Line(p, d), ArcsExtension::Circle(c, r))        |
+            (ArcsExtension::Circle(c, r), ArcsExtension::Line(p, d))        => {
+                // Solve |p + s*d - c| = r (d is a unit vector)
+                let offset  = *p - *c;
+                let b       = offset.dot(d);
+                let det     = b*b - (offset.dot(&offset) - r*r);
+
+                if det < 0.0 {
+                    vec![]
+                } else {
+                    let det = det.sqrt();
+                    vec![*p + *d * (-b - det), *p + *d * (-b + det)]
+                }
+            }
+            (ArcsExtension::Circle(c1, r1), ArcsExtension::Circle(c2, r2))  => {
+                let distance = c1.distance_to(c2);
+
+                if distance < 1e-8 || distance > r1 + r2 || distance < (r1 - r2).abs() {
+                    vec![]
+                } else {
+                    // Point between the two centers on the line joining the intersections
+                    let along       = (r1*r1 - r2*r2 + distance*distance) / (2.0*distance);
+                    let height      = (r1*r1 - along*along).max(0.0).sqrt();
+                    let direction   = (*c2 - *c1) * (1.0/distance);
+                    let mid_point   = *c1 + direction * along;
+                    let normal      = TCoord::from_components(&[-direction.y(), direction.x()]);
+
+                    vec![mid_point + normal * height, mid_point - normal * height]
+                }
+            }
+        }
+    }
+
+    ///
+    /// Generates the curves that follow this extension from `start_point` (which must be on the extension) to `end_point`
+    ///
+    fn curves_to(&self, start_point: TCoord, end_point: TCoord, direction: TCoord) -> Vec<(TCoord, (TCoord, TCoord), TCoord)> {
+        match self {
+            ArcsExtension::Line(_, _)       => vec![line_to_bezier::<Curve<_>>(&(start_point, end_point)).all_points()],
+            ArcsExtension::Circle(c, _)     => arc_curves(*c, start_point, end_point, direction),
+        }
+    }
+}
+
+///
+/// The arcs join extends the two edges using circular arcs that match their curvature until they meet. If they don't meet, or
+/// if the point where they meet is further than `limit` from the join point, this behaves like the miter clip join (this is the
+/// SVG 2 `arcs` join)
+///
+fn arcs_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    // Must be the outer part of the corner
+    if !is_outer_corner(&start_line, &end_line) {
+        return bevel_join(join_point, start_line, end_line, curvature, limit);
+    }
+
+    // Extend the first edge forwards and the second edge backwards (which reverses its curvature)
+    let start_direction = (start_line.0 - start_line.1).to_unit_vector();
+    let end_direction   = (end_line.0 - end_line.1).to_unit_vector();
+    let (start_k, end_k) = curvature;
+
+    let start_extension = ArcsExtension::new(start_line.0, start_direction, start_k);
+    let end_extension   = ArcsExtension::new(end_line.0, end_direction, -end_k);
+
+    // Pick the closest meeting point that's in front of both edges
+    let meeting_point   = start_extension.intersections(&end_extension)
+        .into_iter()
+        .filter(|point| (*point - start_line.0).dot(&start_direction) > 0.0 && (*point - end_line.0).dot(&end_direction) > 0.0)
+        .min_by(|a, b| {
+            let distance_a = a.distance_to(&start_line.0) + a.distance_to(&end_line.0);
+            let distance_b = b.distance_to(&start_line.0) + b.distance_to(&end_line.0);
+            distance_a.total_cmp(&distance_b)
+        });
+
+    match meeting_point {
+        Some(meeting_point) if join_point.is_near_to(&meeting_point, limit) => {
+            // Follow the first extension to the meeting point, then follow the second extension back to the start of the next edge
+            let mut curves  = start_extension.curves_to(start_line.0, meeting_point, start_direction);
+            let end_curves  = end_extension.curves_to(end_line.0, meeting_point, end_direction);
+
+            curves.extend(end_curves.into_iter().rev().map(|(sp, (cp1, cp2), ep)| (ep, (cp2, cp1), sp)));
+            curves
+        }
+
+        _ => miter_clip_join(join_point, start_line, end_line, curvature, limit)
     }
 }
 
 ///
 /// The round join joins two edges using an arc
 ///
+/// The arc leaves the first edge in the direction it's travelling, so it always goes around the outside of the corner.
+///
 #[inline]
-fn round_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
+fn round_join<TCoord>(join_point: TCoord, start_line: (TCoord, TCoord), end_line: (TCoord, TCoord), curvature: (f64, f64), limit: f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>
 where
     TCoord: Coordinate + Coordinate2D,
 {
-    const VERY_CLOSE: f64 = 1e-5;
-
     // Must be the outer part of the corner
-    if !start_line.0.is_near_to(&end_line.0, VERY_CLOSE) && start_line.angle_to(&end_line) > f64::consts::PI {
-        // Get the normals at the start and end of the curve section
-        let start_tangent   = (start_line.0 - start_line.1).to_unit_vector();
-        let end_tangent     = (end_line.0 - end_line.1).to_unit_vector();
-        let start_normal    = TCoord::from_components(&[start_tangent.y(), -start_tangent.x()]);
-        let end_normal      = TCoord::from_components(&[end_tangent.y(), -end_tangent.x()]);
-
-        // Center of the circle is where the lines defined by the normals meet
+    if is_outer_corner(&start_line, &end_line) {
+        // Center of the circle is the point where the two edges join
         let center_point    = join_point;
+        let start_direction = start_line.0 - start_line.1;
 
-        // Radius of the circle is the distance between the center point and either of the two points
-        let radius = center_point.distance_to(&start_line.0);
+        let mut curves      = arc_curves(center_point, start_line.0, end_line.0, start_direction);
 
-        let angle_1 = f64::atan2(start_normal.x(), start_normal.y());
-        let angle_2 = f64::atan2(end_normal.x(), end_normal.y());
-
-        let circle  = Circle::new(center_point, radius);
-        let arc     = circle.arc(angle_1, angle_2);
-
-        let arc_curve = arc.to_bezier_curve::<Curve<_>>();
-        debug_assert!((center_point.distance_to(&end_line.0)-center_point.distance_to(&start_line.0)).abs() < 0.01, "Radius doesn't match: {} {}", radius, center_point.distance_to(&end_line.0));
+        // The edges can be at different distances from the join point if the width of the line is changing
+        let arc_end         = curves.last().map(|(_, _, ep)| *ep).unwrap_or(start_line.0);
+        if !arc_end.is_near_to(&end_line.0, 0.01) {
+            curves.push(line_to_bezier::<Curve<_>>(&(arc_end, end_line.0)).all_points());
+        }
 
-        vec![
-            arc_curve.all_points(),
-        ]
+        curves
     } else {
         // Bevel join on the inside part of the corner
-        bevel_join(join_point, start_line, end_line, limit)
+        bevel_join(join_point, start_line, end_line, curvature, limit)
     }
 }
 
 ///
 /// Generates the edges for a single curve
 ///
-fn stroke_edge<TCoord>(start_point: &mut Option<(TCoord, TCoord)>, points: &mut Vec<(TCoord, TCoord, TCoord)>, curve: &Curve<TCoord>, subdivision_options: &SubdivisionOffsetOptions, width: f64, join: &impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>) -> bool
+/// `width_for_t` is the distance to offset the edge from the curve at each t value.
+///
+/// `join_limit` is passed to the join function: for miter joins, this is the furthest the miter can extend from the corner. The
+/// join function is also passed the curvature of the offset edges on either side of the join.
+///
+pub (crate) fn stroke_edge<TCoord>(start_point: &mut Option<(TCoord, TCoord)>, points: &mut Vec<(TCoord, TCoord, TCoord)>, curve: &Curve<TCoord>, subdivision_options: &SubdivisionOffsetOptions, width_for_t: &impl Fn(f64) -> f64, join_limit: f64, join: &impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), (f64, f64), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>) -> bool
 where
     TCoord: Coordinate + Coordinate2D,
 {
     let mut added_points = false;
 
     // Offset this curve using the subdivision algorithm
-    if let Some(offset_curve) = offset_lms_subdivisions(curve, |_| width, |_| 0.0, &subdivision_options) {
+    if let Some(offset_curve) = offset_lms_subdivisions(curve, width_for_t, |_| 0.0, &subdivision_options) {
         let initial_point   = offset_curve[0].start_point();
         let initial_tangent = offset_curve[0].control_points().0;
 
         if let Some((start_point, start_tangent)) = start_point {
             // Add a join to the existing curve using the join style
             let (last_point, last_tangent) = points.last().map(|(_, cp2, ep)| (*ep, *cp2)).unwrap_or((*start_point, *start_tangent));
+            let last_curvature  = points.last().map(|(cp1, cp2, ep)| end_curvature(*cp1, *cp2, *ep)).unwrap_or(0.0);
+            let (cp1, cp2)      = offset_curve[0].control_points();
+            let next_curvature  = start_curvature(initial_point, cp1, cp2);
 
-            for (_, (cp1, cp2), ep) in join(curve.start_point(), (last_point, last_tangent), (initial_point, initial_tangent), width * 4.0) {
+            for (_, (cp1, cp2), ep) in join(curve.start_point(), (last_point, last_tangent), (initial_point, initial_tangent), (last_curvature, next_curvature), join_limit) {
                 points.push((cp1, cp2, ep));
             }
 
@@ -285,16 +730,251 @@ where
 ///
 /// Generates a thickened line along a path
 ///
-/// The width describes how wide to make the resulting line. 
+/// The width describes how wide to make the resulting line. If the options have a dash pattern, the result contains a separate
+/// stroke for each dash, each with its own start and end caps.
 ///
 pub fn stroke_path<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, width: f64, options: &StrokeOptions) -> Vec<TPathFactory>
 where
     TPathFactory:   BezierPathFactory<Point=TCoord>,
     TCoord:         Coordinate + Coordinate2D,
 {
-    // Half the width (we add and subtract this from the centerline)
-    let half_width  = width/2.0;
-    let join_fn     = options.join.join_function();
+    stroke_path_with_curve_width(path, |_, _| width, options)
+}
+
+///
+/// Generates a line along a path whose width varies along its length
+///
+/// `width_for_length` is called with a position along the path, where 0.0 is the start of the path and 1.0 is the end (measured by
+/// arc length), and should return the width of the line at that point. For example, `|pos| (1.0-pos) * 4.0` will generate a line
+/// that tapers to a point.
+///
+/// The position is always measured along the whole path, so dashed lines follow the same width profile as the solid line would.
+///
+pub fn stroke_path_with_width<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, width_for_length: impl Fn(f64) -> f64, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    stroke_path_with_curve_width(path, length_width_for_pos(path, &width_for_length, options.accuracy), options)
+}
+
+///
+/// Generates a line along a path whose width varies along its length
+///
+/// `width_for_pos` is called with the index of a curve in the path and a t value along that curve, and should return the width of
+/// the line at that point. The width should be the same at the end of one curve and the start of the next.
+///
+/// When the line is dashed, the curve indexes and t values are positions on the original path rather than on the dashes.
+///
+pub fn stroke_path_with_curve_width<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, width_for_pos: impl Fn(usize, f64) -> f64, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    if let Some(inside) = aligned_stroke_side(path, options) {
+        // Stroke at double the width, then clip against the region (the interior points are removed while clipping: removing them
+        // from the stroke of a closed path would fill in the hole in the middle)
+        let doubled         = |curve_idx, t| width_for_pos(curve_idx, t) * 2.0;
+        let stroke_options  = StrokeOptions { remove_interior_points: false, ..options.clone() };
+        let stroke          = stroke_dashes(path, &doubled, &stroke_options);
+
+        clip_aligned_stroke(path, stroke, inside, options)
+    } else {
+        stroke_dashes(path, &width_for_pos, options)
+    }
+}
+
+///
+/// Strokes each of the dashes in a path
+///
+/// `width_for_pos` is called with positions on the original path, and zero-length dashes are drawn as dots using the caps at either
+/// end of the line.
+///
+fn stroke_dashes<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, width_for_pos: &impl Fn(usize, f64) -> f64, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    let curves = path.to_curves::<Curve<TCoord>>();
+
+    path_dash_sections(path, &options.dash_array, options.dash_offset, options.accuracy)
+        .into_iter()
+        .flat_map(|dash| {
+            let is_zero_length = dash.is_zero_length();
+            let PathDash { start_point, points, original_curves } = dash;
+
+            // Map the positions on the dash back to positions on the original path
+            let dash_width = |curve_idx: usize, t: f64| {
+                let (original_idx, t1, t2) = original_curves[curve_idx];
+                width_for_pos(original_idx, t1 + (t2-t1)*t)
+            };
+
+            if is_zero_length {
+                // Dots follow the direction of the path where they are (or point along the x axis if the path has no direction)
+                let (curve_idx, t, _)   = original_curves[0];
+                let direction           = curve_direction(&curves[curve_idx], t);
+                let direction           = if direction.magnitude() > 0.0 { direction } else { TCoord::from_components(&[1.0, 0.0]) };
+
+                stroke_dot(start_point, direction, dash_width(0, 0.0), options)
+            } else {
+                stroke_path_section(&(start_point, points), &dash_width, options)
+            }
+        })
+        .collect()
+}
+
+///
+/// Strokes a path with no length, which is just the start and end caps
+///
+fn stroke_dot<TPathFactory, TCoord>(center_point: TCoord, direction: TCoord, width: f64, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    if options.start_cap == LineCap::Butt && options.end_cap == LineCap::Butt {
+        // Butt caps have no area
+        return vec![];
+    }
+
+    let end_cap_fn      = options.end_cap.cap_function();
+    let start_cap_fn    = options.start_cap.cap_function();
+
+    let left_normal     = TCoord::from_components(&[-direction.y(), direction.x()]) * (width / 2.0);
+    let left_point      = center_point + left_normal;
+    let right_point     = center_point - left_normal;
+
+    let points = end_cap_fn(center_point, (left_point, left_point - direction), (right_point, right_point - direction), (0.0, 0.0), 0.0).into_iter()
+        .chain(start_cap_fn(center_point, (right_point, right_point + direction), (left_point, left_point + direction), (0.0, 0.0), 0.0))
+        .map(|(_, (cp1, cp2), ep)| (cp1, cp2, ep))
+        .collect::<Vec<_>>();
+    let path = TPathFactory::from_points(left_point, points);
+
+    if options.remove_interior_points {
+        path_remove_interior_points(&vec![path], options.accuracy)
+    } else {
+        vec![path]
+    }
+}
+
+///
+/// If a stroke should be aligned to one side of a path, returns true if the stroke should be inside the path or false if it should
+/// be outside (or None if the stroke should be centered, which includes any path that's not closed)
+///
+fn aligned_stroke_side<TCoord>(path: &impl BezierPath<Point=TCoord>, options: &StrokeOptions) -> Option<bool>
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    let start_point = path.start_point();
+    let end_point   = path.points().last().map(|(_, _, end_point)| end_point)?;
+
+    if !start_point.is_near_to(&end_point, SMALL_DISTANCE) {
+        return None;
+    }
+
+    match options.alignment {
+        StrokeAlignment::Center     => None,
+        StrokeAlignment::Inside     => Some(true),
+        StrokeAlignment::Outside    => Some(false),
+    }
+}
+
+///
+/// Clips a stroke that was generated at double the width to one side of a closed path
+///
+fn clip_aligned_stroke<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, stroke: Vec<(TCoord, Vec<(TCoord, TCoord, TCoord)>)>, inside: bool, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    // Clip the stroke against the region enclosed by the path
+    let stroke = path_remove_interior_points::<_, (TCoord, Vec<(TCoord, TCoord, TCoord)>)>(&stroke, options.accuracy);
+    let region = vec![(path.start_point(), path.points().collect::<Vec<_>>())];
+
+    if inside {
+        path_intersect(&stroke, &region, options.accuracy)
+    } else {
+        path_sub(&stroke, &region, options.accuracy)
+    }
+}
+
+///
+/// Converts a width function that's based on the normalised arc length of a path to one based on the curve index and t value
+///
+fn length_width_for_pos<'a, TCoord>(path: &impl BezierPath<Point=TCoord>, width_for_length: &'a impl Fn(f64) -> f64, accuracy: f64) -> impl 'a + Fn(usize, f64) -> f64
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    // Number of samples to take along each curve when mapping t values to lengths
+    const LENGTH_SAMPLES: usize = 16;
+
+    // Measure the cumulative length at a set of sample points along each curve
+    let mut total_length    = 0.0;
+    let lengths             = path.to_curves::<Curve<TCoord>>().into_iter()
+        .map(|curve| {
+            let mut curve_lengths = vec![total_length];
+
+            for sample in 0..LENGTH_SAMPLES {
+                let t1 = (sample as f64) / (LENGTH_SAMPLES as f64);
+                let t2 = ((sample+1) as f64) / (LENGTH_SAMPLES as f64);
+
+                total_length += curve_length(&curve.section(t1, t2), accuracy);
+                curve_lengths.push(total_length);
+            }
+
+            curve_lengths
+        })
+        .collect::<Vec<_>>();
+
+    // Map the curve positions to a proportion of the total length by interpolating between the samples
+    move |curve_idx: usize, t: f64| {
+        if total_length <= 0.0 { return width_for_length(0.0); }
+
+        let curve_lengths   = &lengths[curve_idx];
+        let pos             = t.clamp(0.0, 1.0) * (LENGTH_SAMPLES as f64);
+        let sample          = (pos.floor() as usize).min(LENGTH_SAMPLES-1);
+        let ratio           = pos - (sample as f64);
+        let length          = curve_lengths[sample] * (1.0-ratio) + curve_lengths[sample+1] * ratio;
+
+        width_for_length(length / total_length)
+    }
+}
+
+///
+/// Joins the end of a loop of offset curves back to the point where it started
+///
+fn close_stroke_loop<TCoord>(corner: TCoord, (first_point, first_tangent): (TCoord, TCoord), points: &mut Vec<(TCoord, TCoord, TCoord)>, join_limit: f64, join: &impl Fn(TCoord, (TCoord, TCoord), (TCoord, TCoord), (f64, f64), f64) -> Vec<(TCoord, (TCoord, TCoord), TCoord)>)
+where
+    TCoord: Coordinate + Coordinate2D,
+{
+    if let (Some((first_cp1, first_cp2, _)), Some((last_cp1, last_cp2, last_point))) = (points.first().copied(), points.last().copied()) {
+        let last_curvature  = end_curvature(last_cp1, last_cp2, last_point);
+        let first_curvature = start_curvature(first_point, first_cp1, first_cp2);
+
+        for (_, (cp1, cp2), ep) in join(corner, (last_point, last_cp2), (first_point, first_tangent), (last_curvature, first_curvature), join_limit) {
+            points.push((cp1, cp2, ep));
+        }
+    }
+}
+
+///
+/// Generates a thickened line along a single path section (ignoring the dash pattern)
+///
+fn stroke_path_section<TPathFactory, TCoord>(path: &impl BezierPath<Point=TCoord>, width_for_pos: &impl Fn(usize, f64) -> f64, options: &StrokeOptions) -> Vec<TPathFactory>
+where
+    TPathFactory:   BezierPathFactory<Point=TCoord>,
+    TCoord:         Coordinate + Coordinate2D,
+{
+    // Number of samples to take along each curve when finding the widest point of the line
+    const WIDTH_SAMPLES: usize = 8;
+
+    let join_fn         = options.join.join_function();
+
+    // Closed paths have no caps
+    let start           = path.start_point();
+    let end             = path.points().last().map(|(_, _, end_point)| end_point).unwrap_or(start);
+    let is_closed       = start.is_near_to(&end, SMALL_DISTANCE) && path.points().next().is_some();
+    let start_cap_fn    = options.start_cap.cap_function();
+    let end_cap_fn      = options.end_cap.cap_function();
 
     // Create the list of points that make up the path
     let mut start_point = None;
@@ -303,33 +983,75 @@ where
     // Convert the path to curves
     let path_curves = path.to_curves::<Curve<TCoord>>();
 
-    // Create subdivision options, using the width of the curve as a guide
+    // Create subdivision options, using the widest part of the line as a guide
+    let max_width = (0..path_curves.len())
+        .flat_map(|curve_idx| (0..=WIDTH_SAMPLES).map(move |sample| (curve_idx, (sample as f64) / (WIDTH_SAMPLES as f64))))
+        .map(|(curve_idx, t)| width_for_pos(curve_idx, t))
+        .fold(0.0, f64::max);
+
+    // Lines with no width have no area (and can't be subdivided)
+    if max_width <= 0.0 {
+        return vec![];
+    }
+
     let subdivision_options = SubdivisionOffsetOptions::default()
         .with_min_distance(options.min_sample_distance)
         .with_max_error(options.accuracy)
-        .with_max_distance(width * 20.0);
+        .with_max_distance(max_width * 20.0);
 
-    // Draw forward
-    for curve in path_curves.iter() {
+    // Draw forward (we add and subtract half the width from the centerline)
+    for (curve_idx, curve) in path_curves.iter().enumerate() {
         // Offset this curve using the subdivision algorithm
-        stroke_edge(&mut start_point, &mut points, &curve, &subdivision_options, half_width, &join_fn);
+        let join_limit = width_for_pos(curve_idx, 0.0) / 2.0 * options.miter_limit;
+        stroke_edge(&mut start_point, &mut points, curve, &subdivision_options, &|t| width_for_pos(curve_idx, t) / 2.0, join_limit, &join_fn);
     }
 
-    // Draw backwards
-    let mut added_end_cap = false;
-    for curve in path_curves.iter().rev().map(|curve| curve.reverse()) {
-        if !added_end_cap {
-            added_end_cap = stroke_edge(&mut start_point, &mut points, &curve, &subdivision_options, half_width, &join_fn);
-        } else {
-            stroke_edge(&mut start_point, &mut points, &curve, &subdivision_options, half_width, &join_fn);
+    if is_closed {
+        // Closed paths are drawn as a loop on each side of the path, with a join where the path starts and ends
+        let mut back_start  = None;
+        let mut back_points = vec![];
+
+        for (curve_idx, curve) in path_curves.iter().enumerate().rev().map(|(curve_idx, curve)| (curve_idx, curve.reverse())) {
+            let join_limit      = width_for_pos(curve_idx, 1.0) / 2.0 * options.miter_limit;
+            let width_for_t     = |t| width_for_pos(curve_idx, 1.0-t) / 2.0;
+
+            stroke_edge(&mut back_start, &mut back_points, &curve, &subdivision_options, &width_for_t, join_limit, &join_fn);
         }
-    }
 
-    // Add start cap
-    // TODO: support other cap types
-    if let (Some(start_point), Some(end_point)) = (start_point, points.last().map(|(_, _, p)| p).copied()) {
-        let (_, (cp1, cp2), ep) = line_to_bezier::<Curve<_>>(&(end_point, start_point.0)).all_points();
-        points.push((cp1, cp2, ep));
+        if let (Some(forward_start), Some(back_start)) = (start_point, back_start) {
+            let join_limit = width_for_pos(0, 0.0) / 2.0 * options.miter_limit;
+
+            close_stroke_loop(start, forward_start, &mut points, join_limit, &join_fn);
+            close_stroke_loop(start, back_start, &mut back_points, join_limit, &join_fn);
+
+            // Connect the two loops with a line across the path (and back again once the second loop is finished)
+            let line_to = |from: TCoord, to: TCoord| { let (_, (cp1, cp2), ep) = line_to_bezier::<Curve<_>>(&(from, to)).all_points(); (cp1, cp2, ep) };
+
+            points.push(line_to(forward_start.0, back_start.0));
+            points.extend(back_points);
+            points.push(line_to(back_start.0, forward_start.0));
+        }
+    } else {
+        // Draw backwards
+        let mut added_end_cap = false;
+        for (curve_idx, curve) in path_curves.iter().enumerate().rev().map(|(curve_idx, curve)| (curve_idx, curve.reverse())) {
+            let join_limit      = width_for_pos(curve_idx, 1.0) / 2.0 * options.miter_limit;
+            let width_for_t     = |t| width_for_pos(curve_idx, 1.0-t) / 2.0;
+
+            if !added_end_cap {
+                // The end of the path is where the line reverses, which is drawn using the end cap rather than a join
+                added_end_cap = stroke_edge(&mut start_point, &mut points, &curve, &subdivision_options, &width_for_t, join_limit, &end_cap_fn);
+            } else {
+                stroke_edge(&mut start_point, &mut points, &curve, &subdivision_options, &width_for_t, join_limit, &join_fn);
+            }
+        }
+
+        // Add start cap
+        if let (Some(start_point), Some((_, end_tangent, end_point))) = (start_point, points.last().copied()) {
+            for (_, (cp1, cp2), ep) in start_cap_fn(start, (end_point, end_tangent), start_point, (0.0, 0.0), 0.0) {
+                points.push((cp1, cp2, ep));
+            }
+        }
     }
 
     // Result is the path if we generated at least 2 points
@@ -360,7 +1082,7 @@ mod test {
 
     #[test]
     fn bevel_join_90_degrees() {
-        let corner = bevel_join(Coord2(2.5, 2.5), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(3.0, 3.0), Coord2(3.0, 2.0)), 20.0);
+        let corner = bevel_join(Coord2(2.5, 2.5), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(3.0, 3.0), Coord2(3.0, 2.0)), (0.0, 0.0), 20.0);
         println!("{:?}", corner);
 
         let (sp, (_cp1, _cp2), ep) = corner.last().unwrap();
@@ -370,7 +1092,7 @@ mod test {
 
     #[test]
     fn rounded_join_90_degrees() {
-        let corner = round_join(Coord2(2.5, 2.0), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(3.0, 2.5), Coord2(3.0, 2.5)), 20.0);
+        let corner = round_join(Coord2(2.5, 2.0), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(3.0, 2.5), Coord2(3.0, 2.5)), (0.0, 0.0), 20.0);
         println!("{:?}", corner);
 
         let (sp, (_cp1, _cp2), ep) = corner.last().unwrap();
@@ -378,13 +1100,77 @@ mod test {
         debug_assert!(sp.is_near_to(&Coord2(2.0, 2.0), 0.01), "Start point is wrong (found {:?})", sp);
     }
 
+    #[test]
+    fn rounded_join_follows_start_tangent() {
+        // Offset edges around the corner at 1,1 of a path moving left then up
+        let corner = round_join(Coord2(1.0, 1.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), (Coord2(0.0, 1.0), Coord2(0.0, 2.0)), (0.0, 0.0), 20.0);
+
+        let curve = Curve::from_points(corner[0].0, corner[0].1, corner[0].2);
+        let mid   = curve.point_at_pos(0.5);
+        debug_assert!(mid.is_near_to(&Coord2(1.0 - f64::consts::FRAC_1_SQRT_2, 1.0 - f64::consts::FRAC_1_SQRT_2), 0.01), "Arc goes the wrong way (midpoint {:?})", mid);
+    }
+
     #[test]
     fn rounded_join_180_degrees() {
-        let corner = round_join(Coord2(2.0, 2.5), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(2.0, 3.0), Coord2(1.0, 3.0)), 20.0);
+        let corner = round_join(Coord2(2.0, 2.5), (Coord2(2.0, 2.0), Coord2(1.0, 2.0)), (Coord2(2.0, 3.0), Coord2(1.0, 3.0)), (0.0, 0.0), 20.0);
         println!("{:?}", corner);
 
-        let (sp, (_cp1, _cp2), ep) = corner.last().unwrap();
+        let (sp, _, _)      = corner[0];
+        let (_, _, ep)      = corner.last().unwrap();
         debug_assert!(ep.is_near_to(&Coord2(2.0, 3.0), 0.01), "End point is wrong (found {:?})", ep);
         debug_assert!(sp.is_near_to(&Coord2(2.0, 2.0), 0.01), "Start point is wrong (found {:?})", sp);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn miter_join_within_limit() {
+        // Left-hand offset of a path moving right then turning to move down around the point 0,0
+        let corner = miter_join(Coord2(0.0, 0.0), (Coord2(0.0, 1.0), Coord2(-1.0, 1.0)), (Coord2(1.0, 0.0), Coord2(1.0, -1.0)), (0.0, 0.0), 4.0);
+
+        assert!(corner.len() == 2);
+        assert!(corner[0].2.is_near_to(&Coord2(1.0, 1.0), 0.01), "Miter point is wrong (found {:?})", corner[0].2);
+        assert!(corner[1].2.is_near_to(&Coord2(1.0, 0.0), 0.01));
+    }
+
+    #[test]
+    fn miter_join_beyond_limit_is_bevel() {
+        let corner = miter_join(Coord2(0.0, 0.0), (Coord2(0.0, 1.0), Coord2(-1.0, 1.0)), (Coord2(1.0, 0.0), Coord2(1.0, -1.0)), (0.0, 0.0), 1.2);
+
+        assert!(corner.len() == 1);
+        assert!(corner[0].0.is_near_to(&Coord2(0.0, 1.0), 0.01));
+        assert!(corner[0].2.is_near_to(&Coord2(1.0, 0.0), 0.01));
+    }
+
+    #[test]
+    fn miter_clip_join_beyond_limit() {
+        let corner  = miter_clip_join(Coord2(0.0, 0.0), (Coord2(0.0, 1.0), Coord2(-1.0, 1.0)), (Coord2(1.0, 0.0), Coord2(1.0, -1.0)), (0.0, 0.0), 1.2);
+        let clip_x  = (1.2 - f64::consts::FRAC_1_SQRT_2) / f64::consts::FRAC_1_SQRT_2;
+
+        assert!(corner.len() == 3);
+        assert!(corner[0].2.is_near_to(&Coord2(clip_x, 1.0), 0.01), "Clip point is wrong (found {:?})", corner[0].2);
+        assert!(corner[1].2.is_near_to(&Coord2(1.0, clip_x), 0.01), "Clip point is wrong (found {:?})", corner[1].2);
+        assert!(corner[2].2.is_near_to(&Coord2(1.0, 0.0), 0.01));
+    }
+
+    #[test]
+    fn arcs_join_straight_edges_is_miter() {
+        let corner = arcs_join(Coord2(0.0, 0.0), (Coord2(0.0, 1.0), Coord2(-1.0, 1.0)), (Coord2(1.0, 0.0), Coord2(1.0, -1.0)), (0.0, 0.0), 4.0);
+
+        assert!(corner.len() == 2);
+        assert!(corner[0].2.is_near_to(&Coord2(1.0, 1.0), 0.01));
+    }
+
+    #[test]
+    fn arcs_join_follows_curvature() {
+        // The second edge is curving clockwise around a circle with radius 2 centered at -1,0, which meets the first edge at sqrt(3)-1, 1
+        let corner      = arcs_join(Coord2(0.0, 0.0), (Coord2(0.0, 1.0), Coord2(-1.0, 1.0)), (Coord2(1.0, 0.0), Coord2(1.0, -1.0)), (0.0, -0.5), 4.0);
+        let meeting     = Coord2(3.0f64.sqrt() - 1.0, 1.0);
+
+        assert!(corner[0].2.is_near_to(&meeting, 0.01), "Meeting point is wrong (found {:?})", corner[0].2);
+        assert!(corner.last().unwrap().2.is_near_to(&Coord2(1.0, 0.0), 0.01));
+
+        for (sp, (cp1, cp2), ep) in corner.iter().skip(1) {
+            let mid_point = Curve::from_points(*sp, (*cp1, *cp2), *ep).point_at_pos(0.5);
+            assert!((mid_point.distance_to(&Coord2(-1.0, 0.0)) - 2.0).abs() < 0.01, "Not following the arc (found {:?})", mid_point);
+        }
+    }
+}
@@ -0,0 +1,639 @@
+use super::path::*;
+use crate::geo::*;
+use crate::arc::*;
+use crate::bezier::*;
+
+use std::f64;
+use std::fmt;
+use std::error::Error;
+
+/// The characters that are used for commands in SVG path data
+const SVG_COMMANDS: &[u8] = b"MmLlHhVvCcSsQqTtAaZz";
+
+///
+/// Errors that can occur while parsing SVG path data
+///
+/// Positions are byte offsets into the string that was being parsed.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SvgPathError {
+    /// A character that is not part of the SVG path syntax was found
+    UnexpectedCharacter { position: usize, character: char },
+
+    /// A command was not followed by enough parameters
+    MissingParameters { position: usize, command: char },
+
+    /// A number was badly formed
+    InvalidNumber { position: usize },
+
+    /// An arc flag was something other than '0' or '1'
+    InvalidFlag { position: usize },
+
+    /// The path data did not start with a move command
+    MissingMoveTo { position: usize },
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgPathError::UnexpectedCharacter { position, character }   => write!(f, "unexpected character '{}' at position {}", character, position),
+            SvgPathError::MissingParameters { position, command }       => write!(f, "missing parameters for '{}' command at position {}", command, position),
+            SvgPathError::InvalidNumber { position }                    => write!(f, "invalid number at position {}", position),
+            SvgPathError::InvalidFlag { position }                      => write!(f, "invalid arc flag at position {} (expected '0' or '1')", position),
+            SvgPathError::MissingMoveTo { position }                    => write!(f, "path data must start with a move command (at position {})", position),
+        }
+    }
+}
+
+impl Error for SvgPathError { }
+
+///
+/// Reads the tokens making up some SVG path data
+///
+struct SvgPathTokenizer<'a> {
+    /// The data being tokenized
+    data: &'a str,
+
+    /// The current position in the data
+    pos: usize,
+}
+
+impl<'a> SvgPathTokenizer<'a> {
+    ///
+    /// Skips any whitespace or commas at the current position
+    ///
+    fn skip_separators(&mut self) {
+        let bytes = self.data.as_bytes();
+
+        while self.pos < bytes.len() && (bytes[self.pos].is_ascii_whitespace() || bytes[self.pos] == b',') {
+            self.pos += 1;
+        }
+    }
+
+    ///
+    /// Returns the byte at the specified offset from the current position
+    ///
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.data.as_bytes().get(self.pos + offset).copied()
+    }
+
+    ///
+    /// Returns an 'unexpected character' error for the current position
+    ///
+    fn unexpected_character(&self) -> SvgPathError {
+        SvgPathError::UnexpectedCharacter {
+            position:   self.pos,
+            character:  self.data[self.pos..].chars().next().unwrap_or(' ')
+        }
+    }
+
+    ///
+    /// Reads the next command character, returning None if the end of the data has been reached
+    ///
+    fn command(&mut self) -> Result<Option<(usize, char)>, SvgPathError> {
+        self.skip_separators();
+
+        match self.peek_at(0) {
+            None    => Ok(None),
+            Some(c) => {
+                if SVG_COMMANDS.contains(&c) {
+                    let position = self.pos;
+                    self.pos += 1;
+
+                    Ok(Some((position, c as char)))
+                } else {
+                    Err(self.unexpected_character())
+                }
+            }
+        }
+    }
+
+    ///
+    /// True if the next token is a number
+    ///
+    fn has_number(&mut self) -> bool {
+        self.skip_separators();
+
+        matches!(self.peek_at(0), Some(c) if c.is_ascii_digit() || c == b'.' || c == b'-' || c == b'+')
+    }
+
+    ///
+    /// Returns the error to generate when a parameter for a command is missing
+    ///
+    fn missing_parameter(&self, (position, command): (usize, char)) -> SvgPathError {
+        match self.peek_at(0) {
+            None                                    => SvgPathError::MissingParameters { position, command },
+            Some(c) if SVG_COMMANDS.contains(&c)    => SvgPathError::MissingParameters { position, command },
+            Some(_)                                 => self.unexpected_character(),
+        }
+    }
+
+    ///
+    /// Reads a number parameter for a command
+    ///
+    fn number(&mut self, command: (usize, char)) -> Result<f64, SvgPathError> {
+        if !self.has_number() {
+            return Err(self.missing_parameter(command));
+        }
+
+        let start = self.pos;
+
+        // Sign
+        if let Some(b'-') | Some(b'+') = self.peek_at(0) {
+            self.pos += 1;
+        }
+
+        // Integer and fractional parts
+        let mut num_digits = 0;
+        while matches!(self.peek_at(0), Some(c) if c.is_ascii_digit()) {
+            self.pos    += 1;
+            num_digits  += 1;
+        }
+
+        if let Some(b'.') = self.peek_at(0) {
+            self.pos += 1;
+
+            while matches!(self.peek_at(0), Some(c) if c.is_ascii_digit()) {
+                self.pos    += 1;
+                num_digits  += 1;
+            }
+        }
+
+        if num_digits == 0 {
+            return Err(SvgPathError::InvalidNumber { position: start });
+        }
+
+        // Exponent (only if it's followed by some digits, so '1e' is treated as a number followed by an unexpected character)
+        if let Some(b'e') | Some(b'E') = self.peek_at(0) {
+            let exponent_digit = match self.peek_at(1) {
+                Some(b'-') | Some(b'+') => self.peek_at(2),
+                other                   => other,
+            };
+
+            if matches!(exponent_digit, Some(c) if c.is_ascii_digit()) {
+                self.pos += 1;
+
+                if let Some(b'-') | Some(b'+') = self.peek_at(0) {
+                    self.pos += 1;
+                }
+
+                while matches!(self.peek_at(0), Some(c) if c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+            }
+        }
+
+        self.data[start..self.pos].parse::<f64>()
+            .map_err(|_| SvgPathError::InvalidNumber { position: start })
+    }
+
+    ///
+    /// Reads an x, y coordinate pair for a command
+    ///
+    fn coordinate(&mut self, command: (usize, char)) -> Result<(f64, f64), SvgPathError> {
+        let x = self.number(command)?;
+        let y = self.number(command)?;
+
+        Ok((x, y))
+    }
+
+    ///
+    /// Reads an arc flag, which is a single '0' or '1' that does not need to be separated from what follows it
+    ///
+    fn flag(&mut self, command: (usize, char)) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+
+        match self.peek_at(0) {
+            Some(b'0')                              => { self.pos += 1; Ok(false) },
+            Some(b'1')                              => { self.pos += 1; Ok(true) },
+            None                                    => Err(self.missing_parameter(command)),
+            Some(c) if SVG_COMMANDS.contains(&c)    => Err(self.missing_parameter(command)),
+            Some(_)                                 => Err(SvgPathError::InvalidFlag { position: self.pos }),
+        }
+    }
+}
+
+///
+/// Tracks the subpaths generated while parsing SVG path data
+///
+struct SvgSubpaths {
+    /// The subpaths that have been completed so far
+    paths: Vec<((f64, f64), Vec<((f64, f64), (f64, f64), (f64, f64))>)>,
+
+    /// The start point of the current subpath
+    start_point: (f64, f64),
+
+    /// The current point
+    current_point: (f64, f64),
+
+    /// The curves in the current subpath
+    points: Vec<((f64, f64), (f64, f64), (f64, f64))>,
+
+    /// The second control point of the last curve, if the last command was a cubic curve (used for 'S' commands)
+    last_cubic: Option<(f64, f64)>,
+
+    /// The control point of the last curve, if the last command was a quadratic curve (used for 'T' commands)
+    last_quad: Option<(f64, f64)>,
+}
+
+impl SvgSubpaths {
+    ///
+    /// Finishes the current subpath, if it has any curves in it
+    ///
+    fn finish_subpath(&mut self) {
+        if !self.points.is_empty() {
+            let points = std::mem::take(&mut self.points);
+            self.paths.push((self.start_point, points));
+        }
+    }
+
+    ///
+    /// Starts a new subpath
+    ///
+    fn move_to(&mut self, point: (f64, f64)) {
+        self.finish_subpath();
+
+        self.start_point    = point;
+        self.current_point  = point;
+    }
+
+    ///
+    /// Adds a cubic curve to the current subpath
+    ///
+    fn curve_to(&mut self, cp1: (f64, f64), cp2: (f64, f64), end_point: (f64, f64)) {
+        self.points.push((cp1, cp2, end_point));
+        self.current_point = end_point;
+    }
+
+    ///
+    /// Adds a line to the current subpath
+    ///
+    fn line_to(&mut self, end_point: (f64, f64)) {
+        let (x1, y1) = self.current_point;
+        let (x2, y2) = end_point;
+
+        let cp1 = (x1 + (x2-x1)/3.0, y1 + (y2-y1)/3.0);
+        let cp2 = (x1 + (x2-x1)*2.0/3.0, y1 + (y2-y1)*2.0/3.0);
+
+        self.curve_to(cp1, cp2, end_point);
+    }
+
+    ///
+    /// Adds a quadratic curve to the current subpath
+    ///
+    fn quad_to(&mut self, cp: (f64, f64), end_point: (f64, f64)) {
+        let (x1, y1) = self.current_point;
+        let (x2, y2) = end_point;
+
+        let cp1 = (x1 + (cp.0-x1)*2.0/3.0, y1 + (cp.1-y1)*2.0/3.0);
+        let cp2 = (x2 + (cp.0-x2)*2.0/3.0, y2 + (cp.1-y2)*2.0/3.0);
+
+        self.curve_to(cp1, cp2, end_point);
+    }
+
+    ///
+    /// Closes the current subpath
+    ///
+    fn close(&mut self) {
+        if self.current_point != self.start_point {
+            self.line_to(self.start_point);
+        }
+
+        self.finish_subpath();
+        self.current_point = self.start_point;
+    }
+}
+
+///
+/// Converts an SVG elliptical arc (in endpoint form) into a set of cubic curves
+///
+pub (crate) fn svg_arc_curves(start: (f64, f64), radius: (f64, f64), x_axis_rotation: f64, large_arc: bool, sweep: bool, end: (f64, f64)) -> Vec<((f64, f64), (f64, f64), (f64, f64))> {
+    let (x1, y1) = start;
+    let (x2, y2) = end;
+
+    // Arcs that start and end at the same point are omitted, and arcs with a 0 radius are straight lines
+    if x1 == x2 && y1 == y2 {
+        return vec![];
+    }
+
+    let arc = if let Some(arc) = EllipticalArc::from_svg_endpoints(Coord2(x1, y1), radius, x_axis_rotation, large_arc, sweep, Coord2(x2, y2)) {
+        arc
+    } else {
+        return vec![((x1 + (x2-x1)/3.0, y1 + (y2-y1)/3.0), (x1 + (x2-x1)*2.0/3.0, y1 + (y2-y1)*2.0/3.0), end)];
+    };
+
+    // Use a curve for every 90 degrees of the arc, making sure the last curve ends exactly at the end point
+    let mut curves = arc.quarter_bezier_curves::<Curve<_>>().into_iter()
+        .map(|curve| {
+            let (cp1, cp2)  = curve.control_points();
+            let end_point   = curve.end_point();
+
+            ((cp1.x(), cp1.y()), (cp2.x(), cp2.y()), (end_point.x(), end_point.y()))
+        })
+        .collect::<Vec<_>>();
+
+    if let Some((_, _, end_point)) = curves.last_mut() {
+        *end_point = end;
+    }
+
+    curves
+}
+
+///
+/// Parses SVG path data (the contents of the `d` attribute of a `<path>` element) into a set of paths
+///
+/// All of the SVG path commands are supported, in both their absolute and relative forms. Lines and quadratic curves
+/// are converted to cubic curves and elliptical arcs are approximated using a cubic curve for every 90 degrees. Each
+/// subpath becomes a separate path in the result: subpaths closed with a 'Z' command will end at their start point.
+/// Subpaths that contain no drawing commands are left out.
+///
+pub fn path_from_svg_data<P>(data: &str) -> Result<Vec<P>, SvgPathError>
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let mut tokens      = SvgPathTokenizer { data, pos: 0 };
+    let mut subpaths    = SvgSubpaths {
+        paths:          vec![],
+        start_point:    (0.0, 0.0),
+        current_point:  (0.0, 0.0),
+        points:         vec![],
+        last_cubic:     None,
+        last_quad:      None,
+    };
+    let mut started     = false;
+
+    while let Some(command) = tokens.command()? {
+        let (position, command_char) = command;
+        let relative    = command_char.is_ascii_lowercase();
+        let mut first   = true;
+
+        if !started && !command_char.eq_ignore_ascii_case(&'M') {
+            return Err(SvgPathError::MissingMoveTo { position });
+        }
+        started = true;
+
+        // Commands can be repeated by supplying more parameters
+        loop {
+            let (cx, cy)        = subpaths.current_point;
+            let offset          = |(x, y): (f64, f64)| if relative { (x+cx, y+cy) } else { (x, y) };
+            let mut last_cubic  = None;
+            let mut last_quad   = None;
+
+            match command_char.to_ascii_uppercase() {
+                'M' => {
+                    let point = offset(tokens.coordinate(command)?);
+
+                    // Extra coordinates after a move are treated as lines
+                    if first { subpaths.move_to(point); } else { subpaths.line_to(point); }
+                }
+
+                'L' => {
+                    let point = offset(tokens.coordinate(command)?);
+                    subpaths.line_to(point);
+                }
+
+                'H' => {
+                    let x = tokens.number(command)?;
+                    let x = if relative { x + cx } else { x };
+                    subpaths.line_to((x, cy));
+                }
+
+                'V' => {
+                    let y = tokens.number(command)?;
+                    let y = if relative { y + cy } else { y };
+                    subpaths.line_to((cx, y));
+                }
+
+                'C' => {
+                    let cp1         = offset(tokens.coordinate(command)?);
+                    let cp2         = offset(tokens.coordinate(command)?);
+                    let end_point   = offset(tokens.coordinate(command)?);
+
+                    subpaths.curve_to(cp1, cp2, end_point);
+                    last_cubic = Some(cp2);
+                }
+
+                'S' => {
+                    let cp2         = offset(tokens.coordinate(command)?);
+                    let end_point   = offset(tokens.coordinate(command)?);
+                    let cp1         = subpaths.last_cubic.map(|(x, y)| (2.0*cx - x, 2.0*cy - y)).unwrap_or((cx, cy));
+
+                    subpaths.curve_to(cp1, cp2, end_point);
+                    last_cubic = Some(cp2);
+                }
+
+                'Q' => {
+                    let cp          = offset(tokens.coordinate(command)?);
+                    let end_point   = offset(tokens.coordinate(command)?);
+
+                    subpaths.quad_to(cp, end_point);
+                    last_quad = Some(cp);
+                }
+
+                'T' => {
+                    let end_point   = offset(tokens.coordinate(command)?);
+                    let cp          = subpaths.last_quad.map(|(x, y)| (2.0*cx - x, 2.0*cy - y)).unwrap_or((cx, cy));
+
+                    subpaths.quad_to(cp, end_point);
+                    last_quad = Some(cp);
+                }
+
+                'A' => {
+                    let radius          = tokens.coordinate(command)?;
+                    let x_axis_rotation = tokens.number(command)?;
+                    let large_arc       = tokens.flag(command)?;
+                    let sweep           = tokens.flag(command)?;
+                    let end_point       = offset(tokens.coordinate(command)?);
+
+                    for (cp1, cp2, end_point) in svg_arc_curves((cx, cy), radius, x_axis_rotation, large_arc, sweep, end_point) {
+                        subpaths.curve_to(cp1, cp2, end_point);
+                    }
+                }
+
+                'Z' => {
+                    subpaths.close();
+                }
+
+                _ => unreachable!()
+            }
+
+            subpaths.last_cubic = last_cubic;
+            subpaths.last_quad  = last_quad;
+            first               = false;
+
+            if command_char.eq_ignore_ascii_case(&'Z') || !tokens.has_number() {
+                break;
+            }
+        }
+    }
+
+    subpaths.finish_subpath();
+
+    // Convert to the final path type
+    let to_point = |(x, y): (f64, f64)| P::Point::from_components(&[x, y]);
+
+    Ok(subpaths.paths.into_iter()
+        .map(|(start_point, points)| P::from_points(to_point(start_point), points.into_iter().map(|(cp1, cp2, end_point)| (to_point(cp1), to_point(cp2), to_point(end_point)))))
+        .collect())
+}
+
+///
+/// Writes out SVG path data in a compact form
+///
+struct SvgDataWriter {
+    /// The data written so far
+    data: String,
+
+    /// The number of decimal places to write
+    precision: usize,
+
+    /// The last command that was written
+    last_command: Option<char>,
+
+    /// True if the last number written had a decimal point in it
+    last_had_point: bool,
+}
+
+impl SvgDataWriter {
+    ///
+    /// Rounds a value to the precision of this writer
+    ///
+    fn round(&self, value: f64) -> f64 {
+        let scale = 10.0f64.powi(self.precision as i32);
+        (value * scale).round() / scale
+    }
+
+    ///
+    /// Writes a command (commands that repeat the previous one are left out)
+    ///
+    fn command(&mut self, command: char) {
+        if self.last_command != Some(command) || command == 'M' || command == 'Z' {
+            self.data.push(command);
+            self.last_command = Some(command);
+        }
+    }
+
+    ///
+    /// Writes a number, adding a separator only where it's needed
+    ///
+    fn number(&mut self, value: f64) {
+        let mut number = format!("{:.*}", self.precision, value);
+
+        if number.contains('.') {
+            number = number.trim_end_matches('0').trim_end_matches('.').to_string();
+        }
+        if number == "-0" {
+            number = "0".to_string();
+        }
+        if number.starts_with("0.") {
+            number.remove(0);
+        } else if number.starts_with("-0.") {
+            number.remove(1);
+        }
+
+        let needs_separator = match self.data.chars().last() {
+            Some(c) if c.is_ascii_digit()   => !number.starts_with('-') && (!number.starts_with('.') || !self.last_had_point),
+            _                               => false,
+        };
+
+        if needs_separator {
+            self.data.push(' ');
+        }
+
+        self.last_had_point = number.contains('.');
+        self.data.push_str(&number);
+    }
+
+    ///
+    /// Writes a coordinate
+    ///
+    fn coordinate(&mut self, (x, y): (f64, f64)) {
+        self.number(x);
+        self.number(y);
+    }
+}
+
+///
+/// True if a curve is a straight line to within a particular tolerance
+///
+fn curve_is_line(start: (f64, f64), (cp1, cp2, end): ((f64, f64), (f64, f64), (f64, f64)), tolerance: f64) -> bool {
+    let (dx, dy)    = (end.0-start.0, end.1-start.1);
+    let length_sq   = dx*dx + dy*dy;
+
+    let distance_to_line = |(x, y): (f64, f64)| {
+        let t = if length_sq > 0.0 { (((x-start.0)*dx + (y-start.1)*dy) / length_sq).clamp(0.0, 1.0) } else { 0.0 };
+        let (px, py) = (start.0 + dx*t, start.1 + dy*t);
+
+        ((x-px)*(x-px) + (y-py)*(y-py)).sqrt()
+    };
+
+    distance_to_line(cp1) <= tolerance && distance_to_line(cp2) <= tolerance
+}
+
+///
+/// Writes a set of paths out as SVG path data (suitable for the `d` attribute of a `<path>` element)
+///
+/// Coordinates are written with at most `precision` decimal places, and the data is made as compact as possible:
+/// curves that are straight lines are written as line commands, repeated commands and unnecessary separators are
+/// left out and paths that end at their start point are closed with a 'Z' command.
+///
+pub fn path_to_svg_data<P>(paths: &[P], precision: usize) -> String
+where
+    P:          BezierPath,
+    P::Point:   Coordinate2D,
+{
+    let mut writer  = SvgDataWriter {
+        data:           String::new(),
+        precision,
+        last_command:   None,
+        last_had_point: false,
+    };
+    let tolerance   = 0.5 * 10.0f64.powi(-(precision as i32));
+
+    for path in paths.iter() {
+        let round_point = |point: P::Point| (writer.round(point.x()), writer.round(point.y()));
+        let start_point = round_point(path.start_point());
+        let points      = path.points()
+            .map(|(cp1, cp2, end_point)| (round_point(cp1), round_point(cp2), round_point(end_point)))
+            .collect::<Vec<_>>();
+
+        writer.command('M');
+        writer.coordinate(start_point);
+
+        let is_closed       = points.last().map(|(_, _, end_point)| *end_point == start_point).unwrap_or(false);
+        let mut last_point  = start_point;
+
+        for (idx, curve) in points.iter().enumerate() {
+            let (cp1, cp2, end_point) = *curve;
+
+            if curve_is_line(last_point, *curve, tolerance) {
+                if is_closed && idx == points.len()-1 {
+                    // The 'Z' command will draw the final line
+                } else if end_point.1 == last_point.1 {
+                    writer.command('H');
+                    writer.number(end_point.0);
+                } else if end_point.0 == last_point.0 {
+                    writer.command('V');
+                    writer.number(end_point.1);
+                } else {
+                    writer.command('L');
+                    writer.coordinate(end_point);
+                }
+            } else {
+                writer.command('C');
+                writer.coordinate(cp1);
+                writer.coordinate(cp2);
+                writer.coordinate(end_point);
+            }
+
+            last_point = end_point;
+        }
+
+        if is_closed {
+            writer.command('Z');
+        }
+    }
+
+    writer.data
+}
@@ -18,12 +18,15 @@ pub struct PathContour {
 
     /// The curves in the path (divided into x and y portions)
     curves: Vec<(Curve<f64>, Curve<f64>, Bounds<Coord2>)>,
+
+    /// The fill rule used to determine which points are inside the path
+    fill_rule: FillRule,
 }
 
 impl PathContour {
     ///
     /// Creates a new path contour, which will produce a scan-converted contour for the specified path. The path will
-    /// be processed with an even-odd winding rule. 
+    /// be processed with an even-odd winding rule (call `with_fill_rule()` to change this). 
     ///
     pub fn from_path<TPath>(path: Vec<TPath>, size: ContourSize) -> Self
     where
@@ -47,9 +50,19 @@ impl PathContour {
 
         PathContour { 
             curves, size,
+            fill_rule: FillRule::EvenOdd,
         }
     }
 
+    ///
+    /// Returns a copy of this contour that uses a different fill rule to determine which points are inside the path
+    ///
+    pub fn with_fill_rule(self, fill_rule: FillRule) -> Self {
+        let mut new_contour = self;
+        new_contour.fill_rule = fill_rule;
+        new_contour
+    }
+
     ///
     /// Creates a contour that has the specified path at the center
     ///
@@ -94,6 +107,20 @@ impl PathContour {
     }
 }
 
+///
+/// Returns the direction a curve is crossing a ray in, given the tangent of the curve perpendicular to the ray
+///
+#[inline]
+fn crossing_direction(tangent: f64) -> i32 {
+    if tangent > 0.0 {
+        1
+    } else if tangent < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
 /// Intermediate structure used to represent an intercept from intercepts_on_line
 #[derive(Debug)]
 struct ContourIntercept {
@@ -149,6 +176,30 @@ impl PathContour {
         prev_tangent.signum() == next_tangent.signum() || prev_tangent == 0.0 || next_tangent == 0.0
     }
 
+    ///
+    /// Converts a sorted list of intercepts and the directions the path is crossing the ray in into ranges using the fill rule
+    ///
+    /// The direction is +1 where the path is increasing the winding number and -1 where it's decreasing the winding number
+    /// (or 0 if the path is tangent to the ray)
+    ///
+    fn ranges_for_fill_rule(&self, intercepts: impl IntoIterator<Item=(f64, i32)>) -> SmallVec<[Range<f64>; 4]> {
+        let mut ranges          = smallvec![];
+        let mut winding_number  = 0;
+        let mut range_start     = None;
+
+        for (pos, direction) in intercepts {
+            winding_number += direction;
+
+            match (range_start, self.fill_rule.is_inside(winding_number)) {
+                (None, true)            => { range_start = Some(pos); }
+                (Some(start), false)    => { ranges.push(start..pos); range_start = None; }
+                _                       => { }
+            }
+        }
+
+        ranges
+    }
+
     ///
     /// Removes any places where a ray has intercepted the path twice
     ///
@@ -242,6 +293,18 @@ impl SampledContour for PathContour {
 
             debug_assert!(intercepts.len() <= 1 || intercepts.len()%2 == 0, "Found an uneven number of intercepts ({:?}, y={})", intercepts, y);
 
+            if self.fill_rule != FillRule::EvenOdd {
+                // Path moving downwards increases the winding number (ie, the path is anticlockwise around the ray)
+                return self.ranges_for_fill_rule(intercepts.into_iter()
+                    .map(|intercept| {
+                        let (w1, (w2, w3), w4)  = self.curves[intercept.curve_idx].1.all_points();
+                        let (d1, d2, d3)        = derivative4(w1, w2, w3, w4);
+                        let tangent             = de_casteljau3(intercept.t, d1, d2, d3);
+
+                        (intercept.x_pos, -crossing_direction(tangent))
+                    }));
+            }
+
             // Each tuple represents a range that is within the shape
             return intercepts.into_iter()
                 .tuples()
@@ -267,16 +330,26 @@ impl ColumnSampledContour for PathContour {
                 let curve_intercepts    = solve_basis_for_t(w1, w2, w3, w4, x);
 
                 // Add the intercepts to the list that we've been generating (we ignore t=0 as there should be a corresponding intercept at t=1 on the previous curve)
-                intercepts.extend(curve_intercepts.into_iter().filter(|t| *t > 0.0).map(|t| curve_y.point_at_pos(t)));
+                intercepts.extend(curve_intercepts.into_iter().filter(|t| *t > 0.0).map(|t| {
+                    // Path moving to the right increases the winding number (ie, the path is anticlockwise around the ray)
+                    let (d1, d2, d3)    = derivative4(w1, w2, w3, w4);
+                    let tangent         = de_casteljau3(t, d1, d2, d3);
+
+                    (curve_y.point_at_pos(t), crossing_direction(tangent))
+                }));
             }
 
             // Order the intercepts to generate ranges
-            intercepts.sort_unstable_by(|a, b| a.total_cmp(b));
+            intercepts.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+            if self.fill_rule != FillRule::EvenOdd {
+                return self.ranges_for_fill_rule(intercepts);
+            }
 
             // Each tuple represents a range that is within the shape
             return intercepts.into_iter()
                 .tuples()
-                .map(|(start, end)| start..end)
+                .map(|((start, _), (end, _))| start..end)
                 .collect();
         }, x, 1.0, self.size.height())
     }
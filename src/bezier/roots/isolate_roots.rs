@@ -0,0 +1,584 @@
+use crate::geo::*;
+use super::polynomial_to_bezier::*;
+
+use smallvec::*;
+
+use std::f64;
+
+///
+/// An interval in `t` that contains a root of a bezier polynomial
+///
+/// Intervals found by isolating a sign change always contain a root. Intervals where the polynomial can't be distinguished
+/// from 0 are reported as roots too, but may instead be a pair of complex roots very close to the real line.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RootInterval {
+    /// The lower bound of the interval containing the root
+    pub t_min: f64,
+
+    /// The upper bound of the interval containing the root
+    pub t_max: f64,
+
+    /// The number of roots (counted with multiplicity) found in this interval
+    pub multiplicity: usize,
+}
+
+impl RootInterval {
+    ///
+    /// Returns the best estimate of the location of the root (the midpoint of the interval)
+    ///
+    #[inline]
+    pub fn t(&self) -> f64 {
+        (self.t_min + self.t_max) * 0.5
+    }
+
+    ///
+    /// Returns the width of the interval that contains the root
+    ///
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.t_max - self.t_min
+    }
+}
+
+///
+/// Counts the number of sign changes in a list of bernstein coefficients (ignoring zeros)
+///
+/// By Descartes' rule of signs (in its bernstein form), this is an upper bound on the number of roots in the
+/// interval described by the coefficients, and differs from the actual number of roots by an even number.
+///
+#[inline]
+fn sign_variations(weights: &[f64]) -> usize {
+    let mut num_variations  = 0;
+    let mut last_sign       = 0.0;
+
+    for weight in weights.iter() {
+        if *weight == 0.0 { continue; }
+
+        let sign = weight.signum();
+        if last_sign != 0.0 && sign != last_sign {
+            num_variations += 1;
+        }
+
+        last_sign = sign;
+    }
+
+    num_variations
+}
+
+///
+/// Subdivides a list of bernstein coefficients at the midpoint, returning the coefficients for the two halves
+///
+fn subdivide_weights(weights: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let n           = weights.len();
+    let mut layer   = weights.to_vec();
+    let mut left    = Vec::with_capacity(n);
+    let mut right   = Vec::with_capacity(n);
+
+    left.push(layer[0]);
+    right.push(layer[n-1]);
+
+    for depth in (1..n).rev() {
+        for idx in 0..depth {
+            layer[idx] = (layer[idx] + layer[idx+1]) * 0.5;
+        }
+
+        left.push(layer[0]);
+        right.push(layer[depth-1]);
+    }
+
+    right.reverse();
+
+    (left, right)
+}
+
+///
+/// Evaluates a list of bernstein coefficients at the specified position using de Casteljau's algorithm
+///
+fn evaluate_weights(t: f64, weights: &[f64]) -> f64 {
+    let mut layer = weights.to_vec();
+
+    for depth in (1..layer.len()).rev() {
+        for idx in 0..depth {
+            layer[idx] = layer[idx]*(1.0-t) + layer[idx+1]*t;
+        }
+    }
+
+    layer[0]
+}
+
+///
+/// Removes a root at t=0 from a set of bernstein coefficients whose first weight is 0, reducing the degree by one
+///
+fn deflate_start(weights: &[f64]) -> Vec<f64> {
+    let n = (weights.len()-1) as f64;
+
+    (0..(weights.len()-1))
+        .map(|idx| weights[idx+1] * n / ((idx+1) as f64))
+        .collect()
+}
+
+///
+/// Removes a root at t=1 from a set of bernstein coefficients whose last weight is 0, reducing the degree by one
+///
+fn deflate_end(weights: &[f64]) -> Vec<f64> {
+    let n = (weights.len()-1) as f64;
+
+    (0..(weights.len()-1))
+        .map(|idx| weights[idx] * n / (n - (idx as f64)))
+        .collect()
+}
+
+///
+/// Removes any roots at t=0 from a list of weights, returning the deflated weights and the multiplicity of the root
+///
+fn strip_start_roots(weights: Vec<f64>) -> (Vec<f64>, usize) {
+    let mut weights         = weights;
+    let mut multiplicity    = 0;
+
+    while weights.len() > 1 && weights[0] == 0.0 {
+        weights         = deflate_start(&weights);
+        multiplicity    += 1;
+    }
+
+    (weights, multiplicity)
+}
+
+///
+/// Removes any roots at t=1 from a list of weights, returning the deflated weights and the multiplicity of the root
+///
+fn strip_end_roots(weights: Vec<f64>) -> (Vec<f64>, usize) {
+    let mut weights         = weights;
+    let mut multiplicity    = 0;
+
+    while weights.len() > 1 && weights[weights.len()-1] == 0.0 {
+        weights         = deflate_end(&weights);
+        multiplicity    += 1;
+    }
+
+    (weights, multiplicity)
+}
+
+///
+/// Finds the range around `t` where the polynomial described by `weights` is no further than `threshold` from 0
+///
+fn indistinguishable_range(t: f64, weights: &[f64], threshold: f64) -> (f64, f64) {
+    let find_edge = |towards: f64| {
+        let pos_at = |s: f64| t + (towards - t) * s;
+
+        // Step away from t until the polynomial can be distinguished from 0 (or the end of the range is reached)
+        let (mut s_inside, mut s_outside) = (0.0, 1.0 / (1u64<<40) as f64);
+
+        while evaluate_weights(pos_at(s_outside), weights).abs() <= threshold {
+            if s_outside >= 1.0 { return towards; }
+
+            s_inside    = s_outside;
+            s_outside   = (s_outside * 2.0).min(1.0);
+        }
+
+        // Bisect to find the edge of the range
+        for _ in 0..64 {
+            let s_mid = (s_inside + s_outside) * 0.5;
+            if s_mid <= s_inside || s_mid >= s_outside { break; }
+
+            if evaluate_weights(pos_at(s_mid), weights).abs() <= threshold {
+                s_inside = s_mid;
+            } else {
+                s_outside = s_mid;
+            }
+        }
+
+        pos_at(s_inside)
+    };
+
+    (find_edge(0.0), find_edge(1.0))
+}
+
+///
+/// Estimates the multiplicity of a root at `t` from how quickly the range where the polynomial is close to 0 grows as the
+/// threshold is increased
+///
+/// Near a root of multiplicity `m` the polynomial grows like `(t-root)^m`, so increasing the threshold by a factor of `2^10`
+/// widens the range by a factor of `2^(10/m)`. Unlike checking the derivatives, this still works when the root is only known
+/// to be somewhere in the range where the polynomial can't be distinguished from 0.
+///
+fn estimate_multiplicity(t: f64, weights: &[f64], tolerance: f64, max_multiplicity: usize) -> usize {
+    // Both thresholds are well above the tolerance so that rounding errors don't affect the size of the ranges much
+    let (min_1, max_1)  = indistinguishable_range(t, weights, tolerance * 16.0);
+    let (min_2, max_2)  = indistinguishable_range(t, weights, tolerance * 16.0 * 1024.0);
+    let growth          = (max_2 - min_2) / (max_1 - min_1);
+
+    if growth.is_nan() || growth <= 1.0 {
+        return 1;
+    }
+
+    let multiplicity = (10.0 * f64::consts::LN_2 / growth.ln()).round() as usize;
+    multiplicity.max(1).min(max_multiplicity.max(1))
+}
+
+///
+/// Widens a root found by bisection to cover the whole range where the polynomial can't be distinguished from 0, and
+/// estimates its multiplicity
+///
+/// Rounding errors near a root with a multiplicity greater than 1 can produce a single sign change anywhere in the range
+/// where the polynomial is indistinguishable from 0, so the bisected interval may not contain the root itself.
+///
+fn widen_root(root: RootInterval, weights: &[f64], tolerance: f64, max_multiplicity: usize) -> RootInterval {
+    let (noise_min, noise_max) = indistinguishable_range(root.t(), weights, tolerance);
+
+    RootInterval {
+        t_min:          root.t_min.min(noise_min),
+        t_max:          root.t_max.max(noise_max),
+        multiplicity:   estimate_multiplicity(root.t(), weights, tolerance, max_multiplicity),
+    }
+}
+
+///
+/// Refines an interval whose bernstein coefficients have exactly one sign change (and hence exactly one root) until
+/// it is no wider than `max_width`
+///
+fn refine_single_root(weights: &[f64], t_min: f64, t_max: f64, max_width: f64) -> RootInterval {
+    // The end points must have opposite signs if there's exactly one sign variation
+    let start_sign      = weights[0].signum();
+    let (mut s_min, mut s_max) = (0.0, 1.0);
+
+    while (s_max - s_min) * (t_max - t_min) > max_width {
+        let s_mid = (s_min + s_max) * 0.5;
+
+        // Stop if we've run out of precision
+        if s_mid <= s_min || s_mid >= s_max { break; }
+
+        let val = evaluate_weights(s_mid, weights);
+
+        if val == 0.0 {
+            s_min = s_mid;
+            s_max = s_mid;
+        } else if val.signum() == start_sign {
+            s_min = s_mid;
+        } else {
+            s_max = s_mid;
+        }
+    }
+
+    RootInterval {
+        t_min:          t_min + s_min * (t_max - t_min),
+        t_max:          t_min + s_max * (t_max - t_min),
+        multiplicity:   1,
+    }
+}
+
+///
+/// A section of the range that's waiting to be searched for roots
+///
+enum RootSection {
+    /// The bernstein coefficients for the range t_min..t_max
+    Weights(Vec<f64>, f64, f64),
+
+    /// A root that was found exactly at a subdivision point, with its multiplicity
+    Root(f64, usize),
+}
+
+///
+/// Isolates the roots of a 1D bezier polynomial described by its bernstein coefficients in the range `0.0..=1.0`
+///
+fn isolate_weight_roots(weights: Vec<f64>, max_width: f64) -> Vec<RootInterval> {
+    // The coefficients are considered to be 0 if they're all within this tolerance (it's proportional to the rounding error in the weights)
+    let scale           = weights.iter().fold(0.0f64, |max, weight| max.max(weight.abs()));
+    let degree          = weights.len().max(1) - 1;
+    let tolerance       = scale * f64::EPSILON * 64.0 * (degree as f64 + 1.0);
+    let original        = weights.clone();
+
+    if scale == 0.0 {
+        // Every value of t is a root of the 0 polynomial, so there are no isolated roots
+        return vec![];
+    }
+
+    let mut roots = vec![];
+
+    // Roots at the end points of the range are removed by deflation
+    let (weights, start_multiplicity)   = strip_start_roots(weights);
+    let (weights, end_multiplicity)     = strip_end_roots(weights);
+
+    if start_multiplicity > 0 {
+        roots.push(RootInterval { t_min: 0.0, t_max: 0.0, multiplicity: start_multiplicity });
+    }
+
+    // Process the sections in order (the stack has the leftmost section at the end)
+    let mut sections    = vec![RootSection::Weights(weights, 0.0, 1.0)];
+    let mut cluster     = None;
+
+    while let Some(section) = sections.pop() {
+        let (weights, t_min, t_max) = match section {
+            RootSection::Weights(weights, t_min, t_max) => (weights, t_min, t_max),
+            RootSection::Root(t, multiplicity)          => {
+                // Roots found at a subdivision point are exact
+                if let Some((cluster_min, cluster_max)) = cluster.take() {
+                    let multiplicity = estimate_multiplicity((cluster_min+cluster_max)*0.5, &original, tolerance, degree);
+                    roots.push(RootInterval { t_min: cluster_min, t_max: cluster_max, multiplicity });
+                }
+
+                roots.push(RootInterval { t_min: t, t_max: t, multiplicity });
+                continue;
+            }
+        };
+
+        // Consecutive sections that can't be distinguished from 0 are gathered together into a single root cluster
+        let is_noise = weights.len() > 1 && weights.iter().all(|weight| weight.abs() <= tolerance);
+
+        if is_noise {
+            cluster = match cluster {
+                None                    => Some((t_min, t_max)),
+                Some((cluster_min, _))  => Some((cluster_min, t_max)),
+            };
+            continue;
+        }
+
+        if let Some((cluster_min, cluster_max)) = cluster.take() {
+            let multiplicity = estimate_multiplicity((cluster_min+cluster_max)*0.5, &original, tolerance, degree);
+            roots.push(RootInterval { t_min: cluster_min, t_max: cluster_max, multiplicity });
+        }
+
+        let num_variations = sign_variations(&weights);
+
+        if num_variations == 0 {
+            // No roots in this section
+            continue;
+        }
+
+        if num_variations == 1 {
+            // Exactly one root in this section (though rounding errors near a multiple root can also produce a single sign change)
+            let root = refine_single_root(&weights, t_min, t_max, max_width);
+            roots.push(widen_root(root, &original, tolerance, degree));
+            continue;
+        }
+
+        // Split the section in two
+        let t_mid = (t_min + t_max) * 0.5;
+
+        if t_mid <= t_min || t_mid >= t_max {
+            // Out of precision: the best we can do is report the number of sign variations as the multiplicity
+            roots.push(RootInterval { t_min, t_max, multiplicity: num_variations });
+            continue;
+        }
+
+        let (left, right) = subdivide_weights(&weights);
+
+        // If the midpoint is a root, remove it from both sides and report it separately
+        let (left, mid_multiplicity)    = strip_end_roots(left);
+        let (right, _)                  = strip_start_roots(right);
+
+        sections.push(RootSection::Weights(right, t_mid, t_max));
+        if mid_multiplicity > 0 {
+            sections.push(RootSection::Root(t_mid, mid_multiplicity));
+        }
+        sections.push(RootSection::Weights(left, t_min, t_mid));
+    }
+
+    if let Some((cluster_min, cluster_max)) = cluster.take() {
+        let multiplicity = estimate_multiplicity((cluster_min+cluster_max)*0.5, &original, tolerance, degree);
+        roots.push(RootInterval { t_min: cluster_min, t_max: cluster_max, multiplicity });
+    }
+
+    if end_multiplicity > 0 {
+        roots.push(RootInterval { t_min: 1.0, t_max: 1.0, multiplicity: end_multiplicity });
+    }
+
+    merge_indistinguishable_roots(roots, &original, tolerance)
+}
+
+///
+/// Merges neighbouring roots where the polynomial can't be distinguished from 0 in between them
+///
+/// Rounding errors will usually split a root with a multiplicity greater than 1 into several very close single roots,
+/// so this combines them back into a single root with a higher multiplicity. This only uses the tolerance, so the merged
+/// root is not guaranteed to be a real multiple root. Roots that overlap have been widened to cover the same range, so
+/// they're the same root and its multiplicity has already been estimated.
+///
+fn merge_indistinguishable_roots(roots: Vec<RootInterval>, weights: &[f64], tolerance: f64) -> Vec<RootInterval> {
+    let mut merged  = Vec::<RootInterval>::with_capacity(roots.len());
+
+    for root in roots {
+        if let Some(last) = merged.last_mut() {
+            if root.t_min <= last.t_max {
+                // Overlapping roots cover the same range
+                last.t_max          = last.t_max.max(root.t_max);
+                last.multiplicity   = last.multiplicity.max(root.multiplicity);
+                continue;
+            }
+
+            let gap_mid = (last.t_max + root.t_min) * 0.5;
+
+            if evaluate_weights(gap_mid, weights).abs() <= tolerance {
+                // Combine with the previous root
+                last.t_max          = root.t_max;
+                last.multiplicity   += root.multiplicity;
+                continue;
+            }
+        }
+
+        merged.push(root);
+    }
+
+    merged
+}
+
+///
+/// Isolates the roots of a bezier polynomial in the range `0.0..=1.0`, returning intervals that contain every root along
+/// with its multiplicity.
+///
+/// The y components of the points are the bernstein coefficients of the polynomial (the x components are ignored: the
+/// t value is used instead, so this accepts the output of `polynomial_to_bezier()` as well as 1D bezier curves written
+/// as 2D points, in the same way as `find_bezier_roots()`).
+///
+/// This uses the Vincent-Collins-Akritas method on the bernstein basis: Descartes' rule of signs bounds the number of roots
+/// in each section, and sections are subdivided until each one contains zero or one root. Single roots are then refined
+/// by bisection until the interval is no wider than `max_width`. Unlike `find_bezier_roots()`, this will not miss or
+/// duplicate roots for higher-order polynomials.
+///
+/// Roots that lie exactly at `t=0`, `t=1` or a subdivision point are returned as zero-width intervals. Where the polynomial
+/// can't be distinguished from 0 due to the limits of `f64` precision (which is typical near roots with a multiplicity
+/// greater than 1), the whole of the indistinguishable range is returned as a single interval, which may be wider than
+/// `max_width`. The multiplicity of these intervals is estimated from how quickly the range where the polynomial is close
+/// to 0 grows around them, or from the number of single roots that were found within the range. These intervals are not certified: a
+/// pair of complex roots that is close enough to the real line will be reported as a real root with multiplicity 2.
+///
+/// The returned intervals are sorted in order of increasing `t` and do not overlap.
+///
+pub fn isolate_bezier_roots<TPoint, const N: usize>(points: [TPoint; N], max_width: f64) -> SmallVec<[RootInterval; 4]>
+where
+    TPoint: Coordinate + Coordinate2D,
+{
+    if N == 0 { return smallvec![]; }
+
+    let weights = points.iter().map(|point| point.y()).collect::<Vec<_>>();
+
+    isolate_weight_roots(weights, max_width).into_iter().collect()
+}
+
+///
+/// Isolates the roots of a polynomial in the range `0.0..=1.0`, returning intervals that contain every root along with
+/// its multiplicity.
+///
+/// The polynomial has the form `c[0] + c[1]*x + c[2]*x^2 + c[3]*x^3 ...` where `c` is the list of coefficients. See
+/// `isolate_bezier_roots()` for details on how the roots are found.
+///
+pub fn isolate_polynomial_roots<const N: usize>(coefficients: [f64; N], max_width: f64) -> SmallVec<[RootInterval; 4]> {
+    if N == 0 { return smallvec![]; }
+
+    isolate_bezier_roots(polynomial_to_bezier::<Coord2, N>(coefficients), max_width)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn isolate_simple_polynomial() {
+        // (x-0.5)(x-0.4)(x-0.3)(x-0.2)(x-0.1)
+        let roots = isolate_polynomial_roots([-0.0012, 0.0274, -0.225, 0.85, -1.5, 1.0], 1e-9);
+
+        assert!(roots.len() == 5, "{:?}", roots);
+        for (root, expected) in roots.iter().zip([0.1, 0.2, 0.3, 0.4, 0.5]) {
+            assert!(root.multiplicity == 1, "{:?}", roots);
+            assert!(root.width() <= 1e-9, "{:?}", roots);
+            assert!(root.t_min <= expected+1e-12 && root.t_max >= expected-1e-12, "{:?}", roots);
+        }
+    }
+
+    #[test]
+    fn isolate_close_roots_degree_8() {
+        // Roots at 0.1, 0.11, 0.12, 0.5, 0.51, 0.52, 0.9 and 0.91 (built as bernstein coefficients by multiplying out the factors)
+        let expected    = [0.1, 0.11, 0.12, 0.5, 0.51, 0.52, 0.9, 0.91];
+        let mut poly    = [0.0; 9];
+        poly[0]         = 1.0;
+
+        for (degree, root) in expected.iter().enumerate() {
+            for idx in (0..=degree+1).rev() {
+                let lower   = if idx > 0 { poly[idx-1] } else { 0.0 };
+                poly[idx]   = lower - root * poly[idx];
+            }
+        }
+
+        let roots = isolate_polynomial_roots(poly, 1e-10);
+
+        assert!(roots.len() == 8, "{:?}", roots);
+        for (root, expected) in roots.iter().zip(expected) {
+            assert!(root.multiplicity == 1, "{:?}", roots);
+            assert!((root.t() - expected).abs() < 1e-8, "{:?}", roots);
+        }
+    }
+
+    #[test]
+    fn isolate_double_root() {
+        // (x-0.25)^2 (x-0.75)
+        let roots = isolate_polynomial_roots([-0.046875, 0.4375, -1.25, 1.0], 1e-9);
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!((roots[0].t() - 0.25).abs() < 1e-6, "{:?}", roots);
+        assert!(roots[0].multiplicity == 2, "{:?}", roots);
+        assert!((roots[1].t() - 0.75).abs() < 1e-9, "{:?}", roots);
+        assert!(roots[1].multiplicity == 1, "{:?}", roots);
+    }
+
+    #[test]
+    fn isolate_non_dyadic_double_root() {
+        // (x-0.3)^2 (x-0.7)
+        let roots = isolate_polynomial_roots([-0.063, 0.51, -1.3, 1.0], 1e-9);
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!(roots[0].t_min <= 0.3 && roots[0].t_max >= 0.3, "{:?}", roots);
+        assert!(roots[0].multiplicity == 2, "{:?}", roots);
+        assert!((roots[1].t() - 0.7).abs() < 1e-9, "{:?}", roots);
+        assert!(roots[1].multiplicity == 1, "{:?}", roots);
+    }
+
+    #[test]
+    fn isolate_non_dyadic_triple_root() {
+        // (x-0.3)^3 (x-0.8)
+        let roots = isolate_polynomial_roots([0.0216, -0.243, 0.99, -1.7, 1.0], 1e-9);
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!(roots[0].t_min <= 0.3 && roots[0].t_max >= 0.3, "{:?}", roots);
+        assert!(roots[0].multiplicity == 3, "{:?}", roots);
+        assert!((roots[1].t() - 0.8).abs() < 1e-9, "{:?}", roots);
+        assert!(roots[1].multiplicity == 1, "{:?}", roots);
+    }
+
+    #[test]
+    fn isolate_fifth_order_root() {
+        // (x-0.5)^5
+        let roots = isolate_polynomial_roots([-0.03125, 0.3125, -1.25, 2.5, -2.5, 1.0], 1e-9);
+
+        assert!(roots.len() == 1, "{:?}", roots);
+        assert!(roots[0].t_min <= 0.5 && roots[0].t_max >= 0.5, "{:?}", roots);
+        assert!(roots[0].multiplicity == 5, "{:?}", roots);
+    }
+
+    #[test]
+    fn isolate_roots_at_end_points() {
+        // x^2 (x-1)
+        let roots = isolate_polynomial_roots([0.0, 0.0, -1.0, 1.0], 1e-9);
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!(roots[0] == RootInterval { t_min: 0.0, t_max: 0.0, multiplicity: 2 }, "{:?}", roots);
+        assert!(roots[1] == RootInterval { t_min: 1.0, t_max: 1.0, multiplicity: 1 }, "{:?}", roots);
+    }
+
+    #[test]
+    fn isolate_root_at_midpoint() {
+        // (x-0.5)(x-0.25)
+        let roots = isolate_polynomial_roots([0.125, -0.75, 1.0], 1e-9);
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!((roots[0].t() - 0.25).abs() < 1e-9, "{:?}", roots);
+        assert!(roots[1] == RootInterval { t_min: 0.5, t_max: 0.5, multiplicity: 1 }, "{:?}", roots);
+    }
+
+    #[test]
+    fn no_roots_for_complex_pair() {
+        // (x-0.5)^2 + 0.0001
+        let roots = isolate_polynomial_roots([0.2501, -1.0, 1.0], 1e-9);
+
+        assert!(roots.is_empty(), "{:?}", roots);
+    }
+}
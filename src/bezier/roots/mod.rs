@@ -3,7 +3,9 @@
 mod polynomial_to_bezier;
 mod find_roots;
 mod nearest_point_bezier_root_finder;
+mod isolate_roots;
 
 pub use polynomial_to_bezier::*;
 pub use find_roots::*;
 pub use nearest_point_bezier_root_finder::*;
+pub use isolate_roots::*;
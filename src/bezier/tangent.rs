@@ -1,6 +1,7 @@
 use super::curve::*;
 use super::basis::*;
 use super::derivative::*;
+use super::super::geo::*;
 
 ///
 /// A structure that can be used to compute the tangent of a bezier curve
@@ -31,3 +32,59 @@ impl<Curve: BezierCurve> Tangent<Curve> {
         de_casteljau3(t, self.derivative.0, self.derivative.1, self.derivative.2)
     }
 }
+
+///
+/// Returns a unit vector in the direction a curve is travelling at a particular t value
+///
+/// The tangent is 0 where a control point is on top of the start or end point (or at a cusp), so in that case this uses the
+/// next derivative that's non-zero, falling back to the direction of the chord. This is the direction the curve leaves the point
+/// in, except at t=1.0 where it's the direction the curve arrives from. Curves that are just a point have no direction, so the
+/// result is the origin for those.
+///
+pub (crate) fn curve_direction<Curve: BezierCurve>(curve: &Curve, t: f64) -> Curve::Point {
+    const MIN_MAGNITUDE: f64 = 1e-12;
+
+    let (cp1, cp2)      = curve.control_points();
+    let (d1, d2, d3)    = derivative4(curve.start_point(), cp1, cp2, curve.end_point());
+
+    // Where the tangent is 0, the direction of travel after t is the second derivative (and before t is the opposite direction)
+    let tangent         = de_casteljau3(t, d1, d2, d3);
+    let second          = de_casteljau2(t, d2 - d1, d3 - d2);
+    let second          = if t >= 1.0 { second * -1.0 } else { second };
+    let third           = (d3 - d2) - (d2 - d1);
+    let chord           = curve.end_point() - curve.start_point();
+
+    [tangent, second, third, chord].iter()
+        .find(|direction| direction.magnitude() > MIN_MAGNITUDE)
+        .map(|direction| direction.to_unit_vector())
+        .unwrap_or_else(Curve::Point::origin)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn direction_with_control_points_on_end_points() {
+        let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(0.0, 0.0), Coord2(10.0, 10.0)), Coord2(10.0, 10.0));
+
+        assert!(curve_direction(&curve, 0.0).is_near_to(&Coord2(1.0, 1.0).to_unit_vector(), 1e-6));
+        assert!(curve_direction(&curve, 1.0).is_near_to(&Coord2(1.0, 1.0).to_unit_vector(), 1e-6));
+    }
+
+    #[test]
+    fn direction_of_curve_leaving_along_second_control_point() {
+        // The first control point is on the start point, so the curve leaves towards the second control point
+        let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(0.0, 0.0), Coord2(0.0, 10.0)), Coord2(10.0, 10.0));
+
+        assert!(curve_direction(&curve, 0.0).is_near_to(&Coord2(0.0, 1.0), 1e-6));
+        assert!(curve_direction(&curve, 1.0).is_near_to(&Coord2(1.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn point_has_no_direction() {
+        let curve = Curve::from_points(Coord2(1.0, 1.0), (Coord2(1.0, 1.0), Coord2(1.0, 1.0)), Coord2(1.0, 1.0));
+
+        assert!(curve_direction(&curve, 0.5) == Coord2::origin());
+    }
+}
@@ -106,6 +106,7 @@ mod consts;
 pub mod bezier;
 pub mod line;
 pub mod arc;
+pub mod shape;
 pub mod debug;
 
 pub mod geo;
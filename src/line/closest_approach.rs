@@ -0,0 +1,78 @@
+use super::line::*;
+use super::super::geo::*;
+
+///
+/// Finds the positions where two rays (lines extended to infinity) come closest to each other
+///
+/// This works for lines with any number of dimensions: in 3D, lines will usually not intersect, but this can be used to
+/// find where they pass closest to each other. The return value is the `t` value along each ray for the closest points,
+/// or `None` if the lines are parallel (or either is a point) and so have no single closest point.
+///
+pub fn ray_closest_approach<L: Line>(ray1: &L, ray2: &L) -> Option<(f64, f64)> {
+    let (p1, p2)    = ray1.points();
+    let (q1, q2)    = ray2.points();
+    let (d1, d2)    = (p2-p1, q2-q1);
+    let offset      = p1-q1;
+
+    let a           = d1.dot(&d1);
+    let b           = d1.dot(&d2);
+    let c           = d2.dot(&d2);
+    let d           = d1.dot(&offset);
+    let e           = d2.dot(&offset);
+    let divisor     = a*c - b*b;
+
+    if a <= 0.0 || c <= 0.0 || divisor <= 1e-12 * a * c {
+        None
+    } else {
+        Some(((b*e - c*d) / divisor, (a*e - b*d) / divisor))
+    }
+}
+
+///
+/// Finds the positions where two line segments come closest to each other
+///
+/// This works for lines with any number of dimensions. The return value is the `t` value along each line for the closest
+/// points, both in the range `0.0..=1.0`. If there are many closest points (for instance, because the lines are parallel
+/// and overlapping), one of them is returned.
+///
+pub fn line_closest_approach<L: Line>(line1: &L, line2: &L) -> (f64, f64) {
+    let (p1, p2)    = line1.points();
+    let (q1, q2)    = line2.points();
+    let (d1, d2)    = (p2-p1, q2-q1);
+    let offset      = p1-q1;
+
+    let a           = d1.dot(&d1);
+    let c           = d2.dot(&d2);
+    let e           = d2.dot(&offset);
+
+    if a <= 0.0 && c <= 0.0 {
+        // Both lines are points
+        return (0.0, 0.0);
+    } else if a <= 0.0 {
+        // The first line is a point
+        return (0.0, (e/c).clamp(0.0, 1.0));
+    }
+
+    let d = d1.dot(&offset);
+
+    if c <= 0.0 {
+        // The second line is a point
+        return ((-d/a).clamp(0.0, 1.0), 0.0);
+    }
+
+    // Find the closest point on the first line to the second ray (any point will do if the lines are parallel)
+    let b           = d1.dot(&d2);
+    let divisor     = a*c - b*b;
+    let t1          = if divisor > 1e-12 * a * c { ((b*e - c*d) / divisor).clamp(0.0, 1.0) } else { 0.0 };
+
+    // Find the closest point on the second line to that, then move the point on the first line if that had to be clamped
+    let t2          = (b*t1 + e) / c;
+
+    if t2 < 0.0 {
+        ((-d/a).clamp(0.0, 1.0), 0.0)
+    } else if t2 > 1.0 {
+        (((b-d)/a).clamp(0.0, 1.0), 1.0)
+    } else {
+        (t1, t2)
+    }
+}
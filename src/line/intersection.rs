@@ -1,5 +1,6 @@
 use super::line::*;
 use super::super::geo::*;
+use super::super::consts::*;
 
 /// Smallest divisor magnitude to use in ray_intersects_ray (the closer the divisor is to 0, the more close to parallel the lines are), so this
 /// determines the shallowest angle allowed between two lines before we consider them to be parallel.
@@ -12,6 +13,8 @@ const RAY_DIVISOR_SMALLEST_VALUE: f64 = 2e-12;
 /// Only the 2-dimensional form is supported at the moment (lines are much less likely to intersect
 /// in higher dimensions)
 /// 
+/// Collinear lines are treated as not intersecting: use `line_intersection()` to find where they overlap.
+/// 
 pub fn line_intersects_line<L: Line>(line1: &L, line2: &L) -> Option<L::Point> 
 where
     L::Point: Coordinate2D,
@@ -35,6 +38,103 @@ where
     }
 }
 
+///
+/// Describes how two line segments meet
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LineIntersection<Point> {
+    /// The lines do not meet
+    None,
+
+    /// The lines meet at a single point, which is at `t1` along the first line and `t2` along the second
+    Point { point: Point, t1: f64, t2: f64 },
+
+    /// The lines are collinear and share a section. `t1` is the range of the shared section along the first line (in
+    /// increasing order) and `t2` is the same section along the second line (which is in decreasing order if the lines
+    /// go in opposite directions)
+    Overlap { t1: (f64, f64), t2: (f64, f64) },
+}
+
+///
+/// Finds how two line segments meet, including the section they share if they are collinear
+///
+/// Lines are considered collinear if the end points of one are within `SMALL_DISTANCE` of the other (extended to infinity),
+/// and collinear lines that only share a section shorter than this are considered to meet at a single point. Lines
+/// with the same start and end point are treated as a point.
+///
+pub fn line_intersection<L: Line>(line1: &L, line2: &L) -> LineIntersection<L::Point>
+where
+    L::Point: Coordinate2D,
+{
+    let (p1, p2)    = line1.points();
+    let (q1, q2)    = line2.points();
+    let (d1, d2)    = (p2-p1, q2-q1);
+    let (len1, len2) = (d1.magnitude(), d2.magnitude());
+
+    if len1 <= 0.0 && len2 <= 0.0 {
+        // Both lines are points
+        return if p1.distance_to(&q1) <= SMALL_DISTANCE {
+            LineIntersection::Point { point: p1, t1: 0.0, t2: 0.0 }
+        } else {
+            LineIntersection::None
+        };
+    } else if len1 <= 0.0 {
+        // Work out the intersection with the lines the other way around so we have a line to measure distances against
+        return match line_intersection(line2, line1) {
+            LineIntersection::None                      => LineIntersection::None,
+            LineIntersection::Point { point, t1, t2 }   => LineIntersection::Point { point, t1: t2, t2: t1 },
+            LineIntersection::Overlap { t1, t2 }        => LineIntersection::Overlap { t1: t2, t2: t1 },
+        };
+    }
+
+    // Distances of the end points of the second line from the first line (extended to infinity)
+    let cross       = |v: L::Point| (d1.x()*v.y() - d1.y()*v.x()) / len1;
+    let dist_q1     = cross(q1-p1);
+    let dist_q2     = cross(q2-p1);
+
+    if dist_q1.abs() <= SMALL_DISTANCE && dist_q2.abs() <= SMALL_DISTANCE {
+        // Lines are collinear: project the second line onto the first one to find the shared section
+        let t_on_line1  = |point: L::Point| (point-p1).dot(&d1) / (len1*len1);
+        let t_on_line2  = |point: L::Point| if len2 > 0.0 { ((point-q1).dot(&d2) / (len2*len2)).clamp(0.0, 1.0) } else { 0.0 };
+
+        let (s1, s2)    = (t_on_line1(q1), t_on_line1(q2));
+        let start       = s1.min(s2).max(0.0);
+        let end         = s1.max(s2).min(1.0);
+
+        if (end - start) * len1 < -SMALL_DISTANCE {
+            // Lines are collinear but don't meet
+            LineIntersection::None
+        } else if (end - start) * len1 <= SMALL_DISTANCE {
+            // Lines touch at a single point
+            let t1      = ((start + end) * 0.5).clamp(0.0, 1.0);
+            let point   = line1.point_at_pos(t1);
+
+            LineIntersection::Point { point, t1, t2: t_on_line2(point) }
+        } else {
+            // Lines share a section
+            let t2      = (t_on_line2(line1.point_at_pos(start)), t_on_line2(line1.point_at_pos(end)));
+
+            LineIntersection::Overlap { t1: (start, end), t2 }
+        }
+    } else {
+        // Lines are not collinear, so they can meet at a single point at most
+        let divisor = d2.y()*d1.x() - d2.x()*d1.y();
+        if divisor.abs() <= RAY_DIVISOR_SMALLEST_VALUE {
+            return LineIntersection::None;
+        }
+
+        let offset  = p1-q1;
+        let t1      = (d2.x()*offset.y() - d2.y()*offset.x()) / divisor;
+        let t2      = (d1.x()*offset.y() - d1.y()*offset.x()) / divisor;
+
+        if (0.0..=1.0).contains(&t1) && (0.0..=1.0).contains(&t2) {
+            LineIntersection::Point { point: line1.point_at_pos(t1), t1, t2 }
+        } else {
+            LineIntersection::None
+        }
+    }
+}
+
 ///
 /// Returns the point at which a line and a ray intersect (if they intersect). The ray is assumed to be
 /// infinitely long, but the line is not.
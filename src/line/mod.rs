@@ -7,16 +7,22 @@
 //! 
 //! The basic line type is simply a tuple of two points (that is, any tuple of two values of the same type that
 //! implements `Coordinate`).
+//! 
+//! `Polyline` represents a series of connected lines, and can be simplified or converted to and from bezier paths.
 //!
 
 mod line;
 mod to_curve;
 mod intersection;
 mod coefficients;
+mod closest_approach;
+mod polyline;
 
 pub use self::line::*;
 pub use self::to_curve::*;
 pub use self::coefficients::*;
 pub use self::intersection::*;
+pub use self::closest_approach::*;
+pub use self::polyline::*;
 
 pub use super::geo::*;
@@ -0,0 +1,370 @@
+use super::line::*;
+use super::to_curve::*;
+use super::intersection::*;
+use super::super::bezier::*;
+use super::super::bezier::path::*;
+use super::super::consts::*;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Maximum number of times a curve is subdivided when converting a path to a polyline
+const MAX_FLATTEN_DEPTH: usize = 16;
+
+///
+/// A series of points joined by straight lines
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Polyline<Point> {
+    /// The points in this polyline
+    points: Vec<Point>
+}
+
+///
+/// Returns the distance from a point to the closest point on a line segment, along with the `t` value of that point
+///
+#[inline]
+fn nearest_on_segment<Point: Coordinate>(point: &Point, start: &Point, end: &Point) -> (f64, f64) {
+    let line_delta  = *end - *start;
+    let length_sq   = line_delta.dot(&line_delta);
+    let t           = if length_sq > 0.0 { ((*point - *start).dot(&line_delta) / length_sq).clamp(0.0, 1.0) } else { 0.0 };
+
+    (point.distance_to(&(*start + line_delta*t)), t)
+}
+
+///
+/// Returns the area of the triangle formed by three points (in any number of dimensions)
+///
+#[inline]
+fn triangle_area<Point: Coordinate>(p1: &Point, p2: &Point, p3: &Point) -> f64 {
+    let a       = *p2 - *p1;
+    let b       = *p3 - *p1;
+    let a_dot_b = a.dot(&b);
+
+    0.5 * (a.dot(&a)*b.dot(&b) - a_dot_b*a_dot_b).max(0.0).sqrt()
+}
+
+///
+/// Point waiting to be removed by the Visvalingam-Whyatt algorithm (ordered so the smallest area is at the top of a `BinaryHeap`)
+///
+struct EffectiveArea {
+    area:   f64,
+    index:  usize
+}
+
+impl PartialEq for EffectiveArea {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EffectiveArea { }
+
+impl PartialOrd for EffectiveArea {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EffectiveArea {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.total_cmp(&self.area).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<Point: Coordinate> Geo for Polyline<Point> {
+    type Point = Point;
+}
+
+impl<Point: Coordinate> HasBoundingBox for Polyline<Point> {
+    ///
+    /// Returns the bounding box that encloses the points in this polyline
+    ///
+    fn get_bounding_box<Bounds: BoundingBox<Point=Self::Point>>(&self) -> Bounds {
+        Bounds::bounds_for_points(self.points.iter().cloned())
+    }
+}
+
+impl<Point: Coordinate> Polyline<Point> {
+    ///
+    /// Creates a new polyline from a list of points
+    ///
+    pub fn new<PointIter: IntoIterator<Item=Point>>(points: PointIter) -> Polyline<Point> {
+        Polyline {
+            points: points.into_iter().collect()
+        }
+    }
+
+    ///
+    /// Creates a polyline that follows a bezier path to within `max_error`
+    ///
+    /// Every curve in the path is subdivided until its control points are within `max_error` of the line between its
+    /// end points, so straight sections of the path generate a single line.
+    ///
+    pub fn from_path<P: BezierPath<Point=Point>>(path: &P, max_error: f64) -> Polyline<Point> {
+        let mut points      = vec![path.start_point()];
+        let mut last_point  = path.start_point();
+
+        for (cp1, cp2, end_point) in path.points() {
+            Self::flatten_curve(&mut points, (last_point, cp1, cp2, end_point), max_error, 0);
+            last_point = end_point;
+        }
+
+        Polyline { points }
+    }
+
+    ///
+    /// Adds the end points of the lines that approximate a bezier curve to a list of points
+    ///
+    fn flatten_curve(points: &mut Vec<Point>, (w1, w2, w3, w4): (Point, Point, Point, Point), max_error: f64, depth: usize) {
+        let flatness = nearest_on_segment(&w2, &w1, &w4).0.max(nearest_on_segment(&w3, &w1, &w4).0);
+
+        if flatness <= max_error || depth >= MAX_FLATTEN_DEPTH {
+            points.push(w4);
+        } else {
+            let (first, second) = subdivide4(0.5, w1, w2, w3, w4);
+
+            Self::flatten_curve(points, first, max_error, depth+1);
+            Self::flatten_curve(points, second, max_error, depth+1);
+        }
+    }
+
+    ///
+    /// The points in this polyline
+    ///
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    ///
+    /// The number of points in this polyline
+    ///
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    ///
+    /// True if this polyline has no points
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    ///
+    /// True if this polyline ends where it starts
+    ///
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 2 && self.points[0].is_near_to(&self.points[self.points.len()-1], SMALL_DISTANCE)
+    }
+
+    ///
+    /// Returns the lines that make up this polyline
+    ///
+    pub fn lines(&self) -> impl '_+Iterator<Item=(Point, Point)> {
+        self.points.windows(2).map(|points| (points[0], points[1]))
+    }
+
+    ///
+    /// The total length of the lines in this polyline
+    ///
+    pub fn length(&self) -> f64 {
+        self.lines().map(|(start, end)| start.distance_to(&end)).sum()
+    }
+
+    ///
+    /// Finds the nearest point on this polyline to the specified point
+    ///
+    /// The result is the index of the line containing the nearest point, the `t` value along that line and the point
+    /// itself, or `None` if the polyline has no points.
+    ///
+    pub fn nearest_point(&self, point: &Point) -> Option<(usize, f64, Point)> {
+        if self.points.len() == 1 {
+            return Some((0, 0.0, self.points[0]));
+        }
+
+        self.lines()
+            .enumerate()
+            .map(|(index, (start, end))| {
+                let (distance, t) = nearest_on_segment(point, &start, &end);
+                (distance, index, t, (start, end).point_at_pos(t))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, index, t, point)| (index, t, point))
+    }
+
+    ///
+    /// Simplifies this polyline using the Douglas-Peucker algorithm
+    ///
+    /// Points are removed as long as every removed point is within `max_error` of the line that replaces it. The start
+    /// and end points are always kept.
+    ///
+    pub fn simplify_douglas_peucker(&self, max_error: f64) -> Polyline<Point> {
+        if self.points.len() <= 2 {
+            return self.clone();
+        }
+
+        let mut keep        = vec![false; self.points.len()];
+        let mut sections    = vec![(0, self.points.len()-1)];
+
+        keep[0]                     = true;
+        keep[self.points.len()-1]   = true;
+
+        while let Some((start, end)) = sections.pop() {
+            // Find the point furthest from the line between the start and end of this section
+            let furthest = (start+1..end)
+                .map(|index| (index, nearest_on_segment(&self.points[index], &self.points[start], &self.points[end]).0))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            // Split the section at that point if it's too far away
+            if let Some((index, distance)) = furthest {
+                if distance > max_error {
+                    keep[index] = true;
+                    sections.push((start, index));
+                    sections.push((index, end));
+                }
+            }
+        }
+
+        Polyline {
+            points: self.points.iter().zip(keep).filter(|(_, keep)| *keep).map(|(point, _)| *point).collect()
+        }
+    }
+
+    ///
+    /// Simplifies this polyline using the Visvalingam-Whyatt algorithm
+    ///
+    /// Points are removed in order of the area of the triangle they form with their neighbours, until every remaining
+    /// point forms a triangle with an area of at least `min_area`. The start and end points are always kept.
+    ///
+    pub fn simplify_visvalingam(&self, min_area: f64) -> Polyline<Point> {
+        let num_points = self.points.len();
+        if num_points <= 2 {
+            return self.clone();
+        }
+
+        // The remaining points form a linked list
+        let mut previous    = (0..num_points).map(|index| index.wrapping_sub(1)).collect::<Vec<_>>();
+        let mut next        = (1..=num_points).collect::<Vec<_>>();
+        let mut areas       = vec![f64::INFINITY; num_points];
+        let mut removed     = vec![false; num_points];
+        let mut queue       = BinaryHeap::new();
+
+        let area_at         = |index: usize, previous: &Vec<usize>, next: &Vec<usize>| {
+            triangle_area(&self.points[previous[index]], &self.points[index], &self.points[next[index]])
+        };
+
+        for (index, area) in areas.iter_mut().enumerate().take(num_points-1).skip(1) {
+            *area = area_at(index, &previous, &next);
+            queue.push(EffectiveArea { area: *area, index });
+        }
+
+        while let Some(EffectiveArea { area, index }) = queue.pop() {
+            // Ignore points that are already removed or where the area has changed since they were queued
+            if removed[index] || area != areas[index] { continue; }
+            if area >= min_area { break; }
+
+            // Remove this point from the list
+            let (before, after) = (previous[index], next[index]);
+            removed[index]      = true;
+            next[before]        = after;
+            previous[after]     = before;
+
+            // Update the areas of its neighbours (their area never drops below the area of the point that was removed,
+            // so removing a point can't cause a point with a larger area to be removed first)
+            for neighbour in [before, after] {
+                if neighbour != 0 && neighbour != num_points-1 {
+                    areas[neighbour] = area_at(neighbour, &previous, &next).max(area);
+                    queue.push(EffectiveArea { area: areas[neighbour], index: neighbour });
+                }
+            }
+        }
+
+        Polyline {
+            points: self.points.iter().zip(removed).filter(|(_, removed)| !*removed).map(|(point, _)| *point).collect()
+        }
+    }
+
+    ///
+    /// Converts this polyline to a bezier path, where every line becomes a straight curve
+    ///
+    pub fn to_path<P: BezierPathFactory<Point=Point>>(&self) -> P {
+        let start_point = self.points.first().copied().unwrap_or_else(Point::origin);
+
+        P::from_points(start_point, self.lines().map(|line| {
+            let curve       = line_to_bezier::<Curve<_>>(&line);
+            let (cp1, cp2)  = curve.control_points();
+
+            (cp1, cp2, line.1)
+        }))
+    }
+
+    ///
+    /// Converts this polyline to a smooth bezier path that passes within `max_error` of all of its points
+    ///
+    /// Closed polylines are fitted as a loop so there is no corner where they start and end. Returns `None` if there
+    /// are not enough points to fit a curve to.
+    ///
+    pub fn to_smooth_path<P: BezierPathFactory<Point=Point>>(&self, max_error: f64) -> Option<P> {
+        let curves = if self.is_closed() {
+            fit_curve_loop::<Curve<_>>(&self.points, max_error)?
+        } else {
+            fit_curve::<Curve<_>>(&self.points, max_error)?
+        };
+
+        Some(P::from_connected_curves(curves))
+    }
+}
+
+impl<Point: Coordinate+Coordinate2D> Polyline<Point> {
+    ///
+    /// Finds where a line meets this polyline
+    ///
+    /// The result is the index of each line in this polyline that meets the other line, along with how they meet (the
+    /// first line in the `LineIntersection` is the line from this polyline).
+    ///
+    pub fn intersects_line<L: Line<Point=Point>>(&self, line: &L) -> Vec<(usize, LineIntersection<Point>)> {
+        let line = line.points();
+
+        self.lines()
+            .enumerate()
+            .map(|(index, polyline_line)| (index, line_intersection(&polyline_line, &line)))
+            .filter(|(_, intersection)| intersection != &LineIntersection::None)
+            .collect()
+    }
+
+    ///
+    /// Finds where another polyline meets this one
+    ///
+    /// The result is the index of the line from this polyline, the index of the line from the other polyline and how
+    /// they meet.
+    ///
+    pub fn intersects_polyline(&self, other: &Polyline<Point>) -> Vec<(usize, usize, LineIntersection<Point>)> {
+        let other_bounds = other.get_bounding_box::<Bounds<_>>();
+        let mut result   = vec![];
+
+        for (index, line) in self.lines().enumerate() {
+            // Skip lines that can't meet the other polyline
+            let line_bounds = Bounds::bounds_for_points(vec![line.0, line.1]);
+            if !line_bounds.overlaps(&other_bounds) { continue; }
+
+            for (other_index, intersection) in other.intersects_line(&line) {
+                // Swap the intersection around so that the first line is the line from this polyline (and overlaps are in increasing order along it)
+                let intersection = match intersection {
+                    LineIntersection::None                      => LineIntersection::None,
+                    LineIntersection::Point { point, t1, t2 }   => LineIntersection::Point { point, t1: t2, t2: t1 },
+                    LineIntersection::Overlap { t1, t2 }        => {
+                        if t2.0 <= t2.1 {
+                            LineIntersection::Overlap { t1: t2, t2: t1 }
+                        } else {
+                            LineIntersection::Overlap { t1: (t2.1, t2.0), t2: (t1.1, t1.0) }
+                        }
+                    }
+                };
+
+                result.push((index, other_index, intersection));
+            }
+        }
+
+        result
+    }
+}
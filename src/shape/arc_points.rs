@@ -0,0 +1,20 @@
+use crate::arc::*;
+use crate::bezier::*;
+
+///
+/// Generates the control points for an elliptical arc, using a curve for every 90 degrees
+///
+/// The angles are in radians, clockwise from the positive y axis. The curves go from `start_angle` to `end_angle`,
+/// which can be in either direction.
+///
+pub (super) fn elliptical_arc_points<Coord: Coordinate+Coordinate2D>(center: Coord, (radius_x, radius_y): (f64, f64), start_angle: f64, end_angle: f64) -> Vec<(Coord, Coord, Coord)> {
+    Ellipse::new(center, radius_x, radius_y)
+        .arc(start_angle, end_angle)
+        .quarter_bezier_curves::<Curve<_>>()
+        .into_iter()
+        .map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        })
+        .collect()
+}
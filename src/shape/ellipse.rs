@@ -0,0 +1,66 @@
+use super::arc_points::*;
+use crate::geo::*;
+use crate::bezier::path::*;
+
+use std::f64;
+
+///
+/// Creates an axis-aligned ellipse
+///
+/// The path starts at the top of the ellipse and goes clockwise.
+///
+pub fn ellipse<P>(center: P::Point, radius_x: f64, radius_y: f64) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let mut points = elliptical_arc_points(center, (radius_x, radius_y), 0.0, 2.0*f64::consts::PI);
+
+    // Make sure the path ends exactly at the start point
+    let start_point = P::Point::from_components(&[center.x(), center.y() + radius_y]);
+    if let Some((_, _, end_point)) = points.last_mut() {
+        *end_point = start_point;
+    }
+
+    P::from_points(start_point, points)
+}
+
+///
+/// Creates a pie wedge: a section of a circle bounded by two radii
+///
+/// The angles are in radians, measured clockwise from the positive y axis, in the same way as for `Circle::arc()`.
+/// The wedge covers the angles from `start_angle` to `end_angle` going clockwise, so the order of the angles does not
+/// matter. A wedge that covers 360 degrees or more is a whole circle.
+///
+/// The path starts at the center of the circle, goes out to the arc at the lesser of the two angles and then goes
+/// clockwise around the arc before returning to the center. A whole circle starts at the lesser angle instead.
+///
+pub fn pie_wedge<P>(center: P::Point, radius: f64, start_angle: f64, end_angle: f64) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let (start_angle, end_angle) = if start_angle <= end_angle { (start_angle, end_angle) } else { (end_angle, start_angle) };
+    let arc_start   = P::Point::from_components(&[center.x() + radius*start_angle.sin(), center.y() + radius*start_angle.cos()]);
+
+    if end_angle - start_angle >= 2.0*f64::consts::PI {
+        // Whole circle
+        let mut points = elliptical_arc_points(center, (radius, radius), start_angle, start_angle + 2.0*f64::consts::PI);
+        if let Some((_, _, end_point)) = points.last_mut() {
+            *end_point = arc_start;
+        }
+
+        P::from_points(arc_start, points)
+    } else {
+        // Line out from the center, the arc, then a line back to the center
+        let mut path = BezierPathBuilder::<P>::start(center).line_to(arc_start);
+
+        if end_angle > start_angle {
+            for (cp1, cp2, end_point) in elliptical_arc_points(center, (radius, radius), start_angle, end_angle) {
+                path = path.curve_to((cp1, cp2), end_point);
+            }
+        }
+
+        path.line_to(center).build()
+    }
+}
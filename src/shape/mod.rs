@@ -0,0 +1,35 @@
+//!
+//! # Standard shapes
+//!
+//! The `shape` module provides functions for generating common shapes as bezier paths: rectangles with rounded
+//! corners, ellipses, regular polygons, stars, superellipses and pie wedges. Any type that implements
+//! `BezierPathFactory` can be generated.
+//!
+//! ```
+//! # use flo_curves::*;
+//! # use flo_curves::shape;
+//! # use flo_curves::bezier::path::*;
+//! #
+//! let button    = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(100.0, 30.0), 8.0);
+//! let hexagon   = shape::regular_polygon::<SimpleBezierPath>(Coord2(50.0, 50.0), 20.0, 6);
+//! ```
+//!
+//! All of the shapes follow the same conventions, which match `Circle::to_path()`: each shape is a single closed path
+//! that is wound clockwise (assuming that positive y values indicate an upwards direction, as for `is_clockwise()`),
+//! and starts at the top of the shape. Angles are measured in radians clockwise from the positive y axis, in the same
+//! way as for `Circle::arc()`.
+//!
+//! Circular and elliptical sections are approximated using a cubic curve for every 90 degrees, which is accurate to
+//! around 0.03% of the radius.
+//!
+
+mod arc_points;
+mod rectangle;
+mod ellipse;
+mod polygon;
+mod superellipse;
+
+pub use self::rectangle::*;
+pub use self::ellipse::*;
+pub use self::polygon::*;
+pub use self::superellipse::*;
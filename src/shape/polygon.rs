@@ -0,0 +1,56 @@
+use crate::geo::*;
+use crate::bezier::path::*;
+
+use std::f64;
+
+///
+/// Creates a path from a list of vertices at angles around a center point (the first vertex is the start of the path)
+///
+fn polygon_from_vertices<P>(center: P::Point, vertices: impl Iterator<Item=(f64, f64)>) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let mut vertices    = vertices.map(|(angle, radius)| P::Point::from_components(&[center.x() + radius*angle.sin(), center.y() + radius*angle.cos()]));
+    let start_point     = vertices.next().unwrap_or(center);
+
+    vertices
+        .fold(BezierPathBuilder::<P>::start(start_point), |path, vertex| path.line_to(vertex))
+        .line_to(start_point)
+        .build()
+}
+
+///
+/// Creates a regular polygon with the specified number of sides
+///
+/// The vertices are all `radius` away from the center. The first vertex is directly above the center, and the path
+/// starts there and goes clockwise. Fewer than 3 sides will produce a degenerate shape.
+///
+pub fn regular_polygon<P>(center: P::Point, radius: f64, num_sides: usize) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let angle_per_side = 2.0*f64::consts::PI / (num_sides.max(1) as f64);
+
+    polygon_from_vertices(center, (0..num_sides).map(|side| (angle_per_side*(side as f64), radius)))
+}
+
+///
+/// Creates a star with the specified number of points
+///
+/// The tips of the star are `outer_radius` away from the center, and the vertices between them are `inner_radius`
+/// away. The first tip is directly above the center, and the path starts there and goes clockwise.
+///
+pub fn star<P>(center: P::Point, outer_radius: f64, inner_radius: f64, num_points: usize) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let angle_per_vertex = f64::consts::PI / (num_points.max(1) as f64);
+
+    polygon_from_vertices(center, (0..num_points*2).map(|vertex| {
+        let radius = if vertex % 2 == 0 { outer_radius } else { inner_radius };
+        (angle_per_vertex*(vertex as f64), radius)
+    }))
+}
@@ -0,0 +1,86 @@
+use super::arc_points::*;
+use crate::geo::*;
+use crate::bezier::path::*;
+
+use std::f64;
+
+///
+/// Creates a rectangle with rounded corners, using the same radius for every corner
+///
+/// See `rounded_rectangle_with_corner_radii()` for details.
+///
+pub fn rounded_rectangle<P>(min: P::Point, max: P::Point, radius: f64) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    rounded_rectangle_with_corner_radii(min, max, (radius, radius, radius, radius))
+}
+
+///
+/// Creates a rectangle with a different radius for each corner
+///
+/// The radii are given in the order top-left, top-right, bottom-right, bottom-left (where the top is the edge with the
+/// greatest y value). A radius of 0 produces a square corner. If the radii on an edge add up to more than the length
+/// of that edge, all of the radii are scaled down by the same amount so that they fit (the same rule as CSS uses for
+/// `border-radius`).
+///
+/// The path starts at the left-hand end of the straight part of the top edge and goes clockwise.
+///
+pub fn rounded_rectangle_with_corner_radii<P>(min: P::Point, max: P::Point, (top_left, top_right, bottom_right, bottom_left): (f64, f64, f64, f64)) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    let (x0, y0)    = (min.x().min(max.x()), min.y().min(max.y()));
+    let (x1, y1)    = (min.x().max(max.x()), min.y().max(max.y()));
+    let (w, h)      = (x1-x0, y1-y0);
+
+    // Scale the radii so they fit along every edge
+    let radii       = [top_left.max(0.0), top_right.max(0.0), bottom_right.max(0.0), bottom_left.max(0.0)];
+    let edge_fit    = |length: f64, radius1: f64, radius2: f64| if radius1+radius2 > length { length / (radius1+radius2) } else { 1.0 };
+    let scale       = edge_fit(w, radii[0], radii[1])
+        .min(edge_fit(h, radii[1], radii[2]))
+        .min(edge_fit(w, radii[2], radii[3]))
+        .min(edge_fit(h, radii[3], radii[0]));
+    let [tl, tr, br, bl] = [radii[0]*scale, radii[1]*scale, radii[2]*scale, radii[3]*scale];
+
+    let point       = |x: f64, y: f64| P::Point::from_components(&[x, y]);
+    let quarter     = f64::consts::PI/2.0;
+
+    // Each corner is an arc around its center, which is followed by the straight edge to the start of the next corner
+    let corners     = [
+        (point(x1-tr, y1-tr), tr, 0.0,          point(x1, y1-tr), point(x1, y0+br)),
+        (point(x1-br, y0+br), br, quarter,      point(x1-br, y0), point(x0+bl, y0)),
+        (point(x0+bl, y0+bl), bl, quarter*2.0,  point(x0, y0+bl), point(x0, y1-tl)),
+        (point(x0+tl, y1-tl), tl, quarter*3.0,  point(x0+tl, y1), point(x0+tl, y1)),
+    ];
+
+    let start_point = point(x0+tl, y1);
+    let mut path    = BezierPathBuilder::<P>::start(start_point);
+
+    // The top edge
+    if x1-tr > x0+tl {
+        path = path.line_to(point(x1-tr, y1));
+    }
+
+    for (center, radius, start_angle, arc_end, edge_end) in corners.iter() {
+        if *radius > 0.0 {
+            let mut arc = elliptical_arc_points(*center, (*radius, *radius), *start_angle, start_angle + quarter);
+            if let Some((_, _, end_point)) = arc.last_mut() {
+                *end_point = *arc_end;
+            }
+
+            for (cp1, cp2, end_point) in arc {
+                path = path.curve_to((cp1, cp2), end_point);
+            }
+        }
+
+        if path.current_point() != *edge_end {
+            path = path.line_to(*edge_end);
+        }
+    }
+
+    // Make sure the path ends exactly where it starts
+    path.close().build()
+}
@@ -0,0 +1,102 @@
+use crate::bezier::*;
+use crate::bezier::path::*;
+
+use std::f64;
+
+///
+/// Creates a superellipse, the shape defined by `|x/radius_x|^exponent + |y/radius_y|^exponent = 1`
+///
+/// An exponent of 2 produces an ellipse, and higher exponents produce progressively more rectangular shapes: an
+/// exponent of 4 produces the 'squircle' shape. Exponents between 0 and 1 produce shapes with concave sides that
+/// meet at points on the axes. The exponent must be greater than 0: an empty path is returned for other values.
+///
+/// There's no exact representation of these shapes as bezier curves, so each quarter of the shape is fitted to within
+/// `max_error`. The path starts at the top of the shape and goes clockwise.
+///
+pub fn superellipse<P>(center: P::Point, radius_x: f64, radius_y: f64, exponent: f64, max_error: f64) -> P
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate2D,
+{
+    const SAMPLES_PER_QUARTER: usize = 64;
+
+    // The shape is not defined for exponents of 0 or less
+    if !(exponent > 0.0 && exponent.is_finite()) {
+        return P::from_points(center, vec![]);
+    }
+
+    let power       = 2.0 / exponent;
+    let point_at    = |angle: f64| {
+        let (sin, cos) = angle.sin_cos();
+        let x = radius_x * sin.signum() * sin.abs().powf(power);
+        let y = radius_y * cos.signum() * cos.abs().powf(power);
+
+        P::Point::from_components(&[center.x() + x, center.y() + y])
+    };
+
+    // The quarters are fitted separately so that the points where they meet are preserved
+    let quarter     = f64::consts::PI/2.0;
+    let axis_points = [
+        P::Point::from_components(&[center.x(), center.y() + radius_y]),
+        P::Point::from_components(&[center.x() + radius_x, center.y()]),
+        P::Point::from_components(&[center.x(), center.y() - radius_y]),
+        P::Point::from_components(&[center.x() - radius_x, center.y()]),
+    ];
+    let quadrants   = [(1.0, 1.0), (1.0, -1.0), (-1.0, -1.0), (-1.0, 1.0)];
+    let mut points  = vec![];
+
+    for quarter_num in 0..4 {
+        let start_angle     = quarter * (quarter_num as f64);
+        let start_point     = axis_points[quarter_num];
+        let end_point       = axis_points[(quarter_num+1) % 4];
+
+        let samples         = (0..=SAMPLES_PER_QUARTER)
+            .map(|sample_num| {
+                if sample_num == 0 { 
+                    start_point 
+                } else if sample_num == SAMPLES_PER_QUARTER { 
+                    end_point 
+                } else { 
+                    point_at(start_angle + quarter * (sample_num as f64) / (SAMPLES_PER_QUARTER as f64)) 
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Shapes with small exponents can be thinner than max_error, so the control points are kept in the quadrant of the quarter
+        // to stop the fitted curves from crossing over each other
+        let (sign_x, sign_y)    = quadrants[quarter_num];
+        let (sign_x, sign_y)    = (sign_x * radius_x.signum(), sign_y * radius_y.signum());
+        let in_quadrant         = |point: P::Point| {
+            let x = center.x() + sign_x * (sign_x * (point.x() - center.x())).max(0.0);
+            let y = center.y() + sign_y * (sign_y * (point.y() - center.y())).max(0.0);
+
+            P::Point::from_components(&[x, y])
+        };
+
+        if let Some(curves) = fit_curve::<Curve<_>>(&samples, max_error) {
+            points.extend(curves.into_iter().map(|curve| {
+                let (cp1, cp2) = curve.control_points();
+                (in_quadrant(cp1), in_quadrant(cp2), in_quadrant(curve.end_point()))
+            }));
+        } else {
+            // Fall back to a series of lines if the curve fitting fails
+            let mut last_point = start_point;
+
+            points.extend(samples.into_iter().skip(1).map(|point| {
+                let offset  = point - last_point;
+                let cp1     = last_point + offset*(1.0/3.0);
+                let cp2     = last_point + offset*(2.0/3.0);
+                last_point  = point;
+
+                (cp1, cp2, point)
+            }));
+        }
+
+        // Make sure that each quarter ends exactly at the axis
+        if let Some((_, _, last_point)) = points.last_mut() {
+            *last_point = end_point;
+        }
+    }
+
+    P::from_points(axis_points[0], points)
+}
@@ -3,3 +3,4 @@
 extern crate flo_curves;
 mod bezier;
 mod line;
+mod shape;
@@ -0,0 +1,185 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+use flo_curves::bezier::path::algorithms::*;
+use crate::bezier::line;
+
+#[test]
+fn gap_between_two_ends() {
+    let strokes = vec![line(Coord2(0.0, 0.0), Coord2(100.0, 0.0)), line(Coord2(103.0, 3.0), Coord2(103.0, 100.0))];
+    let gaps    = find_stroke_gaps(&strokes, 5.0);
+
+    assert!(gaps.len() == 1, "{:?}", gaps);
+    assert!(gaps[0].from == StrokePosition::End(0));
+    assert!(gaps[0].to == StrokePosition::Start(1));
+    assert!(gaps[0].bridge.0.distance_to(&Coord2(100.0, 0.0)) < 0.001);
+    assert!(gaps[0].bridge.1.distance_to(&Coord2(103.0, 3.0)) < 0.001);
+}
+
+#[test]
+fn gap_between_end_and_middle_of_stroke() {
+    let strokes = vec![line(Coord2(0.0, 0.0), Coord2(100.0, 0.0)), line(Coord2(50.0, 3.0), Coord2(50.0, 100.0))];
+    let gaps    = find_stroke_gaps(&strokes, 5.0);
+
+    assert!(gaps.len() == 1, "{:?}", gaps);
+    assert!(gaps[0].from == StrokePosition::Start(1));
+
+    match gaps[0].to {
+        StrokePosition::Curve { path_index, curve_index, curve_t } => {
+            assert!(path_index == 0);
+            assert!(curve_index == 0);
+            assert!((curve_t - 0.5).abs() < 0.01);
+        }
+
+        _ => assert!(false, "{:?}", gaps)
+    }
+
+    assert!(gaps[0].bridge.1.distance_to(&Coord2(50.0, 0.0)) < 0.01);
+}
+
+#[test]
+fn no_gaps_when_strokes_touch_or_are_far_apart() {
+    let strokes = vec![
+        line(Coord2(0.0, 0.0), Coord2(100.0, 0.0)), 
+        line(Coord2(100.0, 0.0), Coord2(100.0, 100.0)),
+        line(Coord2(50.0, 0.0), Coord2(50.0, 50.0)),
+        line(Coord2(0.0, 20.0), Coord2(0.0, 100.0)),
+    ];
+
+    assert!(find_stroke_gaps(&strokes, 5.0).is_empty());
+}
+
+#[test]
+fn gap_in_nearly_closed_stroke() {
+    // Circle with a small gap at the top
+    let circle  = Circle::new(Coord2(0.0, 0.0), 50.0);
+    let arc     = circle.arc(0.05, 2.0*std::f64::consts::PI - 0.05);
+    let stroke  = SimpleBezierPath::from_connected_curves(arc.to_bezier_curves::<Curve<_>>(0.01));
+    let gaps    = find_stroke_gaps(&[stroke], 10.0);
+
+    assert!(gaps.len() == 1, "{:?}", gaps);
+    assert!(gaps[0].from == StrokePosition::Start(0));
+    assert!(gaps[0].to == StrokePosition::End(0));
+}
+
+#[test]
+fn short_stroke_does_not_close_itself() {
+    let circle  = Circle::new(Coord2(0.0, 0.0), 1.0);
+    let arc     = circle.arc(0.1, 2.0*std::f64::consts::PI - 0.1);
+    let stroke  = SimpleBezierPath::from_connected_curves(arc.to_bezier_curves::<Curve<_>>(0.01));
+
+    assert!(find_stroke_gaps(&[stroke], 5.0).is_empty());
+}
+
+#[test]
+fn gap_to_closed_stroke() {
+    let strokes = vec![
+        Circle::new(Coord2(0.0, 0.0), 50.0).to_path::<SimpleBezierPath>(),
+        line(Coord2(0.0, 52.0), Coord2(0.0, 100.0)),
+    ];
+    let gaps    = find_stroke_gaps(&strokes, 5.0);
+
+    assert!(gaps.len() == 1, "{:?}", gaps);
+    assert!(gaps[0].from == StrokePosition::Start(1));
+    assert!(gaps[0].bridge.1.distance_to(&Coord2(0.0, 50.0)) < 0.1);
+    assert!(matches!(gaps[0].to, StrokePosition::Curve { path_index: 0, .. }));
+}
+
+#[test]
+fn close_gaps_adds_bridging_strokes() {
+    let strokes = vec![line(Coord2(0.0, 0.0), Coord2(100.0, 0.0)), line(Coord2(103.0, 3.0), Coord2(103.0, 100.0))];
+    let closed  = close_stroke_gaps::<_, SimpleBezierPath>(&strokes, 5.0);
+
+    assert!(closed.len() == 3);
+    assert!(closed[2].start_point().distance_to(&Coord2(100.0, 0.0)) < 0.001);
+    assert!(closed[2].points().last().unwrap().2.distance_to(&Coord2(103.0, 3.0)) < 0.001);
+}
+
+#[test]
+fn fill_square_with_gaps_at_corners() {
+    let strokes = vec![
+        line(Coord2(0.0, 0.0), Coord2(97.0, 0.0)),
+        line(Coord2(100.0, 3.0), Coord2(100.0, 97.0)),
+        line(Coord2(97.0, 100.0), Coord2(3.0, 100.0)),
+        line(Coord2(0.0, 97.0), Coord2(0.0, 3.0)),
+    ];
+
+    assert!(find_stroke_gaps(&strokes, 5.0).len() == 4);
+
+    let filled = flood_fill_closing_gaps::<_, SimpleBezierPath>(Coord2(40.0, 60.0), &FillSettings::default().with_min_gap(None), &strokes, 5.0).unwrap();
+    assert!(filled.len() == 1);
+
+    let bounds = filled[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 3.0, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(100.0, 100.0)) < 3.0, "{:?}", bounds);
+
+    assert!(path_contains_point(&filled[0], &Coord2(50.0, 50.0)));
+    assert!(path_contains_point(&filled[0], &Coord2(95.0, 95.0)));
+    assert!(!path_contains_point(&filled[0], &Coord2(110.0, 50.0)));
+}
+
+#[test]
+fn regions_of_square_with_gaps_at_corners() {
+    let strokes = vec![
+        line(Coord2(0.0, 0.0), Coord2(97.0, 0.0)),
+        line(Coord2(100.0, 3.0), Coord2(100.0, 97.0)),
+        line(Coord2(97.0, 100.0), Coord2(3.0, 100.0)),
+        line(Coord2(0.0, 97.0), Coord2(0.0, 3.0)),
+    ];
+
+    let regions = stroke_regions_closing_gaps::<_, SimpleBezierPath>(&strokes, 5.0, 0.01);
+    assert!(regions.len() == 1, "{:?}", regions.len());
+    assert!(regions[0].paths.len() == 1);
+
+    // The region is bounded by all of the strokes and the 4 bridges (3 of which cut off a corner)
+    let area = path_signed_area(&regions[0].paths[0]).abs();
+    assert!((area - (10000.0 - 3.0*4.5)).abs() < 0.1, "{}", area);
+    assert!((0..8).all(|stroke_index| regions[0].labels.contains(&stroke_index)), "{:?}", regions[0].labels);
+
+    assert!(path_contains_point(&regions[0].paths[0], &Coord2(50.0, 50.0)));
+    assert!(path_contains_point(&regions[0].paths[0], &Coord2(95.0, 95.0)));
+    assert!(!path_contains_point(&regions[0].paths[0], &Coord2(110.0, 50.0)));
+}
+
+#[test]
+fn regions_divided_by_stroke_with_gap() {
+    // Square with a line across the middle that touches the top edge and stops short of the bottom edge
+    let strokes = vec![
+        line(Coord2(0.0, 0.0), Coord2(100.0, 0.0)),
+        line(Coord2(100.0, 0.0), Coord2(100.0, 100.0)),
+        line(Coord2(100.0, 100.0), Coord2(0.0, 100.0)),
+        line(Coord2(0.0, 100.0), Coord2(0.0, 0.0)),
+        line(Coord2(50.0, 3.0), Coord2(50.0, 100.0)),
+    ];
+
+    let regions = stroke_regions_closing_gaps::<_, SimpleBezierPath>(&strokes, 5.0, 0.01);
+    assert!(regions.len() == 2, "{:?}", regions.len());
+
+    let left    = regions.iter().position(|region| path_contains_point(&region.paths[0], &Coord2(25.0, 50.0))).unwrap();
+    let right   = regions.iter().position(|region| path_contains_point(&region.paths[0], &Coord2(75.0, 50.0))).unwrap();
+
+    assert!(left != right);
+    assert!(regions[left].adjacent_faces == vec![right]);
+    assert!(regions[left].labels.contains(&5) && regions[right].labels.contains(&5));
+
+    for region in regions.iter() {
+        assert!((path_signed_area(&region.paths[0]).abs() - 5000.0).abs() < 0.1);
+    }
+}
+
+#[test]
+fn overshooting_strokes_form_one_region() {
+    // Strokes that cross over at the corners of a square
+    let strokes = vec![
+        line(Coord2(-10.0, 0.0), Coord2(110.0, 0.0)),
+        line(Coord2(100.0, -10.0), Coord2(100.0, 110.0)),
+        line(Coord2(110.0, 100.0), Coord2(-10.0, 100.0)),
+        line(Coord2(0.0, 110.0), Coord2(0.0, -10.0)),
+    ];
+
+    let regions = stroke_regions_closing_gaps::<_, SimpleBezierPath>(&strokes, 5.0, 0.01);
+    assert!(regions.len() == 1, "{:?}", regions.len());
+    assert!((path_signed_area(&regions[0].paths[0]).abs() - 10000.0).abs() < 0.1);
+    assert!(regions[0].labels.iter().all(|stroke_index| *stroke_index < 4));
+}
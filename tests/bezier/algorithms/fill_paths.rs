@@ -0,0 +1,128 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+use flo_curves::bezier::path::algorithms::*;
+use crate::bezier::line;
+
+#[test]
+fn ray_hits_rectangle() {
+    let rectangle   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 50.0))
+        .line_to(Coord2(0.0, 50.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let index       = PathRayIndex::new(vec![rectangle].iter());
+
+    let collisions  = index.cast_ray(Coord2(50.0, 25.0), Coord2(51.0, 25.0));
+    assert!(collisions.len() == 1, "{:?}", collisions.len());
+    assert!(collisions[0].position.distance_to(&Coord2(100.0, 25.0)) < 0.01);
+    assert!(collisions[0].what.path_index == 0);
+    assert!(collisions[0].what.curve_index == 1);
+    assert!((collisions[0].what.curve_t - 0.5).abs() < 0.01);
+
+    let collisions  = index.cast_ray(Coord2(-10.0, 25.0), Coord2(-9.0, 25.0));
+    assert!(collisions.len() == 2);
+
+    let collisions  = index.cast_ray(Coord2(-10.0, 25.0), Coord2(-11.0, 25.0));
+    assert!(collisions.is_empty());
+}
+
+#[test]
+fn ray_index_matches_path_intersections() {
+    // Grid of circles, so the ray index has many curves to choose from
+    let circles = (0..10).flat_map(|x| (0..10).map(move |y| Circle::new(Coord2(x as f64 * 30.0, y as f64 * 30.0), 10.0).to_path::<SimpleBezierPath>()))
+        .collect::<Vec<_>>();
+    let index   = PathRayIndex::new(circles.iter());
+
+    for (from, to) in vec![(Coord2(-20.0, 5.0), Coord2(0.0, 5.0)), (Coord2(-20.0, -20.0), Coord2(0.0, 1.0)), (Coord2(122.0, 135.0), Coord2(122.0, 200.0)), (Coord2(150.0, 150.0), Coord2(149.0, 149.5))] {
+        let direction       = to - from;
+        let mut expected    = circles.iter()
+            .flat_map(|circle| circle.to_curves::<Curve<_>>())
+            .flat_map(|curve| curve_intersects_ray(&curve, &(from, to)).into_iter().filter(|(_, line_t, _)| *line_t >= 0.0).map(|(_, line_t, _)| from + direction*line_t).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut actual      = index.cast_ray(from, to).into_iter().map(|collision| collision.position).collect::<Vec<_>>();
+
+        assert!(!actual.is_empty());
+        assert!(actual.len() == expected.len(), "{:?} {:?}", actual, expected);
+
+        expected.sort_by(|a, b| a.distance_to(&from).total_cmp(&b.distance_to(&from)));
+        actual.sort_by(|a, b| a.distance_to(&from).total_cmp(&b.distance_to(&from)));
+
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!(actual.distance_to(expected) < 0.01);
+        }
+    }
+}
+
+#[test]
+fn empty_ray_index() {
+    let index = PathRayIndex::<Coord2>::new(Vec::<SimpleBezierPath>::new().iter());
+
+    assert!(index.cast_ray(Coord2(0.0, 0.0), Coord2(1.0, 0.0)).is_empty());
+}
+
+#[test]
+fn fill_circle_path() {
+    let circle  = Circle::new(Coord2(10.0, 10.0), 50.0).to_path::<SimpleBezierPath>();
+    let filled  = flood_fill_concave_paths::<_, SimpleBezierPath>(Coord2(20.0, 5.0), &FillSettings::default(), &[circle]).unwrap();
+
+    assert!(filled.len() == 1);
+
+    for curve in filled[0].to_curves::<Curve<Coord2>>() {
+        for t in 0..100 {
+            let t           = (t as f64)/100.0;
+            let distance    = Coord2(10.0, 10.0).distance_to(&curve.point_at_pos(t));
+
+            assert!((distance-50.0).abs() < 1.0);
+        }
+    }
+}
+
+#[test]
+fn fill_region_enclosed_by_open_lines() {
+    // Four separate lines that cross at the corners of a square
+    let lines = vec![
+        line(Coord2(-10.0, 0.0), Coord2(110.0, 0.0)),
+        line(Coord2(100.0, -10.0), Coord2(100.0, 110.0)),
+        line(Coord2(110.0, 100.0), Coord2(-10.0, 100.0)),
+        line(Coord2(0.0, 110.0), Coord2(0.0, -10.0)),
+    ];
+
+    let filled = flood_fill_concave_paths::<_, SimpleBezierPath>(Coord2(30.0, 60.0), &FillSettings::default(), &lines).unwrap();
+    assert!(filled.len() == 1);
+
+    let bounds = filled[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 2.0, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(100.0, 100.0)) < 2.0, "{:?}", bounds);
+
+    assert!(path_contains_point(&filled[0], &Coord2(50.0, 50.0)));
+    assert!(path_contains_point(&filled[0], &Coord2(5.0, 95.0)));
+    assert!(!path_contains_point(&filled[0], &Coord2(105.0, 50.0)));
+}
+
+#[test]
+fn fill_l_shaped_region() {
+    // L-shaped region: the corner can't be seen from the fill point
+    let outline = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(100.0, 0.0))
+        .line_to(Coord2(100.0, 30.0))
+        .line_to(Coord2(30.0, 30.0))
+        .line_to(Coord2(30.0, 100.0))
+        .line_to(Coord2(0.0, 100.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let filled = flood_fill_concave_paths::<_, SimpleBezierPath>(Coord2(80.0, 15.0), &FillSettings::default(), &[outline.clone()]).unwrap();
+    assert!(filled.len() == 1);
+
+    assert!(path_contains_point(&filled[0], &Coord2(15.0, 90.0)));
+    assert!(path_contains_point(&filled[0], &Coord2(90.0, 15.0)));
+    assert!(!path_contains_point(&filled[0], &Coord2(60.0, 60.0)));
+
+    // Convex fill can only see part of the region
+    let convex = flood_fill_convex_paths::<_, SimpleBezierPath>(Coord2(80.0, 15.0), &FillSettings::default(), &[outline]).unwrap();
+    assert!(path_contains_point(&convex, &Coord2(90.0, 15.0)));
+    assert!(!path_contains_point(&convex, &Coord2(15.0, 90.0)));
+}
@@ -1,2 +1,4 @@
 mod fill_convex;
 mod fill_concave;
+mod fill_paths;
+mod close_gaps;
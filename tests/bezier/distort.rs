@@ -1,5 +1,7 @@
 use flo_curves::geo::*;
 use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+use flo_curves::arc::*;
 
 #[test]
 fn line_to_sine_wave() {
@@ -23,3 +25,97 @@ fn line_to_sine_wave() {
         }
     }
 }
+
+#[test]
+fn square_along_straight_spine_is_unchanged() {
+    let square  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(10.0, 0.0))
+        .line_to(Coord2(20.0, 0.0))
+        .line_to(Coord2(20.0, 10.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(10.0, 0.0))
+        .build();
+    let spine   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(100.0, 50.0))
+        .line_to(Coord2(200.0, 50.0))
+        .build();
+
+    let mapped  = distort_along_path::<_, _, SimpleBezierPath>(&[square.clone()], &spine, 1.0, 0.1).expect("Mapped shapes");
+
+    assert!(mapped.len() == 1);
+
+    for curve in mapped[0].to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+
+            assert!(pos.x() >= 110.0-0.2 && pos.x() <= 120.0+0.2, "{:?}", pos);
+            assert!(pos.y() >= 50.0-0.2 && pos.y() <= 60.0+0.2, "{:?}", pos);
+        }
+    }
+
+    assert!(mapped[0].start_point().distance_to(&Coord2(110.0, 50.0)) < 0.1);
+}
+
+#[test]
+fn line_along_circle_spine_follows_offset_radius() {
+    // The circle is drawn anticlockwise, so the left normal points outwards
+    let circle  = Circle::new(Coord2(0.0, 0.0), 100.0).to_path::<SimpleBezierPath>();
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 10.0))
+        .line_to(Coord2(300.0, 10.0))
+        .build();
+
+    let mapped  = distort_along_path::<_, _, SimpleBezierPath>(&[line], &circle, 1.0, 0.1).expect("Mapped shapes");
+
+    for curve in mapped[0].to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos     = curve.point_at_pos((t as f64)/10.0);
+            let radius  = pos.distance_to(&Coord2(0.0, 0.0));
+
+            assert!((radius-110.0).abs() < 0.5, "{:?} {:?}", pos, radius);
+        }
+    }
+}
+
+#[test]
+fn shape_past_end_of_spine_extends_along_tangent() {
+    let spine   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(0.0, 100.0))
+        .build();
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-10.0, 0.0))
+        .line_to(Coord2(120.0, 0.0))
+        .build();
+
+    let mapped  = distort_along_path::<_, _, SimpleBezierPath>(&[line], &spine, 1.0, 0.1).expect("Mapped shapes");
+
+    assert!(mapped[0].start_point().distance_to(&Coord2(0.0, -10.0)) < 0.1);
+    assert!(mapped[0].to_curves::<Curve<_>>().last().unwrap().end_point().distance_to(&Coord2(0.0, 120.0)) < 0.1);
+}
+
+#[test]
+fn spine_with_control_points_on_end_points() {
+    // The tangent is 0 at the ends of this spine, and its speed varies along its length
+    let spine   = SimpleBezierPath::from_points(Coord2(0.0, 0.0), vec![(Coord2(0.0, 0.0), Coord2(100.0, 0.0), Coord2(100.0, 0.0))]);
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-10.0, 5.0))
+        .line_to(Coord2(110.0, 5.0))
+        .build();
+
+    let mapped  = distort_along_path::<_, _, SimpleBezierPath>(&[line], &spine, 1.0, 0.1).expect("Mapped shapes");
+
+    assert!(mapped[0].start_point().distance_to(&Coord2(-10.0, 5.0)) < 0.1, "{:?}", mapped[0].start_point());
+    assert!(mapped[0].to_curves::<Curve<_>>().last().unwrap().end_point().distance_to(&Coord2(110.0, 5.0)) < 0.1);
+
+    for curve in mapped[0].to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!((pos.y() - 5.0).abs() < 0.2, "{:?}", pos);
+        }
+    }
+}
+
+#[test]
+fn empty_spine_maps_nothing() {
+    let spine   = SimpleBezierPath::from_points(Coord2(0.0, 0.0), vec![]);
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .build();
+
+    assert!(distort_along_path::<_, _, SimpleBezierPath>(&[line], &spine, 1.0, 0.1).is_none());
+}
@@ -29,11 +29,31 @@ mod walk;
 mod distort;
 mod nearest_point_tests;
 mod flatness_tests;
+mod monotonic;
 
 pub fn approx_equal(a: f64, b: f64) -> bool {
     f64::floor(f64::abs(a-b)*10000.0) == 0.0
 }
 
+///
+/// Creates a path going around a rectangle from (x1, y1) to (x2, y2), via (x2, y1)
+///
+pub fn square(x1: f64, y1: f64, x2: f64, y2: f64) -> bezier::path::SimpleBezierPath {
+    bezier::path::BezierPathBuilder::<bezier::path::SimpleBezierPath>::start(Coord2(x1, y1))
+        .line_to(Coord2(x2, y1))
+        .line_to(Coord2(x2, y2))
+        .line_to(Coord2(x1, y2))
+        .line_to(Coord2(x1, y1))
+        .build()
+}
+
+///
+/// Creates a path containing a straight line
+///
+pub fn line(from: Coord2, to: Coord2) -> bezier::path::SimpleBezierPath {
+    bezier::path::BezierPathBuilder::<bezier::path::SimpleBezierPath>::start(from).line_to(to).build()
+}
+
 #[test]
 fn read_curve_control_points() {
     let curve = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 3.0), Coord2(4.0, 4.0)), Coord2(2.0, 2.0));
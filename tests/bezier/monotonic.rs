@@ -0,0 +1,105 @@
+use flo_curves::bezier::*;
+
+///
+/// Checks that a curve section is monotonic in both x and y by sampling it
+///
+fn is_monotonic<C: BezierCurve<Point=Coord2>>(curve: &C) -> bool {
+    let mut last_point  = curve.point_at_pos(0.0);
+    let mut x_direction = 0.0;
+    let mut y_direction = 0.0;
+
+    for t in 1..=100 {
+        let t       = (t as f64)/100.0;
+        let point   = curve.point_at_pos(t);
+        let dx      = point.x() - last_point.x();
+        let dy      = point.y() - last_point.y();
+
+        if dx.abs() > 1e-9 {
+            if x_direction != 0.0 && dx.signum() != x_direction { return false; }
+            x_direction = dx.signum();
+        }
+
+        if dy.abs() > 1e-9 {
+            if y_direction != 0.0 && dy.signum() != y_direction { return false; }
+            y_direction = dy.signum();
+        }
+
+        last_point = point;
+    }
+
+    true
+}
+
+#[test]
+fn monotonic_t_values_cover_curve() {
+    let curve       = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let t_values    = monotonic_t_values(&curve);
+
+    assert!(t_values[0] == 0.0);
+    assert!(t_values[t_values.len()-1] == 1.0);
+    assert!(t_values.windows(2).all(|t| t[0] < t[1]), "{:?}", t_values);
+}
+
+#[test]
+fn arch_splits_at_extremity() {
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(0.0, 10.0), Coord2(10.0, 10.0)), Coord2(10.0, 0.0));
+    let sections    = monotonic_sections(&curve);
+
+    assert!(sections.len() == 2);
+    assert!((sections[0].original_curve_t_values().1 - 0.5).abs() < 0.0001);
+    assert!(sections.iter().all(|section| is_monotonic(section)));
+}
+
+#[test]
+fn s_curve_splits_at_inflection_point() {
+    // A monotonic curve with an inflection point in the middle
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(0.0, 10.0), Coord2(10.0, 0.0)), Coord2(10.0, 10.0));
+    let sections    = monotonic_sections(&curve);
+
+    assert!(sections.len() == 2, "{:?}", sections.iter().map(|section| section.original_curve_t_values()).collect::<Vec<_>>());
+    assert!((sections[0].original_curve_t_values().1 - 0.5).abs() < 0.0001);
+
+    match curve.features(0.01) {
+        CurveFeatures::SingleInflectionPoint(t) => assert!((t-0.5).abs() < 0.0001),
+        other                                   => assert!(false, "{:?}", other)
+    }
+}
+
+#[test]
+fn loop_sections_are_monotonic() {
+    let curve       = Curve::from_points(Coord2(148.0, 151.0), (Coord2(292.0, 199.0), Coord2(73.0, 221.0)), Coord2(249.0, 136.0));
+    let sections    = monotonic_sections(&curve);
+
+    assert!(sections.len() > 2);
+    assert!(sections.iter().all(|section| is_monotonic(section)));
+
+    // Sections should join up
+    for (prev, next) in sections.iter().zip(sections.iter().skip(1)) {
+        assert!(prev.original_curve_t_values().1 == next.original_curve_t_values().0);
+        assert!(prev.end_point().distance_to(&next.start_point()) < 0.0001);
+    }
+}
+
+#[test]
+fn path_monotonic_curves_record_original_curve() {
+    use flo_curves::arc::*;
+    use flo_curves::bezier::path::*;
+
+    let circle          = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let num_curves      = circle.1.len();
+    let monotonic       = path_to_monotonic_curves::<_, Curve<_>>(&circle);
+    let original_curves = circle.to_curves::<Curve<_>>();
+
+    assert!(monotonic.len() >= num_curves);
+    assert!(monotonic.iter().all(|section| is_monotonic(&section.curve)));
+
+    for section in monotonic.iter() {
+        let original = &original_curves[section.curve_idx];
+
+        assert!(original.point_at_pos(section.t_range.0).distance_to(&section.curve.start_point()) < 0.0001);
+        assert!(original.point_at_pos(section.t_range.1).distance_to(&section.curve.end_point()) < 0.0001);
+    }
+
+    assert!(monotonic[0].curve_idx == 0);
+    assert!(monotonic[monotonic.len()-1].curve_idx == num_curves-1);
+}
@@ -0,0 +1,123 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+fn rectangle(x1: f64, y1: f64, x2: f64, y2: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(x1, y1))
+        .line_to(Coord2(x2, y1))
+        .line_to(Coord2(x2, y2))
+        .line_to(Coord2(x1, y2))
+        .line_to(Coord2(x1, y1))
+        .build()
+}
+
+#[test]
+fn area_of_anticlockwise_rectangle() {
+    let rectangle = rectangle(1.0, 1.0, 5.0, 3.0);
+
+    assert!(!rectangle.is_clockwise());
+    assert!((path_signed_area(&rectangle) - 8.0).abs() < 1e-10);
+}
+
+#[test]
+fn area_of_clockwise_rectangle_is_negative() {
+    let rectangle = rectangle(1.0, 1.0, 5.0, 3.0).reversed::<SimpleBezierPath>();
+
+    assert!(rectangle.is_clockwise());
+    assert!((path_signed_area(&rectangle) + 8.0).abs() < 1e-10);
+}
+
+#[test]
+fn unclosed_path_is_closed_with_a_line() {
+    let triangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(4.0, 0.0))
+        .line_to(Coord2(0.0, 3.0))
+        .build();
+
+    assert!((path_signed_area(&triangle) - 6.0).abs() < 1e-10);
+}
+
+#[test]
+fn area_of_circle() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let area    = path_signed_area(&circle).abs();
+
+    // Bezier curves approximate a circle, so this is accurate to the precision of the approximation
+    assert!((area - f64::consts::PI*16.0).abs() < 0.05, "{}", area);
+}
+
+#[test]
+fn centroid_of_rectangle() {
+    let rectangle   = rectangle(1.0, 1.0, 5.0, 3.0);
+    let centroid    = path_centroid(&rectangle).unwrap();
+
+    assert!(centroid.distance_to(&Coord2(3.0, 2.0)) < 1e-10);
+}
+
+#[test]
+fn centroid_of_curved_shape() {
+    // Symmetric shape made of curves: centroid must lie on the line of symmetry
+    let shape = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to((Coord2(3.0, -2.0), Coord2(7.0, -2.0)), Coord2(10.0, 0.0))
+        .curve_to((Coord2(12.0, 8.0), Coord2(-2.0, 8.0)), Coord2(0.0, 0.0))
+        .build();
+    let centroid = path_centroid(&shape).unwrap();
+
+    assert!((centroid.x() - 5.0).abs() < 1e-10, "{:?}", centroid);
+    assert!(centroid.y() > 0.0);
+}
+
+#[test]
+fn second_moments_of_rectangle() {
+    // 4x2 rectangle centered on 3,2
+    let moments             = path_moments(&rectangle(1.0, 1.0, 5.0, 3.0));
+    let (xx, yy, xy)        = moments.central_moments();
+
+    // b*h^3/12 about each axis
+    assert!((xx - 2.0*4.0*4.0*4.0/12.0).abs() < 1e-10, "{}", xx);
+    assert!((yy - 4.0*2.0*2.0*2.0/12.0).abs() < 1e-10, "{}", yy);
+    assert!(xy.abs() < 1e-10, "{}", xy);
+
+    let tensor = moments.inertia_tensor();
+    assert!((tensor[0][0] - yy).abs() < 1e-10);
+    assert!((tensor[1][1] - xx).abs() < 1e-10);
+}
+
+#[test]
+fn principal_axes_of_rotated_rectangle() {
+    let angle       = f64::consts::PI / 6.0;
+    let rotate      = |p: Coord2| Coord2(p.x()*angle.cos() - p.y()*angle.sin(), p.x()*angle.sin() + p.y()*angle.cos());
+    let rectangle   = rectangle(-2.0, -1.0, 2.0, 1.0).map_points::<SimpleBezierPath>(rotate);
+    let axes        = path_moments(&rectangle).principal_axes();
+
+    assert!((axes.angle - angle).abs() < 1e-10, "{:?}", axes);
+    assert!((axes.min_moment - 4.0*2.0*2.0*2.0/12.0).abs() < 1e-10, "{:?}", axes);
+    assert!((axes.max_moment - 2.0*4.0*4.0*4.0/12.0).abs() < 1e-10, "{:?}", axes);
+}
+
+#[test]
+fn area_of_rectangle_with_hole() {
+    // Both paths go in the same direction: the inner one is a hole because it's inside the outer one
+    let outer   = rectangle(0.0, 0.0, 10.0, 10.0);
+    let inner   = rectangle(2.0, 2.0, 4.0, 4.0);
+    let moments = paths_moments(&vec![outer, inner]);
+
+    assert!((moments.area - 96.0).abs() < 1e-10, "{:?}", moments);
+
+    // The hole moves the centroid away from it
+    let centroid = moments.centroid().unwrap();
+    assert!((centroid.x() - (100.0*5.0 - 4.0*3.0)/96.0).abs() < 1e-10, "{:?}", centroid);
+    assert!((centroid.y() - (100.0*5.0 - 4.0*3.0)/96.0).abs() < 1e-10, "{:?}", centroid);
+}
+
+#[test]
+fn area_of_nested_islands() {
+    // Island inside a hole inside a rectangle
+    let outer   = rectangle(0.0, 0.0, 10.0, 10.0);
+    let hole    = rectangle(2.0, 2.0, 8.0, 8.0).reversed::<SimpleBezierPath>();
+    let island  = rectangle(4.0, 4.0, 6.0, 6.0);
+
+    assert!((paths_area(&vec![outer, hole, island]) - (100.0 - 36.0 + 4.0)).abs() < 1e-10);
+}
@@ -1,4 +1,5 @@
 use flo_curves::*;
+use flo_curves::arc::*;
 use flo_curves::bezier::path::*;
 
 #[test]
@@ -100,3 +101,153 @@ fn cut_square_center() {
     assert!(cut_square.exterior_path[0].points().len() == 4);
     assert!(cut_square.exterior_path[1].points().len() == 4);
 }
+
+#[test]
+fn cut_open_line_through_circle() {
+    let circle      = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 5.0)).line_to(Coord2(10.0, 5.0)).build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&line, &vec![circle], 0.01);
+
+    assert!(cut.interior_sections.len() == 1);
+    assert!(cut.exterior_sections.len() == 2);
+
+    // Interior section runs from one side of the circle to the other and is not closed
+    let interior    = &cut.interior_sections[0];
+    assert!(interior.path.start_point().distance_to(&Coord2(1.0, 5.0)) < 0.01, "{:?}", interior.path.start_point());
+    assert!(interior.path.points().last().unwrap().2.distance_to(&Coord2(9.0, 5.0)) < 0.01);
+    assert!(interior.path.points().count() == 1);
+
+    // T values are on the original line
+    assert!(interior.start.0 == 0 && (interior.start.1 - 0.1).abs() < 0.01, "{:?}", interior.start);
+    assert!(interior.end.0 == 0 && (interior.end.1 - 0.9).abs() < 0.01, "{:?}", interior.end);
+
+    assert!(cut.exterior_sections[0].start == (0, 0.0));
+    assert!(cut.exterior_sections[1].end == (0, 1.0));
+}
+
+#[test]
+fn cut_open_path_joins_curves() {
+    // Path with several curves, all inside the square apart from the last one
+    let square      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let stroke      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(2.0, 2.0))
+        .line_to(Coord2(8.0, 2.0))
+        .curve_to((Coord2(9.0, 4.0), Coord2(9.0, 6.0)), Coord2(8.0, 8.0))
+        .line_to(Coord2(12.0, 8.0))
+        .build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&stroke, &vec![square], 0.01);
+
+    assert!(cut.interior_sections.len() == 1);
+    assert!(cut.exterior_sections.len() == 1);
+
+    let interior    = &cut.interior_sections[0];
+    assert!(interior.path.points().count() == 3);
+    assert!(interior.start == (0, 0.0));
+    assert!(interior.end.0 == 2 && (interior.end.1 - 0.5).abs() < 0.01, "{:?}", interior.end);
+
+    let exterior    = &cut.exterior_sections[0];
+    assert!(exterior.start.0 == 2 && (exterior.start.1 - 0.5).abs() < 0.01);
+    assert!(exterior.end == (2, 1.0));
+    assert!(exterior.path.start_point().distance_to(&Coord2(10.0, 8.0)) < 0.01);
+}
+
+#[test]
+fn cut_open_line_through_doughnut() {
+    let outer       = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner       = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 5.0)).line_to(Coord2(10.0, 5.0)).build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&line, &vec![outer, inner], 0.01);
+
+    // Two sections inside the ring, and the outside sections plus the hole in the middle
+    assert!(cut.interior_sections.len() == 2);
+    assert!(cut.exterior_sections.len() == 3);
+    assert!(cut.exterior_sections[1].path.start_point().distance_to(&Coord2(3.0, 5.0)) < 0.01);
+}
+
+#[test]
+fn cut_open_line_outside_region() {
+    let circle      = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 20.0)).line_to(Coord2(10.0, 20.0)).build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&line, &vec![circle], 0.01);
+
+    assert!(cut.interior_sections.is_empty());
+    assert!(cut.exterior_sections.len() == 1);
+    assert!(cut.exterior_sections[0].start == (0, 0.0));
+    assert!(cut.exterior_sections[0].end == (0, 1.0));
+}
+
+#[test]
+fn cut_open_line_along_edge_of_region() {
+    let square      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-5.0, 0.0)).line_to(Coord2(15.0, 0.0)).build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&line, &vec![square], 0.01);
+
+    // The part of the line that runs along the bottom of the square is inside the region
+    assert!(cut.interior_sections.len() == 1, "{:?}", cut);
+    assert!(cut.exterior_sections.len() == 2, "{:?}", cut);
+
+    let interior    = &cut.interior_sections[0];
+    assert!(interior.path.start_point().distance_to(&Coord2(0.0, 0.0)) < 0.01);
+    assert!(interior.path.points().last().unwrap().2.distance_to(&Coord2(10.0, 0.0)) < 0.01);
+    assert!((interior.start.1 - 0.25).abs() < 0.01, "{:?}", interior.start);
+    assert!((interior.end.1 - 0.75).abs() < 0.01, "{:?}", interior.end);
+}
+
+#[test]
+fn cut_open_line_touching_corner() {
+    let square      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, 15.0)).line_to(Coord2(15.0, 5.0)).build();
+
+    let cut         = path_cut_open::<SimpleBezierPath>(&line, &vec![square], 0.01);
+
+    // Line touches the corner of the square without going inside it
+    assert!(cut.interior_sections.is_empty(), "{:?}", cut);
+    assert!(cut.exterior_sections.iter().all(|section| section.path.points().count() >= 1));
+    assert!(cut.exterior_sections[0].start == (0, 0.0));
+    assert!(cut.exterior_sections.last().unwrap().end == (0, 1.0));
+}
+
+#[test]
+fn cut_open_line_with_fill_rule() {
+    // Two overlapping squares, so the middle has a winding number of 2
+    let square1     = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let square2     = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, 0.0))
+        .line_to(Coord2(15.0, 0.0))
+        .line_to(Coord2(15.0, 10.0))
+        .line_to(Coord2(5.0, 10.0))
+        .line_to(Coord2(5.0, 0.0))
+        .build();
+    let line        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-5.0, 5.0)).line_to(Coord2(20.0, 5.0)).build();
+
+    let even_odd    = path_cut_open_with_fill_rule::<SimpleBezierPath>(&line, &vec![square1.clone(), square2.clone()], FillRule::EvenOdd, 0.01);
+    let non_zero    = path_cut_open_with_fill_rule::<SimpleBezierPath>(&line, &vec![square1, square2], FillRule::NonZero, 0.01);
+
+    assert!(even_odd.interior_sections.len() == 2, "{:?}", even_odd);
+    assert!(non_zero.interior_sections.len() == 1, "{:?}", non_zero);
+    assert!(non_zero.interior_sections[0].path.start_point().distance_to(&Coord2(0.0, 5.0)) < 0.01);
+    assert!(non_zero.interior_sections[0].path.points().last().unwrap().2.distance_to(&Coord2(15.0, 5.0)) < 0.01);
+}
@@ -0,0 +1,110 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+use crate::bezier::square;
+
+fn total_area(paths: &Vec<SimpleBezierPath>) -> f64 {
+    // The pieces of the result can touch at their corners, so add up their areas rather than checking how they're nested
+    paths.iter().map(|path| path_signed_area(path).abs()).sum()
+}
+
+fn is_inside(paths: &Vec<SimpleBezierPath>, point: Coord2) -> bool {
+    path_winding_number(paths, &point) & 1 != 0
+}
+
+#[test]
+fn xor_overlapping_squares() {
+    let square1 = square(1.0, 1.0, 5.0, 5.0);
+    let square2 = square(3.0, 3.0, 7.0, 7.0);
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![square1], &vec![square2], 0.01);
+
+    // Both squares are 16 units, they overlap by 4 units
+    assert!((total_area(&xored) - (16.0 + 16.0 - 8.0)).abs() < 0.01, "{:?}", xored);
+
+    assert!(is_inside(&xored, Coord2(2.0, 2.0)));
+    assert!(is_inside(&xored, Coord2(6.0, 6.0)));
+    assert!(!is_inside(&xored, Coord2(4.0, 4.0)));
+    assert!(!is_inside(&xored, Coord2(6.0, 2.0)));
+}
+
+#[test]
+fn xor_overlapping_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(7.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![circle1.clone()], &vec![circle2.clone()], 0.01);
+    let added   = path_add::<SimpleBezierPath>(&vec![circle1.clone()], &vec![circle2.clone()], 0.01);
+    let inter   = path_intersect::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+
+    // The xor is the union with the intersection removed
+    assert!((paths_area(&xored) - (paths_area(&added) - paths_area(&inter))).abs() < 0.1);
+
+    assert!(is_inside(&xored, Coord2(2.0, 5.0)));
+    assert!(is_inside(&xored, Coord2(10.0, 5.0)));
+    assert!(!is_inside(&xored, Coord2(6.0, 5.0)));
+}
+
+#[test]
+fn xor_separate_squares() {
+    let square1 = square(1.0, 1.0, 3.0, 3.0);
+    let square2 = square(5.0, 5.0, 7.0, 7.0);
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![square1], &vec![square2], 0.01);
+
+    assert!(xored.len() == 2);
+    assert!((paths_area(&xored) - 8.0).abs() < 0.01);
+}
+
+#[test]
+fn xor_square_inside_square() {
+    let outer   = square(1.0, 1.0, 9.0, 9.0);
+    let inner   = square(3.0, 3.0, 7.0, 7.0);
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![outer], &vec![inner], 0.01);
+
+    assert!(xored.len() == 2);
+    assert!((paths_area(&xored) - (64.0 - 16.0)).abs() < 0.01);
+    assert!(!is_inside(&xored, Coord2(5.0, 5.0)));
+    assert!(is_inside(&xored, Coord2(2.0, 2.0)));
+}
+
+#[test]
+fn xor_with_empty_path() {
+    let square1 = square(1.0, 1.0, 5.0, 5.0);
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![square1], &Vec::<SimpleBezierPath>::new(), 0.01);
+
+    assert!(xored.len() == 1);
+    assert!((paths_area(&xored) - 16.0).abs() < 0.01);
+}
+
+#[test]
+fn xor_with_non_zero_fill_rule() {
+    // Nested squares in the same direction: the whole outer square is filled with the non-zero rule
+    let nested  = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)];
+    let other   = vec![square(4.0, 0.0, 6.0, 10.0)];
+
+    let xored   = path_xor_with_fill_rule::<SimpleBezierPath>(&nested, &other, FillRule::NonZero, 0.01);
+
+    assert!((total_area(&xored) - (64.0 + 20.0 - 32.0)).abs() < 0.01, "{:?}", xored);
+    assert!(!is_inside(&xored, Coord2(5.0, 5.0)));
+    assert!(is_inside(&xored, Coord2(2.0, 5.0)));
+    assert!(is_inside(&xored, Coord2(5.0, 0.5)));
+}
+
+#[test]
+fn combine_xor() {
+    let square1 = square(1.0, 1.0, 5.0, 5.0);
+    let square2 = square(3.0, 3.0, 7.0, 7.0);
+    let square3 = square(2.0, 2.0, 6.0, 6.0);
+
+    let xored   = path_combine::<SimpleBezierPath>(PathCombine::Xor(vec![PathCombine::Path(vec![square1]), PathCombine::Path(vec![square2]), PathCombine::Path(vec![square3])]), 0.01);
+
+    // Points covered by 1 or 3 of the squares
+    assert!(is_inside(&xored, Coord2(1.5, 1.5)));
+    assert!(!is_inside(&xored, Coord2(2.5, 2.5)));
+    assert!(is_inside(&xored, Coord2(4.0, 4.0)));
+    assert!(!is_inside(&xored, Coord2(5.5, 5.5)));
+    assert!(is_inside(&xored, Coord2(6.5, 6.5)));
+}
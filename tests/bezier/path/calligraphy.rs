@@ -0,0 +1,144 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+use crate::bezier::line;
+
+use std::f64;
+
+fn square_nib(size: f64) -> PenNib<Coord2> {
+    PenNib::Polygon(vec![Coord2(-size, -size), Coord2(size, -size), Coord2(size, size), Coord2(-size, size)])
+}
+
+#[test]
+fn square_nib_along_line() {
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &square_nib(1.0), &StrokeOptions::default());
+
+    assert!(stroked.len() == 1, "{:?}", stroked);
+
+    let bounds = stroked[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-1.0, -1.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(11.0, 1.0)) < 0.01, "{:?}", bounds);
+    assert!((paths_area(&stroked) - 24.0).abs() < 0.01, "{}", paths_area(&stroked));
+}
+
+#[test]
+fn square_nib_along_diagonal_line() {
+    // Moving diagonally, the stroke is as wide as the diagonal of the nib
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 10.0)), &square_nib(1.0), &StrokeOptions::default());
+
+    // Area is the two squares at the ends plus the hexagonal region they sweep out
+    assert!((paths_area(&stroked) - (4.0 + 10.0 * 2.0 * 2.0)).abs() < 0.01, "{}", paths_area(&stroked));
+    assert!(path_contains_point(&stroked[0], &Coord2(5.0 + 0.95, 5.0 - 0.95)));
+    assert!(!path_contains_point(&stroked[0], &Coord2(5.0 + 1.05, 5.0 - 1.05)));
+}
+
+#[test]
+fn elliptical_nib_along_line() {
+    let nib     = PenNib::Ellipse { radius_x: 2.0, radius_y: 1.0, angle: 0.0 };
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &nib, &StrokeOptions::default().with_accuracy(0.01));
+
+    assert!(stroked.len() == 1, "{:?}", stroked);
+
+    let bounds = stroked[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-2.0, -1.0)) < 0.02, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(12.0, 1.0)) < 0.02, "{:?}", bounds);
+    assert!((paths_area(&stroked) - (20.0 + f64::consts::PI * 2.0)).abs() < 0.1, "{}", paths_area(&stroked));
+}
+
+#[test]
+fn elliptical_nib_width_depends_on_direction() {
+    let nib         = PenNib::Ellipse { radius_x: 2.0, radius_y: 1.0, angle: 0.0 };
+    let vertical    = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(0.0, 10.0)), &nib, &StrokeOptions::default().with_accuracy(0.01));
+
+    let bounds = vertical[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-2.0, -1.0)) < 0.02, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(2.0, 11.0)) < 0.02, "{:?}", bounds);
+}
+
+#[test]
+fn rotated_elliptical_nib() {
+    // Rotating the ellipse by 90 degrees swaps the axes
+    let nib     = PenNib::Ellipse { radius_x: 2.0, radius_y: 1.0, angle: f64::consts::PI / 2.0 };
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &nib, &StrokeOptions::default().with_accuracy(0.01));
+
+    let bounds = stroked[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-1.0, -2.0)) < 0.02, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(11.0, 2.0)) < 0.02, "{:?}", bounds);
+}
+
+#[test]
+fn chisel_nib_along_line() {
+    // A 45 degree chisel nib makes a parallelogram
+    let nib     = PenNib::chisel(2.0, f64::consts::PI / 4.0);
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &nib, &StrokeOptions::default());
+
+    assert!(stroked.len() == 1, "{:?}", stroked);
+    assert!((paths_area(&stroked) - 10.0 * 2.0f64.sqrt()).abs() < 0.01, "{}", paths_area(&stroked));
+
+    let bounds = stroked[0].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().y() - f64::consts::FRAC_1_SQRT_2).abs() < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn chisel_nib_parallel_to_line_has_no_area() {
+    let nib     = PenNib::chisel(2.0, 0.0);
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &nib, &StrokeOptions::default());
+
+    assert!(paths_area(&stroked).abs() < 0.01, "{:?}", stroked);
+}
+
+#[test]
+fn square_nib_around_circle() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&circle, &square_nib(0.5), &StrokeOptions::default().with_accuracy(0.01));
+
+    // Forms a ring
+    assert!(stroked.len() == 2, "{:?}", stroked);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(9.0, 5.0)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0 + 4.0*f64::consts::FRAC_1_SQRT_2 + 0.6, 5.0 + 4.0*f64::consts::FRAC_1_SQRT_2 + 0.6)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0 + 4.0*f64::consts::FRAC_1_SQRT_2 + 0.45, 5.0 + 4.0*f64::consts::FRAC_1_SQRT_2 + 0.45)) & 1 != 0);
+
+    // The ring is 1 unit wide at the top and sides
+    let bounds = stroked.iter().map(|path| path.bounding_box::<Bounds<_>>()).fold(Bounds::empty(), |a, b| a.union_bounds(b));
+    assert!(bounds.min().distance_to(&Coord2(0.5, 0.5)) < 0.02, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(9.5, 9.5)) < 0.02, "{:?}", bounds);
+}
+
+#[test]
+fn dashed_nib_stroke() {
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&line(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &square_nib(0.5), &StrokeOptions::default().with_dash_array(vec![2.0, 2.0]));
+
+    assert!(stroked.len() == 3, "{:?}", stroked);
+    assert!((paths_area(&stroked) - 3.0 * 3.0).abs() < 0.01, "{}", paths_area(&stroked));
+}
+
+#[test]
+fn square_nib_around_corners() {
+    // Turning anticlockwise and clockwise produce the same shape
+    for end_y in vec![10.0, -10.0] {
+        let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, end_y)).build();
+        let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&path, &square_nib(1.0), &StrokeOptions::default());
+
+        assert!(stroked.len() == 1, "{:?}", stroked);
+        assert!((paths_area(&stroked) - 44.0).abs() < 0.01, "{} {:?}", paths_area(&stroked), stroked);
+        assert!(path_contains_point(&stroked[0], &Coord2(10.9, -0.9 * end_y.signum())));
+    }
+}
+
+#[test]
+fn elliptical_nib_around_circle() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let nib     = PenNib::Ellipse { radius_x: 1.0, radius_y: 0.5, angle: 0.0 };
+    let stroked = stroke_path_with_nib::<SimpleBezierPath, _>(&circle, &nib, &StrokeOptions::default().with_accuracy(0.01));
+
+    // Ring is thicker at the sides than at the top and bottom
+    let bounds = stroked.iter().map(|path| path.bounding_box::<Bounds<_>>()).fold(Bounds::empty(), |a, b| a.union_bounds(b));
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.5)) < 0.02, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(10.0, 9.5)) < 0.02, "{:?}", bounds);
+
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(9.9, 5.0)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 9.4)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 9.6)) & 1 == 0);
+}
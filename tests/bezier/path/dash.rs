@@ -0,0 +1,131 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+fn path_length(path: &SimpleBezierPath) -> f64 {
+    path.to_curves::<Curve<_>>().iter().map(|curve| curve_length(curve, 0.001)).sum()
+}
+
+#[test]
+fn dash_straight_line() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[2.0, 1.0], 0.0, 0.001);
+
+    // Dashes at 0-2, 3-5, 6-8, 9-10
+    assert!(dashes.len() == 4, "{:?}", dashes);
+    assert!(dashes[0].start_point().distance_to(&Coord2(0.0, 0.0)) < 0.01);
+    assert!(dashes[1].start_point().distance_to(&Coord2(3.0, 0.0)) < 0.01);
+    assert!(dashes[2].start_point().distance_to(&Coord2(6.0, 0.0)) < 0.01);
+    assert!(dashes[3].start_point().distance_to(&Coord2(9.0, 0.0)) < 0.01);
+
+    assert!((path_length(&dashes[0]) - 2.0).abs() < 0.01);
+    assert!((path_length(&dashes[3]) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn dash_with_offset() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[2.0, 1.0], 2.5, 0.001);
+
+    // Starts half-way through the gap: dashes at 0.5-2.5, 3.5-5.5, 6.5-8.5, 9.5-10
+    assert!(dashes.len() == 4, "{:?}", dashes);
+    assert!(dashes[0].start_point().distance_to(&Coord2(0.5, 0.0)) < 0.01);
+    assert!(dashes[3].start_point().distance_to(&Coord2(9.5, 0.0)) < 0.01);
+}
+
+#[test]
+fn dash_with_negative_offset() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[2.0, 1.0], -1.0, 0.001);
+
+    // Starts in the gap, with one unit left
+    assert!(dashes[0].start_point().distance_to(&Coord2(1.0, 0.0)) < 0.01);
+}
+
+#[test]
+fn odd_dash_array_is_repeated() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[1.0, 2.0, 3.0], 0.0, 0.001);
+
+    // 1 on, 2 off, 3 on, 1 off, 2 on, 3 off
+    assert!(dashes.len() == 3, "{:?}", dashes);
+    assert!(dashes[1].start_point().distance_to(&Coord2(3.0, 0.0)) < 0.01);
+    assert!((path_length(&dashes[1]) - 3.0).abs() < 0.01);
+    assert!(dashes[2].start_point().distance_to(&Coord2(7.0, 0.0)) < 0.01);
+    assert!((path_length(&dashes[2]) - 2.0).abs() < 0.01);
+}
+
+#[test]
+fn dashes_continue_across_curves() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(3.0, 0.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&path, &[4.0, 1.0], 0.0, 0.001);
+
+    // First dash goes around the corner
+    assert!(dashes.len() == 2, "{:?}", dashes);
+    assert!(dashes[0].points().count() == 2);
+    assert!(dashes[0].points().last().unwrap().2.distance_to(&Coord2(3.0, 1.0)) < 0.01);
+    assert!(dashes[1].start_point().distance_to(&Coord2(3.0, 2.0)) < 0.01);
+}
+
+#[test]
+fn dash_lengths_follow_arc_length() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let dashes  = path_dashes::<SimpleBezierPath>(&circle, &[1.0, 1.0], 0.5, 0.0001);
+
+    // All but the dashes at the start and end should be 1 unit long
+    assert!(dashes.len() > 10);
+    for dash in dashes.iter().skip(1).take(dashes.len()-2) {
+        assert!((path_length(dash) - 1.0).abs() < 0.01, "{:?}", path_length(dash));
+    }
+}
+
+#[test]
+fn closed_path_joins_first_and_last_dash() {
+    let square  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(4.0, 0.0))
+        .line_to(Coord2(4.0, 4.0))
+        .line_to(Coord2(0.0, 4.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    // Perimeter is 16, so there are 3 whole dashes, with a half dash at the start and end
+    let dashes  = path_dashes::<SimpleBezierPath>(&square, &[3.0, 1.0], 1.5, 0.001);
+
+    assert!(dashes.len() == 4, "{:?}", dashes);
+
+    // The joined dash runs through the start point of the square
+    let joined = dashes.last().unwrap();
+    assert!((path_length(joined) - 3.0).abs() < 0.01, "{:?}", path_length(joined));
+    assert!(joined.start_point().distance_to(&Coord2(0.0, 1.5)) < 0.01, "{:?}", joined.start_point());
+    assert!(joined.points().last().unwrap().2.distance_to(&Coord2(1.5, 0.0)) < 0.01);
+}
+
+#[test]
+fn empty_dash_array_produces_original_path() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[], 0.0, 0.001);
+
+    assert!(dashes.len() == 1);
+    assert!(dashes[0] == line);
+}
+
+#[test]
+fn zero_length_dashes_are_points() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let dashes  = path_dashes::<SimpleBezierPath>(&line, &[0.0, 5.0], 0.0, 0.001);
+
+    // A degenerate dash at 0 and 5 (the pattern would start a dash exactly at the end of the line, which isn't included)
+    assert!(dashes.len() == 2, "{:?}", dashes);
+
+    for (dash, point) in dashes.iter().zip(vec![Coord2(0.0, 0.0), Coord2(5.0, 0.0)]) {
+        assert!(dash.start_point().distance_to(&point) < 0.01, "{:?}", dash);
+        assert!(dash.points().count() == 1, "{:?}", dash);
+
+        let (cp1, cp2, end_point) = dash.points().next().unwrap();
+        assert!(cp1 == dash.start_point() && cp2 == dash.start_point() && end_point == dash.start_point(), "{:?}", dash);
+    }
+}
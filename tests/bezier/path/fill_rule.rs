@@ -0,0 +1,148 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+use crate::bezier::square;
+
+fn is_inside(paths: &Vec<SimpleBezierPath>, point: Coord2) -> bool {
+    // Output from the arithmetic operations never overlaps itself, so the even-odd rule can be used to test it
+    FillRule::EvenOdd.is_inside(path_winding_number(paths, &point))
+}
+
+#[test]
+fn fill_rules_from_winding_numbers() {
+    assert!(!FillRule::EvenOdd.is_inside(0));
+    assert!(FillRule::EvenOdd.is_inside(1));
+    assert!(!FillRule::EvenOdd.is_inside(2));
+    assert!(FillRule::EvenOdd.is_inside(-1));
+
+    assert!(!FillRule::NonZero.is_inside(0));
+    assert!(FillRule::NonZero.is_inside(2));
+    assert!(FillRule::NonZero.is_inside(-1));
+
+    assert!(FillRule::Positive.is_inside(2));
+    assert!(!FillRule::Positive.is_inside(-1));
+
+    assert!(!FillRule::Negative.is_inside(2));
+    assert!(FillRule::Negative.is_inside(-1));
+}
+
+#[test]
+fn winding_number_of_anticlockwise_square() {
+    let square = vec![square(1.0, 1.0, 5.0, 5.0)];
+
+    assert!(path_winding_number(&square, &Coord2(3.0, 3.0)) == 1);
+    assert!(path_winding_number(&square, &Coord2(6.0, 3.0)) == 0);
+    assert!(path_winding_number(&square, &Coord2(3.0, 0.0)) == 0);
+}
+
+#[test]
+fn winding_number_of_clockwise_circle() {
+    let circle = vec![Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>().reversed::<SimpleBezierPath>()];
+    let direction = if circle[0].is_clockwise() { -1 } else { 1 };
+
+    assert!(path_winding_number(&circle, &Coord2(5.0, 5.0)) == direction);
+    assert!(path_winding_number(&circle, &Coord2(5.0, 9.5)) == 0);
+}
+
+#[test]
+fn winding_number_of_nested_squares() {
+    let squares = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0), square(4.0, 4.0, 6.0, 6.0).reversed::<SimpleBezierPath>()];
+
+    assert!(path_winding_number(&squares, &Coord2(2.0, 2.0)) == 1);
+    assert!(path_winding_number(&squares, &Coord2(3.5, 3.5)) == 2);
+    assert!(path_winding_number(&squares, &Coord2(5.0, 5.0)) == 1);
+}
+
+#[test]
+fn remove_interior_points_with_non_zero_rule() {
+    // Two overlapping squares in the same direction: the overlap has a winding number of 2
+    let squares     = vec![square(1.0, 1.0, 5.0, 5.0), square(3.0, 3.0, 7.0, 7.0)];
+
+    let non_zero    = path_remove_interior_points_with_fill_rule::<_, SimpleBezierPath>(&squares, FillRule::NonZero, 0.01);
+    let even_odd    = path_remove_interior_points_with_fill_rule::<_, SimpleBezierPath>(&squares, FillRule::EvenOdd, 0.01);
+
+    assert!(is_inside(&non_zero, Coord2(2.0, 2.0)));
+    assert!(is_inside(&non_zero, Coord2(4.0, 4.0)));
+    assert!(is_inside(&non_zero, Coord2(6.0, 6.0)));
+    assert!(!is_inside(&non_zero, Coord2(6.0, 2.0)));
+    assert!((paths_area(&non_zero) - 28.0).abs() < 0.01, "{:?}", non_zero);
+
+    assert!(is_inside(&even_odd, Coord2(2.0, 2.0)));
+    assert!(!is_inside(&even_odd, Coord2(4.0, 4.0)));
+    assert!(is_inside(&even_odd, Coord2(6.0, 6.0)));
+}
+
+#[test]
+fn remove_interior_points_with_positive_and_negative_rules() {
+    let squares     = vec![square(1.0, 1.0, 5.0, 5.0), square(3.0, 3.0, 7.0, 7.0).reversed::<SimpleBezierPath>()];
+
+    let positive    = path_remove_interior_points_with_fill_rule::<_, SimpleBezierPath>(&squares, FillRule::Positive, 0.01);
+    let negative    = path_remove_interior_points_with_fill_rule::<_, SimpleBezierPath>(&squares, FillRule::Negative, 0.01);
+
+    // Overlapping area has a winding number of 0
+    assert!(is_inside(&positive, Coord2(2.0, 2.0)));
+    assert!(!is_inside(&positive, Coord2(4.0, 4.0)));
+    assert!(!is_inside(&positive, Coord2(6.0, 6.0)));
+
+    assert!(!is_inside(&negative, Coord2(2.0, 2.0)));
+    assert!(!is_inside(&negative, Coord2(4.0, 4.0)));
+    assert!(is_inside(&negative, Coord2(6.0, 6.0)));
+}
+
+#[test]
+fn add_with_non_zero_rule() {
+    // Nested squares in the same direction are filled with the non-zero rule
+    let nested      = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)];
+    let other       = vec![square(8.0, 4.0, 12.0, 6.0)];
+
+    let non_zero    = path_add_with_fill_rule::<SimpleBezierPath>(&nested, &other, FillRule::NonZero, 0.01);
+    let even_odd    = path_add_with_fill_rule::<SimpleBezierPath>(&nested, &other, FillRule::EvenOdd, 0.01);
+
+    assert!(is_inside(&non_zero, Coord2(5.0, 5.0)));
+    assert!(is_inside(&non_zero, Coord2(2.0, 2.0)));
+    assert!(is_inside(&non_zero, Coord2(11.0, 5.0)));
+    assert!(non_zero.len() == 1, "{:?}", non_zero);
+
+    assert!(!is_inside(&even_odd, Coord2(5.0, 5.0)));
+    assert!(is_inside(&even_odd, Coord2(2.0, 2.0)));
+    assert!(is_inside(&even_odd, Coord2(11.0, 5.0)));
+}
+
+#[test]
+fn sub_with_non_zero_rule() {
+    let nested      = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)];
+    let to_remove   = vec![square(0.0, 4.0, 4.0, 6.0)];
+
+    let non_zero    = path_sub_with_fill_rule::<SimpleBezierPath>(&nested, &to_remove, FillRule::NonZero, 0.01);
+
+    assert!(is_inside(&non_zero, Coord2(5.0, 5.0)));
+    assert!(is_inside(&non_zero, Coord2(2.0, 2.0)));
+    assert!(!is_inside(&non_zero, Coord2(2.0, 5.0)));
+    assert!(!is_inside(&non_zero, Coord2(3.5, 5.0)));
+    assert!((paths_area(&non_zero) - (64.0 - 6.0)).abs() < 0.01, "{:?}", non_zero);
+}
+
+#[test]
+fn intersect_with_non_zero_rule() {
+    let nested      = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)];
+    let other       = vec![square(4.0, 0.0, 6.0, 10.0)];
+
+    let non_zero    = path_intersect_with_fill_rule::<SimpleBezierPath>(&nested, &other, FillRule::NonZero, 0.01);
+    let even_odd    = path_intersect_with_fill_rule::<SimpleBezierPath>(&nested, &other, FillRule::EvenOdd, 0.01);
+
+    assert!((paths_area(&non_zero) - 16.0).abs() < 0.01, "{:?}", non_zero);
+    assert!((paths_area(&even_odd) - 8.0).abs() < 0.01, "{:?}", even_odd);
+    assert!(is_inside(&non_zero, Coord2(5.0, 5.0)));
+    assert!(!is_inside(&even_odd, Coord2(5.0, 5.0)));
+}
+
+#[test]
+fn reversed_inner_path_is_hole_with_non_zero_rule() {
+    let doughnut    = vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0).reversed::<SimpleBezierPath>()];
+
+    let non_zero    = path_remove_interior_points_with_fill_rule::<_, SimpleBezierPath>(&doughnut, FillRule::NonZero, 0.01);
+
+    assert!(is_inside(&non_zero, Coord2(2.0, 2.0)));
+    assert!(!is_inside(&non_zero, Coord2(5.0, 5.0)));
+    assert!((paths_area(&non_zero) - (64.0 - 16.0)).abs() < 0.01, "{:?}", non_zero);
+}
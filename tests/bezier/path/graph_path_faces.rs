@@ -0,0 +1,109 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+fn rectangle(min: Coord2, max: Coord2) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(min)
+        .line_to(Coord2(max.x(), min.y()))
+        .line_to(max)
+        .line_to(Coord2(min.x(), max.y()))
+        .line_to(min)
+        .build()
+}
+
+fn face_area(face: &GraphPathFace<SimpleBezierPath, i32>) -> f64 {
+    face.paths.iter().map(|path| path_signed_area(path)).sum()
+}
+
+#[test]
+fn single_rectangle_has_one_face() {
+    let graph   = GraphPath::from_path(&rectangle(Coord2(0.0, 0.0), Coord2(10.0, 20.0)), 1);
+    let faces   = graph.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 1);
+    assert!(faces[0].paths.len() == 1);
+    assert!(faces[0].edges.len() == 4);
+    assert!(faces[0].labels == vec![1, 1, 1, 1]);
+    assert!(faces[0].adjacent_faces.is_empty());
+    assert!((face_area(&faces[0]) - 200.0).abs() < 0.01);
+}
+
+#[test]
+fn overlapping_circles_have_three_faces() {
+    let circle1 = Circle::new(Coord2(0.0, 0.0), 10.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(12.0, 0.0), 10.0).to_path::<SimpleBezierPath>();
+    let graph   = GraphPath::from_path(&circle1, 1).collide(GraphPath::from_path(&circle2, 2), 0.01);
+    let faces   = graph.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 3, "{}", faces.len());
+
+    // The lens in the middle is bounded by both circles and is next to both of the other faces
+    let lens = faces.iter().position(|face| path_contains_point(&face.paths[0], &Coord2(6.0, 0.0))).unwrap();
+    let left = faces.iter().position(|face| path_contains_point(&face.paths[0], &Coord2(-5.0, 0.0))).unwrap();
+    let right = faces.iter().position(|face| path_contains_point(&face.paths[0], &Coord2(17.0, 0.0))).unwrap();
+
+    assert!(lens != left && lens != right && left != right);
+    assert!(faces[lens].labels.contains(&1) && faces[lens].labels.contains(&2));
+    assert!(faces[left].labels.iter().all(|label| *label == 1 || *label == 2));
+    assert!(faces[lens].adjacent_faces.len() == 2);
+    assert!(faces[lens].adjacent_faces.contains(&left) && faces[lens].adjacent_faces.contains(&right));
+    assert!(faces[left].adjacent_faces == vec![lens]);
+    assert!(faces[right].adjacent_faces == vec![lens]);
+
+    // Faces don't overlap, so their total area is the area of the union of the circles
+    let total_area  = faces.iter().map(|face| face_area(face)).sum::<f64>();
+    let union_area  = path_signed_area(&circle1).abs() + path_signed_area(&circle2).abs() - face_area(&faces[lens]);
+    assert!((total_area - union_area).abs() < 0.1, "{} {}", total_area, union_area);
+}
+
+#[test]
+fn rectangle_divided_by_line() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, -5.0)).line_to(Coord2(5.0, 25.0)).build();
+    let graph   = GraphPath::from_path(&rectangle(Coord2(0.0, 0.0), Coord2(10.0, 20.0)), 1).collide(GraphPath::from_path(&line, 2), 0.01);
+    let faces   = graph.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 2, "{}", faces.len());
+    assert!(faces[0].adjacent_faces == vec![1]);
+    assert!(faces[1].adjacent_faces == vec![0]);
+
+    for face in faces.iter() {
+        assert!((face_area(face) - 100.0).abs() < 0.01);
+        assert!(face.labels.contains(&2));
+    }
+}
+
+#[test]
+fn rectangle_with_hole() {
+    let outer   = rectangle(Coord2(0.0, 0.0), Coord2(100.0, 100.0));
+    let inner   = rectangle(Coord2(20.0, 20.0), Coord2(40.0, 40.0));
+    let graph   = GraphPath::from_path(&outer, 1).collide(GraphPath::from_path(&inner, 2), 0.01);
+    let faces   = graph.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 2);
+
+    let outer_face = faces.iter().position(|face| face.paths.len() == 2).unwrap();
+    let inner_face = 1 - outer_face;
+
+    assert!((face_area(&faces[outer_face]) - (10000.0 - 400.0)).abs() < 0.01);
+    assert!((face_area(&faces[inner_face]) - 400.0).abs() < 0.01);
+    assert!(faces[inner_face].labels.iter().all(|label| *label == 2));
+    assert!(faces[outer_face].adjacent_faces == vec![inner_face]);
+    assert!(faces[inner_face].adjacent_faces == vec![outer_face]);
+}
+
+#[test]
+fn separate_rectangles_are_not_adjacent() {
+    let graph   = GraphPath::from_path(&rectangle(Coord2(0.0, 0.0), Coord2(10.0, 10.0)), 1).collide(GraphPath::from_path(&rectangle(Coord2(20.0, 0.0), Coord2(30.0, 10.0)), 2), 0.01);
+    let faces   = graph.faces::<SimpleBezierPath>();
+
+    assert!(faces.len() == 2);
+    assert!(faces.iter().all(|face| face.paths.len() == 1 && face.adjacent_faces.is_empty()));
+}
+
+#[test]
+fn line_has_no_faces() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 5.0)).build();
+    let graph   = GraphPath::from_path(&line, 1);
+
+    assert!(graph.faces::<SimpleBezierPath>().is_empty());
+}
@@ -0,0 +1,165 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+fn arrowhead() -> SimpleBezierPath {
+    // Tip at the origin, back edge 2 units behind it
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(-2.0, 1.0))
+        .line_to(Coord2(-2.0, -1.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn vertex_markers_on_open_path() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let markers = path_vertex_markers(&path);
+
+    assert!(markers.len() == 3);
+
+    assert!(markers[0].position.distance_to(&Coord2(0.0, 0.0)) < 0.001);
+    assert!(markers[0].angle.abs() < 0.001);
+
+    // Vertex bisects the incoming and outgoing directions
+    assert!(markers[1].position.distance_to(&Coord2(10.0, 0.0)) < 0.001);
+    assert!((markers[1].angle - f64::consts::PI/4.0).abs() < 0.001, "{}", markers[1].angle);
+    assert!(markers[1].curve_idx == 1);
+
+    assert!(markers[2].position.distance_to(&Coord2(10.0, 10.0)) < 0.001);
+    assert!((markers[2].angle - f64::consts::PI/2.0).abs() < 0.001);
+}
+
+#[test]
+fn vertex_markers_on_closed_path() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let markers = path_vertex_markers(&path);
+
+    assert!(markers.len() == 5);
+
+    // The start and end of a closed path are a vertex coming in from above (moving down) and going out to the right
+    assert!((markers[0].angle - -f64::consts::PI/4.0).abs() < 0.001, "{}", markers[0].angle);
+    assert!((markers[4].angle - -f64::consts::PI/4.0).abs() < 0.001, "{}", markers[4].angle);
+}
+
+#[test]
+fn interval_markers_along_line() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let markers = path_interval_markers(&path, 3.0, 1.0, 0.001);
+
+    // Markers at 1, 4, 7, 10, 13, 16, 19
+    assert!(markers.len() == 7, "{:?}", markers);
+
+    for (marker, expected) in markers.iter().zip(vec![Coord2(1.0, 0.0), Coord2(4.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 3.0), Coord2(10.0, 6.0), Coord2(10.0, 9.0)]) {
+        assert!(marker.position.distance_to(&expected) < 0.01, "{:?} {:?}", marker, expected);
+    }
+
+    assert!(markers[0].angle.abs() < 0.001);
+    assert!((markers[6].angle - f64::consts::PI/2.0).abs() < 0.001);
+}
+
+#[test]
+fn interval_markers_along_circle() {
+    let circle  = Circle::new(Coord2(0.0, 0.0), 5.0).to_path::<SimpleBezierPath>();
+    let length  = 2.0 * f64::consts::PI * 5.0;
+    let markers = path_interval_markers(&circle, length / 8.0, 0.0, 0.0001);
+
+    assert!(markers.len() == 8 || markers.len() == 9, "{:?}", markers);
+
+    // Markers should be evenly spaced around the circle
+    for (m1, m2) in markers.iter().zip(markers.iter().skip(1)) {
+        let distance = m1.position.distance_to(&m2.position);
+        assert!((distance - 2.0 * 5.0 * (f64::consts::PI / 8.0).sin()).abs() < 0.01, "{}", distance);
+    }
+}
+
+#[test]
+fn place_marker_rotates_and_moves() {
+    let position    = MarkerPosition { position: Coord2(5.0, 5.0), angle: f64::consts::PI/2.0, curve_idx: 0, t: 0.0 };
+    let marker      = position.place_marker::<SimpleBezierPath>(&arrowhead(), 2.0);
+
+    // Pointing up, so the back edge is below the tip
+    let bounds      = marker.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(3.0, 1.0)) < 0.001, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(7.0, 5.0)) < 0.001, "{:?}", bounds);
+}
+
+#[test]
+fn trim_path_by_length() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let trimmed = path_trim_by_length::<SimpleBezierPath>(&path, 2.0, 3.0, 0.001).unwrap();
+
+    assert!(trimmed.start_point().distance_to(&Coord2(2.0, 0.0)) < 0.01);
+    assert!(trimmed.points().last().unwrap().2.distance_to(&Coord2(10.0, 7.0)) < 0.01);
+    assert!(trimmed.points().count() == 2);
+
+    assert!(path_trim_by_length::<SimpleBezierPath>(&path, 12.0, 9.0, 0.001).is_none());
+}
+
+#[test]
+fn trim_path_to_curve_boundary() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+
+    let trimmed = path_trim_by_length::<SimpleBezierPath>(&path, 10.0, 0.0, 0.001).unwrap();
+    let points  = trimmed.points().collect::<Vec<_>>();
+
+    assert!(trimmed.start_point().distance_to(&Coord2(10.0, 0.0)) < 0.01, "{:?}", trimmed);
+    assert!(points.len() == 1, "{:?}", trimmed);
+    assert!(points.iter().all(|(cp1, cp2, end_point)| !cp1.x().is_nan() && !cp1.y().is_nan() && !cp2.x().is_nan() && !cp2.y().is_nan() && !end_point.x().is_nan()), "{:?}", trimmed);
+    assert!(points[0].2.distance_to(&Coord2(10.0, 10.0)) < 0.01);
+
+    let trimmed = path_trim_by_length::<SimpleBezierPath>(&path, 0.0, 10.0, 0.001).unwrap();
+    let points  = trimmed.points().collect::<Vec<_>>();
+
+    assert!(points.len() == 1, "{:?}", trimmed);
+    assert!(points[0].2.distance_to(&Coord2(10.0, 0.0)) < 0.01, "{:?}", trimmed);
+}
+
+#[test]
+fn arrowhead_at_end_of_line() {
+    let path                = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(0.0, 10.0)).build();
+    let (trimmed, marker)   = path_with_end_marker::<SimpleBezierPath>(&path, &arrowhead(), 2.0, 0.001);
+
+    let trimmed = trimmed.unwrap();
+    assert!(trimmed.points().last().unwrap().2.distance_to(&Coord2(0.0, 8.0)) < 0.01);
+
+    let bounds  = marker.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-1.0, 8.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(1.0, 10.0)) < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn arrowhead_at_start_of_line() {
+    let path                = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let (trimmed, marker)   = path_with_start_marker::<SimpleBezierPath>(&path, &arrowhead(), 2.0, 0.001);
+
+    let trimmed = trimmed.unwrap();
+    assert!(trimmed.start_point().distance_to(&Coord2(2.0, 0.0)) < 0.01);
+
+    // Arrowhead points backwards, away from the line
+    let bounds  = marker.bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(0.0, -1.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(2.0, 1.0)) < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn arrowhead_on_curved_path_lines_up_with_back_edge() {
+    let circle              = Circle::new(Coord2(0.0, 0.0), 5.0);
+    let arc                 = circle.arc(0.0, f64::consts::PI/2.0).to_bezier_curve::<bezier::Curve<Coord2>>();
+    let path                = SimpleBezierPath::from_connected_curves(vec![arc]);
+    let (trimmed, marker)   = path_with_end_marker::<SimpleBezierPath>(&path, &arrowhead(), 2.0, 0.001);
+
+    // The back edge of the arrowhead is centered on the end of the trimmed path
+    let back_end            = trimmed.unwrap().points().last().unwrap().2;
+    let back_edge_center    = marker.points().nth(0).map(|(_, _, p1)| p1).unwrap() * 0.5 + marker.points().nth(1).map(|(_, _, p2)| p2).unwrap() * 0.5;
+
+    assert!(back_end.distance_to(&back_edge_center) < 0.01, "{:?} {:?}", back_end, back_edge_center);
+    assert!((back_end.magnitude() - 5.0).abs() < 0.01);
+}
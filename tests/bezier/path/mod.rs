@@ -8,11 +8,21 @@ mod intersection;
 mod bounds;
 mod graph_path;
 mod is_clockwise;
+mod area;
+mod fill_rule;
+mod offset;
 mod arithmetic_add;
 mod arithmetic_chain_add;
 mod arithmetic_sub;
 mod arithmetic_cut;
 mod arithmetic_intersect;
+mod arithmetic_xor;
 mod arithmetic_complicated_paths;
 mod rays;
 mod stroke_tests;
+mod dash;
+mod calligraphy;
+mod markers;
+mod svg_data;
+mod path_builder;
+mod graph_path_faces;
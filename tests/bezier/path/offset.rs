@@ -0,0 +1,151 @@
+use std::f64;
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+use crate::bezier::square;
+
+#[test]
+fn outset_square_with_miter() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 5.0, 5.0)], 1.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1);
+    assert!((paths_area(&offset) - 36.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_square_with_bevel() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 5.0, 5.0)], 1.0, LineJoin::Bevel, 4.0);
+
+    // Corners are cut off by a triangle with an area of 0.5
+    assert!(offset.len() == 1);
+    assert!((paths_area(&offset) - 34.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_square_with_round_join() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 5.0, 5.0)], 1.0, LineJoin::Round, 4.0);
+
+    // Square, the sides and a circle of radius 1 at the corners
+    let expected_area = 16.0 + 16.0 + f64::consts::PI;
+
+    assert!(offset.len() == 1);
+    assert!((paths_area(&offset) - expected_area).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_clockwise_square() {
+    // Direction of the path doesn't matter
+    let clockwise   = square(1.0, 1.0, 5.0, 5.0).reversed::<SimpleBezierPath>();
+    let offset      = path_offset::<SimpleBezierPath>(&vec![clockwise], 1.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1);
+    assert!((paths_area(&offset) - 36.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn inset_square() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 5.0, 5.0)], -1.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1);
+    assert!((paths_area(&offset) - 4.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn inset_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let offset = path_offset::<SimpleBezierPath>(&vec![circle], -1.0, LineJoin::Round, 4.0);
+
+    assert!(offset.len() == 1);
+    for (_, _, point) in offset[0].points() {
+        assert!((point.distance_to(&Coord2(5.0, 5.0)) - 3.0).abs() < 0.05, "{:?}", point);
+    }
+}
+
+#[test]
+fn inset_square_until_it_collapses() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 5.0, 5.0)], -3.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.is_empty(), "{:?}", offset);
+}
+
+#[test]
+fn outset_concave_shape() {
+    // L-shaped path with a concave corner at 3,3
+    let l_shape = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 3.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(3.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    let offset = path_offset::<SimpleBezierPath>(&vec![l_shape], 0.5, LineJoin::Miter, 4.0);
+
+    // L-shape made of a 5x3 and a 3x2 rectangle (the concave corner moves to 3.5, 3.5)
+    assert!(offset.len() == 1, "{:?}", offset);
+    assert!((paths_area(&offset) - (15.0 + 6.0)).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_square_with_hole() {
+    // Growing the region shrinks the hole
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)], 1.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 2, "{:?}", offset);
+    assert!((paths_area(&offset) - (100.0 - 4.0)).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_fills_hole() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 9.0, 9.0), square(4.0, 4.0, 6.0, 6.0)], 1.5, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1, "{:?}", offset);
+    assert!((paths_area(&offset) - 121.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn outset_merges_nearby_squares() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 3.0, 3.0), square(4.0, 1.0, 6.0, 3.0)], 1.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1, "{:?}", offset);
+    assert!((paths_area(&offset) - 28.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn inset_square_with_hole() {
+    // Shrinking the region grows the hole
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)], -0.5, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 2, "{:?}", offset);
+    assert!((paths_area(&offset) - (49.0 - 25.0)).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
+
+#[test]
+fn inset_square_past_its_center() {
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(0.0, 0.0, 10.0, 10.0)], -11.0, LineJoin::Miter, 4.0);
+    assert!(offset.is_empty(), "{:?}", offset);
+
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(0.0, 0.0, 10.0, 10.0)], -20.0, LineJoin::Miter, 4.0);
+    assert!(offset.is_empty(), "{:?}", offset);
+}
+
+#[test]
+fn inset_circle_past_its_center() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 5.0).to_path::<SimpleBezierPath>();
+
+    let offset = path_offset::<SimpleBezierPath>(&vec![circle.clone()], -6.0, LineJoin::Round, 4.0);
+    assert!(offset.is_empty(), "{:?}", offset);
+
+    let offset = path_offset::<SimpleBezierPath>(&vec![circle], -10.0, LineJoin::Round, 4.0);
+    assert!(offset.is_empty(), "{:?}", offset);
+}
+
+#[test]
+fn outset_square_with_hole_past_its_center() {
+    // The hole is 4x4, so it collapses when the region is grown by more than 2
+    let offset = path_offset::<SimpleBezierPath>(&vec![square(1.0, 1.0, 9.0, 9.0), square(3.0, 3.0, 7.0, 7.0)], 5.0, LineJoin::Miter, 4.0);
+
+    assert!(offset.len() == 1, "{:?}", offset);
+    assert!((paths_area(&offset) - 324.0).abs() < 0.1, "{:?} {:?}", paths_area(&offset), offset);
+}
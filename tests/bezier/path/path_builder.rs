@@ -0,0 +1,270 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+fn assert_near(actual: Coord2, expected: Coord2) {
+    assert!(actual.distance_to(&expected) < 1e-6, "{:?} != {:?}", actual, expected);
+}
+
+#[test]
+fn quad_to_matches_quadratic_curve() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .quad_to(Coord2(5.0, 10.0), Coord2(10.0, 0.0))
+        .build();
+    let curves  = path.to_curves::<Curve<_>>();
+
+    assert!(curves.len() == 1);
+    assert_near(curves[0].point_at_pos(0.5), Coord2(5.0, 5.0));
+    assert_near(curves[0].point_at_pos(0.25), Coord2(2.5, 3.75));
+}
+
+#[test]
+fn smooth_curve_reflects_previous_control_point() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to((Coord2(0.0, 10.0), Coord2(10.0, 10.0)), Coord2(10.0, 0.0))
+        .smooth_curve_to(Coord2(20.0, -10.0), Coord2(20.0, 0.0))
+        .build();
+    let points  = path.points().collect::<Vec<_>>();
+
+    assert_near(points[1].0, Coord2(10.0, -10.0));
+}
+
+#[test]
+fn smooth_curve_at_start_uses_start_point() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 2.0))
+        .smooth_curve_to(Coord2(5.0, 5.0), Coord2(10.0, 0.0))
+        .build();
+    let points  = path.points().collect::<Vec<_>>();
+
+    assert_near(points[0].0, Coord2(1.0, 2.0));
+}
+
+#[test]
+fn smooth_curve_only_reflects_cubic_curves() {
+    // After lines, quadratic curves and arcs, the first control point is the current point (the same as SVG's 'S' command)
+    let after_line  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .smooth_curve_to(Coord2(20.0, 10.0), Coord2(20.0, 0.0))
+        .build();
+    let after_quad  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .quad_to(Coord2(5.0, 10.0), Coord2(10.0, 0.0))
+        .smooth_curve_to(Coord2(20.0, 10.0), Coord2(20.0, 0.0))
+        .build();
+    let after_arc   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .arc_to((5.0, 5.0), 0.0, false, true, Coord2(10.0, 0.0))
+        .smooth_curve_to(Coord2(20.0, 10.0), Coord2(20.0, 0.0))
+        .build();
+
+    for path in vec![after_line, after_quad, after_arc] {
+        let (cp1, _, _) = path.points().last().unwrap();
+        assert_near(cp1, Coord2(10.0, 0.0));
+    }
+
+    // Smooth curves can follow each other
+    let smooth      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .smooth_curve_to(Coord2(10.0, 10.0), Coord2(10.0, 0.0))
+        .smooth_curve_to(Coord2(20.0, -10.0), Coord2(20.0, 0.0))
+        .build();
+    let points      = smooth.points().collect::<Vec<_>>();
+    assert_near(points[1].0, Coord2(10.0, -10.0));
+}
+
+#[test]
+fn smooth_quad_reflects_previous_quad_control_point() {
+    let reflected   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .quad_to(Coord2(5.0, 10.0), Coord2(10.0, 0.0))
+        .smooth_quad_to(Coord2(20.0, 0.0))
+        .build();
+    let expected    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .quad_to(Coord2(5.0, 10.0), Coord2(10.0, 0.0))
+        .quad_to(Coord2(15.0, -10.0), Coord2(20.0, 0.0))
+        .build();
+
+    for (actual, expected) in reflected.points().zip(expected.points()) {
+        assert_near(actual.0, expected.0);
+        assert_near(actual.1, expected.1);
+        assert_near(actual.2, expected.2);
+    }
+
+    // After a line, there's no quadratic control point to reflect
+    let after_line  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .quad_to(Coord2(5.0, 10.0), Coord2(10.0, 0.0))
+        .line_to(Coord2(20.0, 0.0))
+        .smooth_quad_to(Coord2(30.0, 0.0))
+        .build();
+    let last_curve  = after_line.to_curves::<Curve<_>>()[2];
+    assert!(last_curve.point_at_pos(0.5).y().abs() < 1e-6);
+}
+
+#[test]
+fn relative_commands() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(10.0, 10.0))
+        .line_by(Coord2(5.0, 0.0))
+        .curve_by((Coord2(1.0, 1.0), Coord2(2.0, 2.0)), Coord2(3.0, 3.0))
+        .quad_by(Coord2(1.0, 0.0), Coord2(2.0, 0.0))
+        .build();
+    let points  = path.points().collect::<Vec<_>>();
+
+    assert_near(points[0].2, Coord2(15.0, 10.0));
+    assert_near(points[1].0, Coord2(16.0, 11.0));
+    assert_near(points[1].1, Coord2(17.0, 12.0));
+    assert_near(points[1].2, Coord2(18.0, 13.0));
+    assert_near(points[2].2, Coord2(20.0, 13.0));
+}
+
+#[test]
+fn close_adds_line_only_when_needed() {
+    let open    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .close()
+        .build();
+    let closed  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .close()
+        .build();
+    let nearly  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 0.0000001))
+        .close()
+        .build();
+
+    assert!(open.points().count() == 3);
+    assert!(open.points().last().unwrap().2 == Coord2(0.0, 0.0));
+    assert!(closed.points().count() == 3);
+    assert!(nearly.points().count() == 3);
+    assert!(nearly.points().last().unwrap().2 == Coord2(0.0, 0.0));
+}
+
+#[test]
+fn arc_to_draws_semicircle() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .arc_to((10.0, 10.0), 0.0, false, true, Coord2(20.0, 0.0))
+        .build();
+    let curves  = path.to_curves::<Curve<_>>();
+
+    assert!(curves.len() == 2);
+    assert!(curves[1].end_point() == Coord2(20.0, 0.0));
+
+    for curve in curves.iter() {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!((pos.distance_to(&Coord2(10.0, 0.0)) - 10.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn arc_by_is_relative() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(5.0, 5.0))
+        .arc_by((10.0, 10.0), 0.0, false, true, Coord2(20.0, 0.0))
+        .build();
+
+    assert!(path.points().last().unwrap().2 == Coord2(25.0, 5.0));
+}
+
+#[test]
+fn tangent_arc_continues_smoothly() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .tangent_arc_to(Coord2(20.0, 10.0))
+        .build();
+    let curves  = path.to_curves::<Curve<_>>();
+
+    // Quarter circle centered at (10, 10)
+    assert!(curves.len() == 2);
+    assert!(curves[1].end_point() == Coord2(20.0, 10.0));
+
+    let start_tangent = curves[1].tangent_at_pos(0.0).to_unit_vector();
+    assert_near(start_tangent, Coord2(1.0, 0.0));
+
+    for t in 0..=10 {
+        let pos = curves[1].point_at_pos((t as f64)/10.0);
+        assert!((pos.distance_to(&Coord2(10.0, 10.0)) - 10.0).abs() < 0.01, "{:?}", pos);
+    }
+}
+
+#[test]
+fn tangent_arc_turning_right_and_back() {
+    // End point is behind and to the right, so this covers more than 180 degrees
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .tangent_arc_by(Coord2(-5.0, -5.0))
+        .build();
+    let curves  = path.to_curves::<Curve<_>>();
+
+    let start_tangent = curves[1].tangent_at_pos(0.0).to_unit_vector();
+    assert_near(start_tangent, Coord2(1.0, 0.0));
+    assert!(curves.last().unwrap().end_point() == Coord2(5.0, -5.0));
+
+    // Center is at (10, -5), radius 5
+    for curve in curves.iter().skip(1) {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!((pos.distance_to(&Coord2(10.0, -5.0)) - 5.0).abs() < 0.01, "{:?}", pos);
+        }
+    }
+}
+
+#[test]
+fn tangent_arc_straight_ahead_is_line() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .tangent_arc_to(Coord2(20.0, 0.0))
+        .build();
+
+    assert!(path.points().count() == 2);
+}
+
+#[test]
+fn compound_path_with_hole() {
+    let paths = CompoundPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .close()
+        .move_to(Coord2(3.0, 3.0))
+        .line_by(Coord2(0.0, 4.0))
+        .line_by(Coord2(4.0, 0.0))
+        .line_by(Coord2(0.0, -4.0))
+        .close()
+        .build();
+
+    assert!(paths.len() == 2);
+    assert!(paths[0].points().count() == 4);
+    assert!(paths[1].start_point() == Coord2(3.0, 3.0));
+    assert!(paths[1].points().last().unwrap().2 == Coord2(3.0, 3.0));
+
+    // The hole goes in the opposite direction
+    let area = paths.iter().map(|path| path_signed_area(path)).sum::<f64>();
+    assert!((area.abs() - 84.0).abs() < 0.01, "{:?}", area);
+}
+
+#[test]
+fn compound_path_skips_empty_subpaths() {
+    let paths = CompoundPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .move_to(Coord2(5.0, 5.0))
+        .move_by(Coord2(1.0, 1.0))
+        .line_to(Coord2(10.0, 6.0))
+        .move_to(Coord2(20.0, 20.0))
+        .build();
+
+    assert!(paths.len() == 1);
+    assert!(paths[0].start_point() == Coord2(6.0, 6.0));
+}
+
+#[test]
+fn compound_path_continues_after_close() {
+    let paths = CompoundPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .close()
+        .line_to(Coord2(-10.0, 0.0))
+        .build();
+
+    assert!(paths.len() == 2);
+    assert!(paths[1].start_point() == Coord2(0.0, 0.0));
+}
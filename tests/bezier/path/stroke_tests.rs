@@ -41,3 +41,458 @@ fn stroke_closes_path_1() {
     let end_point   = curves.last().unwrap().end_point();
     assert!(end_point == start_point, "Path should be closed ({:?} != {:?}), curves are {:?}", start_point, end_point, curves);
 }
+
+#[test]
+fn stroke_dashed_line() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 1.0, &StrokeOptions::default().with_dash_array(vec![2.0, 1.0]).with_dash_offset(0.0));
+
+    // One stroke per dash, each closed and covering the dash
+    assert!(stroked_path.len() == 4, "Should be 4 dashes, found {}", stroked_path.len());
+
+    for (dash, (min_x, max_x)) in stroked_path.iter().zip(vec![(0.0, 2.0), (3.0, 5.0), (6.0, 8.0), (9.0, 10.0)]) {
+        let bounds = dash.bounding_box::<Bounds<_>>();
+
+        assert!((bounds.min().x() - min_x).abs() < 0.01, "{:?} {:?}", bounds, min_x);
+        assert!((bounds.max().x() - max_x).abs() < 0.01, "{:?} {:?}", bounds, max_x);
+        assert!((bounds.min().y() - -0.5).abs() < 0.01, "{:?}", bounds);
+        assert!((bounds.max().y() - 0.5).abs() < 0.01, "{:?}", bounds);
+    }
+}
+
+#[test]
+fn stroke_dotted_line() {
+    // Zero-length dashes with round caps are drawn as circles
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let options         = StrokeOptions::default().with_start_cap(LineCap::Round).with_end_cap(LineCap::Round).with_dash_array(vec![0.0, 5.0]);
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 2.0, &options);
+
+    assert!(stroked_path.len() == 2, "Should be 2 dots, found {}", stroked_path.len());
+
+    for (dot, center) in stroked_path.iter().zip(vec![Coord2(0.0, 0.0), Coord2(5.0, 0.0)]) {
+        let bounds = dot.bounding_box::<Bounds<_>>();
+
+        assert!(bounds.min().distance_to(&(center - Coord2(1.0, 1.0))) < 0.01, "{:?} {:?}", bounds, center);
+        assert!(bounds.max().distance_to(&(center + Coord2(1.0, 1.0))) < 0.01, "{:?} {:?}", bounds, center);
+        assert!((path_signed_area(dot).abs() - std::f64::consts::PI).abs() < 0.01, "{:?}", path_signed_area(dot));
+    }
+}
+
+#[test]
+fn stroke_dotted_line_with_butt_caps_is_empty() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 2.0, &StrokeOptions::default().with_dash_array(vec![0.0, 5.0]));
+
+    assert!(stroked_path.is_empty(), "{:?}", stroked_path);
+}
+
+#[test]
+fn stroke_round_caps() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let options         = StrokeOptions::default().with_start_cap(LineCap::Round).with_end_cap(LineCap::Round);
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 2.0, &options);
+
+    assert!(stroked_path.len() == 1);
+
+    // Caps are semicircles around the ends of the line
+    let bounds = stroked_path[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-1.0, -1.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(11.0, 1.0)) < 0.01, "{:?}", bounds);
+    assert!((path_signed_area(&stroked_path[0]).abs() - (20.0 + std::f64::consts::PI)).abs() < 0.01, "{:?}", path_signed_area(&stroked_path[0]));
+
+    for curve in stroked_path[0].to_curves::<Curve<_>>() {
+        for sample in 0..=10 {
+            let point = curve.point_at_pos((sample as f64) / 10.0);
+            assert!(point.distance_to(&Coord2(point.x().max(0.0).min(10.0), 0.0)) < 1.01, "{:?}", point);
+        }
+    }
+}
+
+#[test]
+fn stroke_square_caps() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let options         = StrokeOptions::default().with_start_cap(LineCap::Square).with_end_cap(LineCap::Butt);
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 2.0, &options);
+
+    assert!(stroked_path.len() == 1);
+
+    // Only the start of the line is extended
+    let bounds = stroked_path[0].bounding_box::<Bounds<_>>();
+    assert!(bounds.min().distance_to(&Coord2(-1.0, -1.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(10.0, 1.0)) < 0.01, "{:?}", bounds);
+    assert!((path_signed_area(&stroked_path[0]).abs() - 22.0).abs() < 0.01, "{:?}", path_signed_area(&stroked_path[0]));
+}
+
+#[test]
+fn stroke_dashes_have_caps() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let options         = StrokeOptions::default().with_start_cap(LineCap::Square).with_end_cap(LineCap::Square).with_dash_array(vec![2.0, 2.0]);
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&line, 1.0, &options);
+
+    assert!(stroked_path.len() == 3, "Should be 3 dashes, found {}", stroked_path.len());
+
+    for (dash, (min_x, max_x)) in stroked_path.iter().zip(vec![(-0.5, 2.5), (3.5, 6.5), (7.5, 10.5)]) {
+        let bounds = dash.bounding_box::<Bounds<_>>();
+
+        assert!((bounds.min().x() - min_x).abs() < 0.01, "{:?} {:?}", bounds, min_x);
+        assert!((bounds.max().x() - max_x).abs() < 0.01, "{:?} {:?}", bounds, max_x);
+    }
+}
+
+#[test]
+fn stroke_dashed_tapered_line() {
+    // The width is measured along the whole line, not restarted for each dash
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let stroked_path    = stroke_path_with_width::<SimpleBezierPath, _>(&line, |pos| 1.0 + pos, &StrokeOptions::default().with_dash_array(vec![2.0, 2.0]));
+
+    assert!(stroked_path.len() == 3, "Should be 3 dashes, found {}", stroked_path.len());
+
+    // The last dash runs from 8 to 10, so is between 1.8 and 2 units wide
+    let bounds = stroked_path[2].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().y() - 1.0).abs() < 0.01, "{:?}", bounds);
+
+    assert!(path_contains_point(&stroked_path[2], &Coord2(8.1, 0.85)));
+    assert!(!path_contains_point(&stroked_path[2], &Coord2(8.1, 0.95)));
+
+    // The first dash is between 1 and 1.2 units wide
+    let bounds = stroked_path[0].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().y() - 0.6).abs() < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_dashed_line_with_curve_width() {
+    // The curve indexes are for the original path, so the dash that crosses the corner changes width at the corner
+    let path            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let width_for_pos   = |curve_idx, _t| if curve_idx == 0 { 1.0 } else { 3.0 };
+    let stroked_path    = stroke_path_with_curve_width::<SimpleBezierPath, _>(&path, width_for_pos, &StrokeOptions::default().with_dash_array(vec![3.0, 1.0]));
+
+    // Dashes at 0-3, 4-7, 8-11 (crossing the corner), 12-15, 16-19
+    assert!(stroked_path.len() == 5, "Should be 5 dashes, found {}", stroked_path.len());
+
+    let bounds = stroked_path[1].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().y() - 0.5).abs() < 0.01, "{:?}", bounds);
+
+    let bounds = stroked_path[3].bounding_box::<Bounds<_>>();
+    assert!((bounds.min().x() - 8.5).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.max().x() - 11.5).abs() < 0.01, "{:?}", bounds);
+}
+
+fn stroke_bounds(path: &SimpleBezierPath, options: &StrokeOptions) -> Bounds<Coord2> {
+    let stroked_path = stroke_path::<SimpleBezierPath, _>(path, 1.0, options);
+
+    assert!(stroked_path.len() == 1, "Should be 1 subpath, found {}", stroked_path.len());
+    stroked_path[0].bounding_box::<Bounds<_>>()
+}
+
+#[test]
+fn stroke_near_reversing_miter_falls_back_to_bevel() {
+    // The miter for this corner would extend a long way past the end of the line
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(0.0, 0.5)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Miter));
+
+    assert!(bounds.max().x() < 10.1, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_near_reversing_miter_with_large_limit() {
+    // With a large enough miter limit, the miter can reach its tip
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(0.0, 0.5)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Miter).with_miter_limit(100.0));
+
+    assert!(bounds.max().x() > 14.0, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_near_reversing_miter_clip() {
+    // The miter is cut off at the miter limit (4 * half the width)
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(0.0, 0.5)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::MiterClip));
+
+    assert!((bounds.max().x() - 12.0).abs() < 0.1, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_exactly_reversing_miter_clip() {
+    // Lines that double back on themselves have no miter point, but can still be clipped
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(0.0, 0.0)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::MiterClip).with_miter_limit(2.0));
+
+    assert!((bounds.max().x() - 11.0).abs() < 0.1, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_near_reversing_arcs() {
+    // Straight lines have no curvature, so the arcs join behaves like miter-clip
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(0.0, 0.5)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Arcs));
+
+    assert!((bounds.max().x() - 12.0).abs() < 0.1, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_right_angle_miter() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+
+    let miter   = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Miter));
+    let clip    = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::MiterClip));
+    let arcs    = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Arcs));
+
+    // Right angles are well within the default miter limit, so these all produce the same corner
+    for bounds in vec![miter, clip, arcs] {
+        assert!((bounds.max().x() - 10.5).abs() < 0.01, "{:?}", bounds);
+        assert!((bounds.min().y() - -0.5).abs() < 0.01, "{:?}", bounds);
+    }
+}
+
+#[test]
+fn stroke_round_join_has_butt_ends() {
+    // The ends of the line are caps rather than joins, so they shouldn't be rounded
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let bounds  = stroke_bounds(&path, &StrokeOptions::default().with_join(LineJoin::Round));
+
+    assert!((bounds.max().y() - 10.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.min().x() - 0.0).abs() < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_ends_use_caps_for_every_join() {
+    // The line reverses at the end of the path, which is drawn with the cap and not the join
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+
+    for join in vec![LineJoin::Miter, LineJoin::Round, LineJoin::Bevel, LineJoin::MiterClip, LineJoin::Arcs] {
+        let butt_bounds     = stroke_bounds(&path, &StrokeOptions::default().with_join(join));
+        let square_bounds   = stroke_bounds(&path, &StrokeOptions::default().with_join(join).with_start_cap(LineCap::Square).with_end_cap(LineCap::Square));
+
+        assert!((butt_bounds.max().y() - 10.0).abs() < 0.01, "{:?} {:?}", join, butt_bounds);
+        assert!((butt_bounds.min().x() - 0.0).abs() < 0.01, "{:?} {:?}", join, butt_bounds);
+        assert!((square_bounds.max().y() - 10.5).abs() < 0.01, "{:?} {:?}", join, square_bounds);
+        assert!((square_bounds.min().x() - -0.5).abs() < 0.01, "{:?} {:?}", join, square_bounds);
+    }
+}
+
+#[test]
+fn stroke_round_join_at_hairpin() {
+    // Where the path doubles back on itself, the join goes around the end of the line
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(5.0, 0.0)).build();
+    let stroked = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &StrokeOptions::default().with_join(LineJoin::Round));
+    let bounds  = stroked.iter().map(|path| path.bounding_box::<Bounds<_>>()).fold(Bounds::empty(), |a, b| a.union_bounds(b));
+
+    assert!((bounds.max().x() - 11.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.min().x() - 0.0).abs() < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_bevel_join_at_hairpin() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(5.0, 0.0)).build();
+    let stroked = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &StrokeOptions::default().with_join(LineJoin::Bevel));
+    let bounds  = stroked.iter().map(|path| path.bounding_box::<Bounds<_>>()).fold(Bounds::empty(), |a, b| a.union_bounds(b));
+
+    assert!((bounds.max().x() - 10.0).abs() < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_tapered_line() {
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let stroked_path    = stroke_path_with_width::<SimpleBezierPath, _>(&line, |pos| 1.0 + pos, &StrokeOptions::default());
+
+    assert!(stroked_path.len() == 1, "Should be 1 subpath, found {}", stroked_path.len());
+
+    // Line is 1 unit wide at the start and 2 units wide at the end
+    let bounds = stroked_path[0].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().y() - 1.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.min().y() - -1.0).abs() < 0.01, "{:?}", bounds);
+
+    assert!(!path_contains_point(&stroked_path[0], &Coord2(1.0, 0.6)));
+    assert!(path_contains_point(&stroked_path[0], &Coord2(1.0, 0.5)));
+    assert!(path_contains_point(&stroked_path[0], &Coord2(9.0, 0.9)));
+    assert!(path_contains_point(&stroked_path[0], &Coord2(9.0, -0.9)));
+}
+
+#[test]
+fn stroke_tapered_line_uses_arc_length() {
+    // The middle of the length of the path is at x=5 even though the second curve is much longer than the first
+    let line            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(1.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let stroked_path    = stroke_path_with_width::<SimpleBezierPath, _>(&line, |pos| if pos < 0.5 { 1.0 } else { 1.0 + (pos-0.5) * 4.0 }, &StrokeOptions::default());
+
+    assert!(path_contains_point(&stroked_path[0], &Coord2(4.5, 0.45)));
+    assert!(!path_contains_point(&stroked_path[0], &Coord2(4.5, 0.55)));
+    assert!(path_contains_point(&stroked_path[0], &Coord2(7.5, 0.9)));
+}
+
+#[test]
+fn stroke_constant_curve_width_matches_stroke_path() {
+    let path        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let options     = StrokeOptions::default().with_join(LineJoin::Round);
+
+    let fixed       = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &options);
+    let variable    = stroke_path_with_curve_width::<SimpleBezierPath, _>(&path, |_, _| 2.0, &options);
+
+    assert!(fixed == variable);
+}
+
+#[test]
+fn stroke_widening_curve_with_join() {
+    // The first curve has a fixed width, and the second widens to 4 units
+    let path            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+    let options         = StrokeOptions::default().with_join(LineJoin::Miter);
+    let stroked_path    = stroke_path_with_curve_width::<SimpleBezierPath, _>(&path, |curve_idx, t| if curve_idx == 0 { 2.0 } else { 2.0 + t*2.0 }, &options);
+
+    assert!(stroked_path.len() == 1, "Should be 1 subpath, found {}", stroked_path.len());
+
+    let bounds = stroked_path[0].bounding_box::<Bounds<_>>();
+    assert!((bounds.max().x() - 12.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.min().x() - 0.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.min().y() - -1.0).abs() < 0.01, "{:?}", bounds);
+    assert!((bounds.max().y() - 10.0).abs() < 0.01, "{:?}", bounds);
+
+    // Miter join at the corner
+    assert!(path_contains_point(&stroked_path[0], &Coord2(10.9, -0.9)));
+}
+
+fn square_path(size: f64) -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(size, 0.0))
+        .line_to(Coord2(size, size))
+        .line_to(Coord2(0.0, size))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn stroke_inside_square() {
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &StrokeOptions::default().with_alignment(StrokeAlignment::Inside));
+
+    // Ring between the square and a square inset by 2 units
+    assert!(stroked.len() == 2, "{:?}", stroked);
+    assert!((paths_area(&stroked) - (100.0 - 36.0)).abs() < 0.01, "{}", paths_area(&stroked));
+
+    let bounds = stroked.iter().map(|path| path.bounding_box::<Bounds<_>>()).fold(Bounds::empty(), |a, b| a.union_bounds(b));
+    assert!(bounds.min().distance_to(&Coord2(0.0, 0.0)) < 0.01, "{:?}", bounds);
+    assert!(bounds.max().distance_to(&Coord2(10.0, 10.0)) < 0.01, "{:?}", bounds);
+}
+
+#[test]
+fn stroke_outside_square_with_miter_joins() {
+    let options = StrokeOptions::default().with_alignment(StrokeAlignment::Outside).with_join(LineJoin::Miter);
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+
+    // Ring between the square and a square outset by 2 units (all four corners are mitered, including the one where the path starts)
+    assert!(stroked.len() == 2, "{:?}", stroked);
+    assert!((paths_area(&stroked) - (196.0 - 100.0)).abs() < 0.01, "{}", paths_area(&stroked));
+    assert!(path_winding_number(&stroked, &Coord2(-1.9, -1.9)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(11.9, 11.9)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+}
+
+#[test]
+fn stroke_outside_square_with_bevel_joins() {
+    let options = StrokeOptions::default().with_alignment(StrokeAlignment::Outside).with_join(LineJoin::Bevel);
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+
+    // Each corner is cut off by a triangle with an area of 2
+    assert!((paths_area(&stroked) - (196.0 - 100.0 - 8.0)).abs() < 0.01, "{}", paths_area(&stroked));
+}
+
+#[test]
+fn stroke_outside_open_path_is_centered() {
+    let path        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).build();
+    let centered    = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &StrokeOptions::default());
+    let outside     = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &StrokeOptions::default().with_alignment(StrokeAlignment::Outside));
+
+    assert!(centered == outside);
+}
+
+#[test]
+fn stroke_inside_dashed_square() {
+    let options = StrokeOptions::default().with_alignment(StrokeAlignment::Inside).with_dash_array(vec![5.0, 5.0]);
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 1.0, &options);
+
+    // Dashes cover the first half of each side
+    assert!(path_winding_number(&stroked, &Coord2(2.5, 0.5)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(7.5, 0.5)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(2.5, -0.5)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(9.5, 2.5)) & 1 != 0);
+}
+
+#[test]
+fn stroke_round_joins_stay_within_width() {
+    // The arcs for the round joins should go around the outside of each corner, so no part of the stroke is further than half the width from the path
+    let path            = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).line_to(Coord2(0.0, 0.0)).build();
+    let path_curves     = path.to_curves::<Curve<_>>();
+    let stroked_path    = stroke_path::<SimpleBezierPath, _>(&path, 2.0, &StrokeOptions::default().with_join(LineJoin::Round));
+
+    for curve in stroked_path[0].to_curves::<Curve<_>>() {
+        for sample in 0..=10 {
+            let point       = curve.point_at_pos((sample as f64) / 10.0);
+            let distance    = path_curves.iter()
+                .map(|path_curve| path_curve.point_at_pos(path_curve.nearest_t(&point)).distance_to(&point))
+                .fold(f64::MAX, f64::min);
+
+            assert!(distance < 1.01, "{:?} is {} from the path", point, distance);
+        }
+    }
+}
+
+#[test]
+fn stroke_closed_path_joins_start_corner() {
+    for join in [LineJoin::Miter, LineJoin::Round, LineJoin::Bevel] {
+        let options = StrokeOptions::default().with_join(join).with_remove_interior_points();
+        let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+
+        // The corner where the path starts is joined in the same way as the other corners
+        for (corner, outside) in [(Coord2(0.0, 0.0), Coord2(-1.0, -1.0)), (Coord2(10.0, 0.0), Coord2(1.0, -1.0)), (Coord2(10.0, 10.0), Coord2(1.0, 1.0)), (Coord2(0.0, 10.0), Coord2(-1.0, 1.0))] {
+            assert!(path_winding_number(&stroked, &(corner + outside*0.3)) & 1 != 0, "{:?} {:?}", join, corner);
+            assert!(path_winding_number(&stroked, &(corner - outside*1.1)) & 1 == 0, "{:?} {:?}", join, corner);
+        }
+
+        assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+    }
+
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &StrokeOptions::default().with_join(LineJoin::Miter).with_remove_interior_points());
+    assert!((paths_area(&stroked) - (144.0 - 64.0)).abs() < 0.01, "{}", paths_area(&stroked));
+}
+
+#[test]
+fn stroke_closed_path_with_single_long_dash() {
+    // A dash that's longer than the path covers all of it, so it's stroked like a closed path
+    let options = StrokeOptions::default().with_join(LineJoin::Miter).with_remove_interior_points();
+    let solid   = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+    let dashed  = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options.clone().with_dash_array(vec![100.0, 10.0]));
+
+    assert!((paths_area(&dashed) - paths_area(&solid)).abs() < 0.01, "{} {}", paths_area(&dashed), paths_area(&solid));
+    assert!(path_winding_number(&dashed, &Coord2(-0.7, -0.7)) & 1 != 0);
+}
+
+#[test]
+fn stroke_with_zero_width_is_empty() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(10.0, 0.0)).line_to(Coord2(10.0, 10.0)).build();
+
+    assert!(stroke_path::<SimpleBezierPath, _>(&path, 0.0, &StrokeOptions::default()).is_empty());
+    assert!(stroke_path_with_width::<SimpleBezierPath, _>(&path, |_| 0.0, &StrokeOptions::default()).is_empty());
+    assert!(stroke_path_with_curve_width::<SimpleBezierPath, _>(&path, |_, _| 0.0, &StrokeOptions::default().with_dash_array(vec![2.0, 2.0])).is_empty());
+}
+
+#[test]
+fn stroke_inside_square_removing_interior_points() {
+    let options = StrokeOptions::default().with_alignment(StrokeAlignment::Inside).with_join(LineJoin::Miter).with_remove_interior_points();
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+
+    // Ring between the square and a square inset by 2 units
+    assert!((paths_area(&stroked) - (100.0 - 36.0)).abs() < 0.01, "{}", paths_area(&stroked));
+    assert!(path_winding_number(&stroked, &Coord2(1.0, 1.0)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(-0.5, 5.0)) & 1 == 0);
+}
+
+#[test]
+fn stroke_outside_square_removing_interior_points() {
+    let options = StrokeOptions::default().with_alignment(StrokeAlignment::Outside).with_join(LineJoin::Miter).with_remove_interior_points();
+    let stroked = stroke_path::<SimpleBezierPath, _>(&square_path(10.0), 2.0, &options);
+
+    // Ring between the square and a square outset by 2 units
+    assert!((paths_area(&stroked) - (196.0 - 100.0)).abs() < 0.01, "{}", paths_area(&stroked));
+    assert!(path_winding_number(&stroked, &Coord2(-1.9, -1.9)) & 1 != 0);
+    assert!(path_winding_number(&stroked, &Coord2(5.0, 5.0)) & 1 == 0);
+    assert!(path_winding_number(&stroked, &Coord2(1.0, 5.0)) & 1 == 0);
+}
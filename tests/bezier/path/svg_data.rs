@@ -0,0 +1,225 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+fn assert_near(actual: Coord2, expected: Coord2) {
+    assert!(actual.distance_to(&expected) < 1e-6, "{:?} != {:?}", actual, expected);
+}
+
+fn end_points(path: &SimpleBezierPath) -> Vec<Coord2> {
+    path.points().map(|(_, _, end_point)| end_point).collect()
+}
+
+#[test]
+fn parse_closed_rectangle() {
+    let paths = path_from_svg_data::<SimpleBezierPath>("M1,1 L5,1 L5,5 L1,5 Z").unwrap();
+
+    assert!(paths.len() == 1);
+    assert_near(paths[0].start_point(), Coord2(1.0, 1.0));
+    assert!(end_points(&paths[0]) == vec![Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0), Coord2(1.0, 1.0)]);
+}
+
+#[test]
+fn parse_relative_and_implicit_commands() {
+    let paths = path_from_svg_data::<SimpleBezierPath>("m10 10 5 0 0 5h-5v-5").unwrap();
+
+    assert!(paths.len() == 1);
+    assert_near(paths[0].start_point(), Coord2(10.0, 10.0));
+    assert!(end_points(&paths[0]) == vec![Coord2(15.0, 10.0), Coord2(15.0, 15.0), Coord2(10.0, 15.0), Coord2(10.0, 10.0)]);
+}
+
+#[test]
+fn parse_compact_numbers() {
+    let paths = path_from_svg_data::<SimpleBezierPath>("M-1.5.5L2e1-3E-1").unwrap();
+
+    assert_near(paths[0].start_point(), Coord2(-1.5, 0.5));
+    assert_near(end_points(&paths[0])[0], Coord2(20.0, -0.3));
+}
+
+#[test]
+fn parse_multiple_subpaths() {
+    let paths = path_from_svg_data::<SimpleBezierPath>("M0 0H10V10ZM20 20H30 M 100 100 m 5 5 l 1 1").unwrap();
+
+    // The lone move command produces no path
+    assert!(paths.len() == 3);
+    assert_near(paths[1].start_point(), Coord2(20.0, 20.0));
+    assert_near(paths[2].start_point(), Coord2(105.0, 105.0));
+    assert_near(end_points(&paths[2])[0], Coord2(106.0, 106.0));
+}
+
+#[test]
+fn parse_command_after_close_starts_from_subpath_start() {
+    let paths = path_from_svg_data::<SimpleBezierPath>("M5 5h10v10zl-5 0").unwrap();
+
+    assert!(paths.len() == 2);
+    assert_near(paths[1].start_point(), Coord2(5.0, 5.0));
+    assert_near(end_points(&paths[1])[0], Coord2(0.0, 5.0));
+}
+
+#[test]
+fn parse_smooth_cubic_reflects_control_point() {
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M0 0C0 10 10 10 10 0S20 -10 20 0").unwrap();
+    let points  = paths[0].points().collect::<Vec<_>>();
+
+    assert!(points.len() == 2);
+    assert_near(points[1].0, Coord2(10.0, -10.0));
+    assert_near(points[1].1, Coord2(20.0, -10.0));
+}
+
+#[test]
+fn parse_smooth_cubic_without_previous_curve_uses_current_point() {
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M0 0L5 0S10 10 10 0").unwrap();
+    let points  = paths[0].points().collect::<Vec<_>>();
+
+    assert_near(points[1].0, Coord2(5.0, 0.0));
+}
+
+#[test]
+fn parse_quadratic_curves() {
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M0 0Q5 10 10 0T20 0").unwrap();
+    let curves  = paths[0].to_curves::<bezier::Curve<_>>();
+
+    // A quadratic with control point (5, 10) has its midpoint at (5, 5)
+    assert_near(curves[0].point_at_pos(0.5), Coord2(5.0, 5.0));
+
+    // The smooth quadratic reflects the control point to (15, -10)
+    assert_near(curves[1].point_at_pos(0.5), Coord2(15.0, -5.0));
+}
+
+#[test]
+fn parse_semicircular_arc() {
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M0 0A10 10 0 0 1 20 0").unwrap();
+    let curves  = paths[0].to_curves::<bezier::Curve<_>>();
+
+    assert!(curves.len() == 2);
+    assert_near(curves[1].end_point(), Coord2(20.0, 0.0));
+
+    for curve in curves.iter() {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!((pos.distance_to(&Coord2(10.0, 0.0)) - 10.0).abs() < 0.01);
+
+            // Sweep flag 1 goes in the direction of increasing angle, which is through negative y here
+            assert!(pos.y() <= 1e-6);
+        }
+    }
+}
+
+#[test]
+fn parse_arc_with_compact_flags_and_small_radius() {
+    // The radius is too small to reach the end point, so it's scaled up to make a semicircle
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M0 0a1 1 0 0020 0").unwrap();
+    let curves  = paths[0].to_curves::<bezier::Curve<_>>();
+
+    assert_near(curves.last().unwrap().end_point(), Coord2(20.0, 0.0));
+    for curve in curves.iter() {
+        let pos = curve.point_at_pos(0.5);
+        assert!((pos.distance_to(&Coord2(10.0, 0.0)) - 10.0).abs() < 0.01);
+        assert!(pos.y() >= 0.0);
+    }
+}
+
+#[test]
+fn parse_large_rotated_elliptical_arc() {
+    let paths   = path_from_svg_data::<SimpleBezierPath>("M10 0A20 10 90 1 0 0 20").unwrap();
+    let curves  = paths[0].to_curves::<bezier::Curve<_>>();
+
+    // Rotated by 90 degrees, so the ellipse is 10 wide and 20 high, and the large arc covers 3/4 of it
+    assert!(curves.len() == 3);
+    assert_near(curves[2].end_point(), Coord2(0.0, 20.0));
+
+    let center = Coord2(0.0, 0.0);
+    for curve in curves.iter() {
+        for t in 0..=10 {
+            let pos         = curve.point_at_pos((t as f64)/10.0) - center;
+            let on_ellipse  = (pos.x()/10.0).powi(2) + (pos.y()/20.0).powi(2);
+
+            assert!((on_ellipse - 1.0).abs() < 0.01, "{:?} {:?}", pos, on_ellipse);
+        }
+    }
+}
+
+#[test]
+fn parse_errors() {
+    assert!(path_from_svg_data::<SimpleBezierPath>("L10 10") == Err(SvgPathError::MissingMoveTo { position: 0 }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 L10") == Err(SvgPathError::MissingParameters { position: 5, command: 'L' }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 C1 1 2 2 Z") == Err(SvgPathError::MissingParameters { position: 5, command: 'C' }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 L10 x") == Err(SvgPathError::UnexpectedCharacter { position: 9, character: 'x' }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 X") == Err(SvgPathError::UnexpectedCharacter { position: 5, character: 'X' }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 L- 5") == Err(SvgPathError::InvalidNumber { position: 6 }));
+    assert!(path_from_svg_data::<SimpleBezierPath>("M0 0 A5 5 0 2 0 10 0") == Err(SvgPathError::InvalidFlag { position: 12 }));
+
+    let message = path_from_svg_data::<SimpleBezierPath>("M0 0 L10").unwrap_err().to_string();
+    assert!(message == "missing parameters for 'L' command at position 5", "{}", message);
+}
+
+#[test]
+fn parse_empty_data() {
+    assert!(path_from_svg_data::<SimpleBezierPath>("").unwrap().is_empty());
+    assert!(path_from_svg_data::<SimpleBezierPath>("  \n ").unwrap().is_empty());
+}
+
+#[test]
+fn write_rectangle() {
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    assert!(path_to_svg_data(&[rectangle], 2) == "M1 1H5V5H1Z");
+}
+
+#[test]
+fn write_compact_numbers() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.5, -0.25))
+        .line_to(Coord2(-10.0, 20.125))
+        .line_to(Coord2(3.0, 0.75))
+        .line_to(Coord2(3.1, 0.5))
+        .build();
+
+    let data = path_to_svg_data(&[path], 2);
+    assert!(data == "M.5-.25L-10 20.13 3 .75 3.1.5", "{}", data);
+}
+
+#[test]
+fn write_curves_and_multiple_paths() {
+    let curve   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .curve_to((Coord2(0.0, 10.0), Coord2(10.0, 10.0)), Coord2(10.0, 0.0))
+        .curve_to((Coord2(10.0, -10.0), Coord2(20.0, -10.0)), Coord2(20.0, 0.0))
+        .build();
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(0.0, -5.0)).build();
+
+    let data = path_to_svg_data(&[curve, line], 3);
+    assert!(data == "M0 0C0 10 10 10 10 0 10-10 20-10 20 0M0 0V-5", "{}", data);
+}
+
+#[test]
+fn circle_round_trip() {
+    let circle      = Circle::new(Coord2(50.0, 50.0), 25.0).to_path::<SimpleBezierPath>();
+    let data        = path_to_svg_data(&[circle.clone()], 4);
+    let parsed      = path_from_svg_data::<SimpleBezierPath>(&data).unwrap();
+
+    assert!(data.ends_with('Z'));
+    assert!(parsed.len() == 1);
+    assert!(parsed[0].start_point().distance_to(&circle.start_point()) < 0.0001);
+
+    let original    = circle.points().collect::<Vec<_>>();
+    let round_trip  = parsed[0].points().collect::<Vec<_>>();
+
+    assert!(original.len() == round_trip.len());
+    for ((cp1, cp2, end), (rcp1, rcp2, rend)) in original.into_iter().zip(round_trip.into_iter()) {
+        assert!(cp1.distance_to(&rcp1) < 0.0001);
+        assert!(cp2.distance_to(&rcp2) < 0.0001);
+        assert!(end.distance_to(&rend) < 0.0001);
+    }
+}
+
+#[test]
+fn parsed_lines_round_trip() {
+    let data    = "M10 10H20V20L15 25 10 20Z";
+    let paths   = path_from_svg_data::<SimpleBezierPath>(data).unwrap();
+
+    assert!(path_to_svg_data(&paths, 3) == data);
+}
@@ -398,3 +398,27 @@ fn chisel_columns() {
     check_columns_vs_rows(&chisel_contour);
 }
 */
+
+#[test]
+fn doughnut_with_non_zero_fill_rule() {
+    // Both circles go in the same direction, so the non-zero rule should fill the hole in the middle
+    let center          = Coord2(500.0, 500.0);
+    let outer_circle    = Circle::new(center, 300.0).to_path::<SimpleBezierPath>();
+    let inner_circle    = Circle::new(center, 200.0).to_path::<SimpleBezierPath>();
+
+    let even_odd        = PathContour::from_path(vec![outer_circle.clone(), inner_circle.clone()], ContourSize(1000, 1000));
+    let non_zero        = PathContour::from_path(vec![outer_circle.clone(), inner_circle.clone()], ContourSize(1000, 1000)).with_fill_rule(FillRule::NonZero);
+    let reversed        = PathContour::from_path(vec![outer_circle, inner_circle.reversed::<SimpleBezierPath>()], ContourSize(1000, 1000)).with_fill_rule(FillRule::NonZero);
+
+    assert!(even_odd.intercepts_on_line(500.5).len() == 2);
+    assert!(reversed.intercepts_on_line(500.5).len() == 2);
+
+    let intercepts = non_zero.intercepts_on_line(500.5);
+    assert!(intercepts.len() == 1, "{:?}", intercepts);
+    assert!((intercepts[0].start - 200.0).abs() < 2.0 && (intercepts[0].end - 800.0).abs() < 2.0, "{:?}", intercepts);
+
+    let intercepts = non_zero.intercepts_on_column(500.5);
+    assert!(intercepts.len() == 1, "{:?}", intercepts);
+
+    check_columns_vs_rows(&non_zero);
+}
@@ -0,0 +1,80 @@
+use flo_curves::*;
+use flo_curves::line::*;
+
+#[test]
+fn skew_rays_closest_approach() {
+    let ray1        = (Coord3(0.0, 0.0, 0.0), Coord3(1.0, 0.0, 0.0));
+    let ray2        = (Coord3(0.0, 0.0, 2.0), Coord3(0.0, 1.0, 2.0));
+    let (t1, t2)    = ray_closest_approach(&ray1, &ray2).unwrap();
+
+    assert!(ray1.point_at_pos(t1).distance_to(&Coord3(0.0, 0.0, 0.0)) < 0.001);
+    assert!(ray2.point_at_pos(t2).distance_to(&Coord3(0.0, 0.0, 2.0)) < 0.001);
+}
+
+#[test]
+fn skew_rays_closest_approach_outside_segments() {
+    let ray1        = (Coord3(1.0, 0.0, 0.0), Coord3(2.0, 0.0, 0.0));
+    let ray2        = (Coord3(5.0, -3.0, 1.0), Coord3(5.0, -2.0, 1.0));
+    let (t1, t2)    = ray_closest_approach(&ray1, &ray2).unwrap();
+
+    assert!((t1-4.0).abs() < 0.001);
+    assert!((t2-3.0).abs() < 0.001);
+}
+
+#[test]
+fn parallel_rays_have_no_closest_approach() {
+    let ray1 = (Coord3(0.0, 0.0, 0.0), Coord3(1.0, 1.0, 1.0));
+    let ray2 = (Coord3(0.0, 1.0, 0.0), Coord3(2.0, 3.0, 2.0));
+
+    assert!(ray_closest_approach(&ray1, &ray2).is_none());
+}
+
+#[test]
+fn segments_closest_approach_is_clamped() {
+    let line1       = (Coord3(1.0, 0.0, 0.0), Coord3(2.0, 0.0, 0.0));
+    let line2       = (Coord3(5.0, -3.0, 1.0), Coord3(5.0, -2.0, 1.0));
+    let (t1, t2)    = line_closest_approach(&line1, &line2);
+
+    assert!((t1-1.0).abs() < 0.001);
+    assert!((t2-1.0).abs() < 0.001);
+}
+
+#[test]
+fn crossing_segments_closest_approach_is_intersection() {
+    let line1       = (Coord3(0.0, 0.0, 0.0), Coord3(2.0, 2.0, 2.0));
+    let line2       = (Coord3(2.0, 0.0, 0.0), Coord3(0.0, 2.0, 2.0));
+    let (t1, t2)    = line_closest_approach(&line1, &line2);
+
+    assert!(line1.point_at_pos(t1).distance_to(&line2.point_at_pos(t2)) < 0.001);
+    assert!(line1.point_at_pos(t1).distance_to(&Coord3(1.0, 1.0, 1.0)) < 0.001);
+}
+
+#[test]
+fn parallel_segments_closest_approach() {
+    let line1       = (Coord3(0.0, 0.0, 0.0), Coord3(10.0, 0.0, 0.0));
+    let line2       = (Coord3(12.0, 1.0, 0.0), Coord3(20.0, 1.0, 0.0));
+    let (t1, t2)    = line_closest_approach(&line1, &line2);
+
+    assert!((t1-1.0).abs() < 0.001);
+    assert!(t2.abs() < 0.001);
+}
+
+#[test]
+fn point_to_segment_closest_approach() {
+    let point       = (Coord3(5.0, 3.0, 4.0), Coord3(5.0, 3.0, 4.0));
+    let line        = (Coord3(0.0, 0.0, 0.0), Coord3(10.0, 0.0, 0.0));
+    let (t1, t2)    = line_closest_approach(&point, &line);
+
+    assert!(t1.abs() < 0.001);
+    assert!((t2-0.5).abs() < 0.001);
+}
+
+#[test]
+fn closest_approach_in_2d() {
+    let line1       = (Coord2(0.0, 0.0), Coord2(10.0, 0.0));
+    let line2       = (Coord2(5.0, 1.0), Coord2(5.0, 10.0));
+    let (t1, t2)    = line_closest_approach(&line1, &line2);
+
+    assert!((t1-0.5).abs() < 0.001);
+    assert!(t2.abs() < 0.001);
+}
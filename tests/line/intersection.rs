@@ -118,3 +118,105 @@ fn line_out_of_bounds_crossing() {
     assert!(clipped.is_none());
 }
 
+
+#[test]
+fn segments_cross_at_point() {
+    let intersection = line_intersection(&(Coord2(10.0, 20.0), Coord2(50.0, 60.0)), &(Coord2(10.0, 45.0), Coord2(50.0, 35.0)));
+
+    match intersection {
+        LineIntersection::Point { point, t1, t2 } => {
+            assert!(point.distance_to(&Coord2(30.0, 40.0)) < 0.01);
+            assert!((t1-0.5).abs() < 0.001);
+            assert!((t2-0.5).abs() < 0.001);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
+
+#[test]
+fn segments_do_not_cross() {
+    assert!(line_intersection(&(Coord2(12.0, 13.0), Coord2(24.0, 30.0)), &(Coord2(1.0, 1.0), Coord2(0.0, -1.0))) == LineIntersection::None);
+}
+
+#[test]
+fn parallel_segments_do_not_cross() {
+    assert!(line_intersection(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(0.0, 1.0), Coord2(10.0, 1.0))) == LineIntersection::None);
+}
+
+#[test]
+fn collinear_segments_overlap() {
+    let intersection = line_intersection(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(5.0, 0.0), Coord2(15.0, 0.0)));
+
+    match intersection {
+        LineIntersection::Overlap { t1, t2 } => {
+            assert!((t1.0-0.5).abs() < 0.001 && (t1.1-1.0).abs() < 0.001, "{:?}", t1);
+            assert!((t2.0-0.0).abs() < 0.001 && (t2.1-0.5).abs() < 0.001, "{:?}", t2);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
+
+#[test]
+fn collinear_segments_overlap_in_opposite_directions() {
+    let intersection = line_intersection(&(Coord2(0.0, 0.0), Coord2(10.0, 10.0)), &(Coord2(8.0, 8.0), Coord2(-2.0, -2.0)));
+
+    match intersection {
+        LineIntersection::Overlap { t1, t2 } => {
+            assert!((t1.0-0.0).abs() < 0.001 && (t1.1-0.8).abs() < 0.001, "{:?}", t1);
+            assert!((t2.0-0.8).abs() < 0.001 && (t2.1-0.0).abs() < 0.001, "{:?}", t2);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
+
+#[test]
+fn collinear_segment_inside_other() {
+    let intersection = line_intersection(&(Coord2(0.0, 0.0), Coord2(0.0, 10.0)), &(Coord2(0.0, 2.0), Coord2(0.0, 4.0)));
+
+    match intersection {
+        LineIntersection::Overlap { t1, t2 } => {
+            assert!((t1.0-0.2).abs() < 0.001 && (t1.1-0.4).abs() < 0.001, "{:?}", t1);
+            assert!((t2.0-0.0).abs() < 0.001 && (t2.1-1.0).abs() < 0.001, "{:?}", t2);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
+
+#[test]
+fn collinear_segments_touching_at_end() {
+    let intersection = line_intersection(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(10.0, 0.0), Coord2(20.0, 0.0)));
+
+    match intersection {
+        LineIntersection::Point { point, t1, t2 } => {
+            assert!(point.distance_to(&Coord2(10.0, 0.0)) < 0.001);
+            assert!((t1-1.0).abs() < 0.001);
+            assert!(t2.abs() < 0.001);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
+
+#[test]
+fn collinear_segments_with_gap() {
+    assert!(line_intersection(&(Coord2(0.0, 0.0), Coord2(10.0, 0.0)), &(Coord2(11.0, 0.0), Coord2(20.0, 0.0))) == LineIntersection::None);
+}
+
+#[test]
+fn point_on_segment() {
+    let intersection = line_intersection(&(Coord2(3.0, 0.0), Coord2(3.0, 0.0)), &(Coord2(0.0, 0.0), Coord2(10.0, 0.0)));
+
+    match intersection {
+        LineIntersection::Point { point, t1, t2 } => {
+            assert!(point.distance_to(&Coord2(3.0, 0.0)) < 0.001);
+            assert!(t1.abs() < 0.001);
+            assert!((t2-0.3).abs() < 0.001);
+        }
+
+        _ => assert!(false, "{:?}", intersection)
+    }
+}
@@ -2,6 +2,8 @@
 
 mod to_curve;
 mod intersection;
+mod closest_approach;
+mod polyline;
 mod coefficients;
 mod nearest;
 mod angle;
@@ -0,0 +1,164 @@
+use flo_curves::*;
+use flo_curves::line::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+use flo_curves::arc::*;
+
+#[test]
+fn polyline_length_and_bounds() {
+    let polyline    = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(3.0, 4.0), Coord2(3.0, 10.0)]);
+    let bounds      = polyline.get_bounding_box::<Bounds<_>>();
+
+    assert!((polyline.length() - 11.0).abs() < 0.001);
+    assert!(bounds.min() == Coord2(0.0, 0.0));
+    assert!(bounds.max() == Coord2(3.0, 10.0));
+    assert!(polyline.lines().count() == 2);
+}
+
+#[test]
+fn polyline_nearest_point() {
+    let polyline                = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0)]);
+    let (index, t, nearest)     = polyline.nearest_point(&Coord2(12.0, 4.0)).unwrap();
+
+    assert!(index == 1);
+    assert!((t - 0.4).abs() < 0.001);
+    assert!(nearest.distance_to(&Coord2(10.0, 4.0)) < 0.001);
+
+    assert!(Polyline::<Coord2>::new(vec![]).nearest_point(&Coord2(0.0, 0.0)).is_none());
+}
+
+#[test]
+fn douglas_peucker_removes_nearly_straight_points() {
+    let polyline    = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(1.0, 0.1), Coord2(2.0, -0.1), Coord2(3.0, 5.0), Coord2(4.0, 6.0), Coord2(5.0, 7.0)]);
+    let simplified  = polyline.simplify_douglas_peucker(0.5);
+
+    assert!(simplified.points() == &[Coord2(0.0, 0.0), Coord2(2.0, -0.1), Coord2(3.0, 5.0), Coord2(5.0, 7.0)], "{:?}", simplified);
+}
+
+#[test]
+fn douglas_peucker_keeps_end_points() {
+    let polyline    = Polyline::new((0..100).map(|x| Coord2(x as f64, 0.0)));
+    let simplified  = polyline.simplify_douglas_peucker(0.1);
+
+    assert!(simplified.points() == &[Coord2(0.0, 0.0), Coord2(99.0, 0.0)]);
+}
+
+#[test]
+fn visvalingam_removes_small_triangles() {
+    let polyline    = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(1.0, 0.1), Coord2(2.0, 0.0), Coord2(3.0, 5.0), Coord2(4.0, 0.0)]);
+    let simplified  = polyline.simplify_visvalingam(1.0);
+
+    assert!(simplified.points() == &[Coord2(0.0, 0.0), Coord2(2.0, 0.0), Coord2(3.0, 5.0), Coord2(4.0, 0.0)], "{:?}", simplified);
+}
+
+#[test]
+fn visvalingam_simplifies_circle() {
+    let circle      = Polyline::new((0..=360).map(|degrees| {
+        let angle = (degrees as f64).to_radians();
+        Coord2(angle.sin()*100.0, angle.cos()*100.0)
+    }));
+    let simplified  = circle.simplify_visvalingam(50.0);
+
+    assert!(simplified.len() < 100);
+    assert!(simplified.len() > 8);
+    assert!(simplified.points()[0] == circle.points()[0]);
+    assert!(simplified.points()[simplified.len()-1] == circle.points()[circle.len()-1]);
+
+    for point in simplified.points() {
+        assert!((point.magnitude() - 100.0).abs() < 0.001);
+    }
+}
+
+#[test]
+fn polyline_to_path_and_back() {
+    let polyline    = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0)]);
+    let path        = polyline.to_path::<SimpleBezierPath>();
+
+    assert!(path.start_point() == Coord2(0.0, 0.0));
+    assert!(path.points().count() == 2);
+
+    let round_trip  = Polyline::from_path(&path, 0.01);
+    assert!(round_trip.points() == polyline.points(), "{:?}", round_trip);
+}
+
+#[test]
+fn polyline_from_circle_path() {
+    let circle      = Circle::new(Coord2(0.0, 0.0), 50.0).to_path::<SimpleBezierPath>();
+    let polyline    = Polyline::from_path(&circle, 0.01);
+
+    assert!(polyline.is_closed());
+    assert!(polyline.len() > 16);
+    assert!((polyline.length() - 2.0*std::f64::consts::PI*50.0).abs() < 1.0);
+
+    for point in polyline.points() {
+        assert!((point.magnitude() - 50.0).abs() < 0.1);
+    }
+}
+
+#[test]
+fn polyline_to_smooth_path() {
+    let points      = (0..=20).map(|x| { let x = x as f64 / 2.0; Coord2(x, x.sin()) }).collect::<Vec<_>>();
+    let polyline    = Polyline::new(points.clone());
+    let path        = polyline.to_smooth_path::<SimpleBezierPath>(0.01).unwrap();
+
+    assert!(path.start_point().distance_to(&Coord2(0.0, 0.0)) < 0.01);
+    assert!(path.points().last().unwrap().2.distance_to(&points[20]) < 0.01);
+
+    let curves = path_to_curves::<_, Curve<_>>(&path).collect::<Vec<_>>();
+    for point in points.iter() {
+        let nearest = curves.iter()
+            .map(|curve| curve.point_at_pos(curve.nearest_t(point)).distance_to(point))
+            .fold(f64::MAX, f64::min);
+        assert!(nearest < 0.05, "{:?} {}", point, nearest);
+    }
+
+    assert!(Polyline::new(vec![Coord2(0.0, 0.0)]).to_smooth_path::<SimpleBezierPath>(0.01).is_none());
+}
+
+#[test]
+fn polyline_intersects_line() {
+    let polyline        = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0), Coord2(20.0, 10.0)]);
+    let intersections   = polyline.intersects_line(&(Coord2(5.0, 5.0), Coord2(15.0, 5.0)));
+
+    assert!(intersections.len() == 1);
+    match intersections[0] {
+        (1, LineIntersection::Point { point, t1, .. }) => {
+            assert!(point.distance_to(&Coord2(10.0, 5.0)) < 0.001);
+            assert!((t1 - 0.5).abs() < 0.001);
+        }
+
+        _ => assert!(false, "{:?}", intersections)
+    }
+}
+
+#[test]
+fn polylines_overlap() {
+    let polyline1       = Polyline::new(vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0)]);
+    let polyline2       = Polyline::new(vec![Coord2(15.0, 5.0), Coord2(10.0, 5.0), Coord2(10.0, 2.0), Coord2(5.0, -5.0)]);
+    let intersections   = polyline1.intersects_polyline(&polyline2);
+
+    // Line 1 of polyline1 overlaps with line 1 of polyline2, and the end of line 0 of polyline2 touches line 1 of polyline1
+    let overlap = intersections.iter().filter(|(_, _, intersection)| matches!(intersection, LineIntersection::Overlap { .. })).collect::<Vec<_>>();
+    assert!(overlap.len() == 1, "{:?}", intersections);
+
+    match overlap[0] {
+        (1, 1, LineIntersection::Overlap { t1, t2 }) => {
+            assert!((t1.0 - 0.2).abs() < 0.001 && (t1.1 - 0.5).abs() < 0.001, "{:?}", t1);
+            assert!((t2.0 - 1.0).abs() < 0.001 && (t2.1 - 0.0).abs() < 0.001, "{:?}", t2);
+        }
+
+        _ => assert!(false, "{:?}", intersections)
+    }
+
+    // Line 2 of polyline2 crosses line 0 of polyline1
+    assert!(intersections.iter().any(|(idx1, idx2, intersection)| *idx1 == 0 && *idx2 == 2 && matches!(intersection, LineIntersection::Point { .. })));
+}
+
+#[test]
+fn polyline_in_3d() {
+    let polyline    = Polyline::new(vec![Coord3(0.0, 0.0, 0.0), Coord3(0.0, 0.0, 1.0), Coord3(0.0, 0.01, 2.0), Coord3(0.0, 0.0, 3.0)]);
+    let simplified  = polyline.simplify_douglas_peucker(0.1);
+
+    assert!(simplified.points() == &[Coord3(0.0, 0.0, 0.0), Coord3(0.0, 0.0, 3.0)]);
+    assert!((polyline.length() - 3.0).abs() < 0.001);
+}
@@ -0,0 +1,225 @@
+use flo_curves::*;
+use flo_curves::shape;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+fn assert_closed_clockwise_from(path: &SimpleBezierPath, start_point: Coord2) {
+    assert!(path.start_point().distance_to(&start_point) < 1e-9, "{:?} != {:?}", path.start_point(), start_point);
+    assert!(path.points().last().unwrap().2 == path.start_point());
+    assert!(path.is_clockwise());
+}
+
+fn assert_area(path: &SimpleBezierPath, expected: f64, tolerance: f64) {
+    let area = path_signed_area(path).abs();
+    assert!((area - expected).abs() < tolerance, "area {} != {}", area, expected);
+}
+
+#[test]
+fn rounded_rectangle_area_and_start() {
+    let rect = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(20.0, 10.0), 3.0);
+
+    assert_closed_clockwise_from(&rect, Coord2(3.0, 10.0));
+    assert_area(&rect, 200.0 - (4.0 - f64::consts::PI)*9.0, 0.01);
+}
+
+#[test]
+fn rounded_rectangle_points_are_on_the_corner_circles() {
+    let rect = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(20.0, 10.0), 3.0);
+
+    for curve in rect.to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+
+            // Inside the bounds
+            assert!(pos.x() >= -1e-6 && pos.x() <= 20.0+1e-6 && pos.y() >= -1e-6 && pos.y() <= 10.0+1e-6);
+
+            // In the corner regions, on the circle
+            let corner_center = Coord2(pos.x().max(3.0).min(17.0), pos.y().max(3.0).min(7.0));
+            if corner_center != pos && (corner_center.x() == 3.0 || corner_center.x() == 17.0) && (corner_center.y() == 3.0 || corner_center.y() == 7.0) {
+                assert!((pos.distance_to(&corner_center) - 3.0).abs() < 0.01, "{:?}", pos);
+            }
+        }
+    }
+}
+
+#[test]
+fn rectangle_with_no_radius_has_four_lines() {
+    let rect = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(10.0, 10.0), Coord2(0.0, 0.0), 0.0);
+
+    assert_closed_clockwise_from(&rect, Coord2(0.0, 10.0));
+    assert!(rect.points().count() == 4);
+    assert_area(&rect, 100.0, 1e-6);
+}
+
+#[test]
+fn rounded_rectangle_with_corner_radii() {
+    let rect = shape::rounded_rectangle_with_corner_radii::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(20.0, 10.0), (0.0, 2.0, 0.0, 4.0));
+
+    assert_closed_clockwise_from(&rect, Coord2(0.0, 10.0));
+    assert_area(&rect, 200.0 - (1.0 - f64::consts::PI/4.0)*(4.0 + 16.0), 0.01);
+
+    // Square corners are left exactly where they are
+    let points = rect.points().map(|(_, _, point)| point).collect::<Vec<_>>();
+    assert!(points.contains(&Coord2(20.0, 0.0)));
+}
+
+#[test]
+fn oversized_radii_are_scaled_to_fit() {
+    // Radius is clamped to half the height, making a pill shape
+    let pill = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(10.0, 4.0), 5.0);
+
+    assert_closed_clockwise_from(&pill, Coord2(2.0, 4.0));
+    assert_area(&pill, 40.0 - (4.0 - f64::consts::PI)*4.0, 0.01);
+
+    // Circle when the radius is bigger than everything
+    let circle = shape::rounded_rectangle::<SimpleBezierPath>(Coord2(0.0, 0.0), Coord2(10.0, 10.0), 100.0);
+    assert!(circle.points().count() == 4);
+    assert_area(&circle, f64::consts::PI*25.0, 0.05);
+}
+
+#[test]
+fn ellipse_area_and_points() {
+    let ellipse = shape::ellipse::<SimpleBezierPath>(Coord2(5.0, 5.0), 10.0, 4.0);
+
+    assert_closed_clockwise_from(&ellipse, Coord2(5.0, 9.0));
+    assert_area(&ellipse, f64::consts::PI*40.0, 0.1);
+
+    for curve in ellipse.to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos         = curve.point_at_pos((t as f64)/10.0) - Coord2(5.0, 5.0);
+            let on_ellipse  = (pos.x()/10.0).powi(2) + (pos.y()/4.0).powi(2);
+
+            assert!((on_ellipse - 1.0).abs() < 0.002, "{:?}", on_ellipse);
+        }
+    }
+}
+
+#[test]
+fn ellipse_matches_circle_convention() {
+    let ellipse = shape::ellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 5.0, 5.0);
+    let circle  = arc::Circle::new(Coord2(0.0, 0.0), 5.0).to_path::<SimpleBezierPath>();
+
+    assert!(ellipse.is_clockwise() == circle.is_clockwise());
+}
+
+#[test]
+fn regular_hexagon() {
+    let hexagon = shape::regular_polygon::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 6);
+
+    assert_closed_clockwise_from(&hexagon, Coord2(0.0, 10.0));
+    assert!(hexagon.points().count() == 6);
+    assert_area(&hexagon, 3.0*(3.0f64).sqrt()/2.0*100.0, 1e-6);
+
+    // Second vertex is 60 degrees clockwise
+    let second = hexagon.points().next().unwrap().2;
+    assert!(second.distance_to(&Coord2(10.0*(f64::consts::PI/3.0).sin(), 10.0*(f64::consts::PI/3.0).cos())) < 1e-9);
+}
+
+#[test]
+fn five_pointed_star() {
+    let star = shape::star::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 4.0, 5);
+
+    assert_closed_clockwise_from(&star, Coord2(0.0, 10.0));
+    assert!(star.points().count() == 10);
+
+    // 10 triangles with sides 10 and 4 and an angle of 36 degrees between them
+    assert_area(&star, 10.0 * 0.5 * 10.0 * 4.0 * (f64::consts::PI/5.0).sin(), 1e-6);
+
+    let points = star.points().map(|(_, _, point)| point.magnitude()).collect::<Vec<_>>();
+    for (idx, radius) in points.into_iter().enumerate() {
+        let expected = if idx % 2 == 0 { 4.0 } else { 10.0 };
+        assert!((radius - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn superellipse_with_exponent_2_is_ellipse() {
+    let shape = shape::superellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 5.0, 2.0, 0.01);
+
+    assert_closed_clockwise_from(&shape, Coord2(0.0, 5.0));
+    assert_area(&shape, f64::consts::PI*50.0, 0.2);
+}
+
+#[test]
+fn squircle_area_and_points() {
+    let shape = shape::superellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 10.0, 4.0, 0.01);
+
+    assert_closed_clockwise_from(&shape, Coord2(0.0, 10.0));
+
+    // Area of a superellipse is 4ab * gamma(1+1/n)^2 / gamma(1+2/n)
+    assert_area(&shape, 370.8149, 0.5);
+
+    for curve in shape.to_curves::<Curve<_>>() {
+        for t in 0..=10 {
+            let pos         = curve.point_at_pos((t as f64)/10.0);
+            let on_shape    = (pos.x()/10.0).abs().powi(4) + (pos.y()/10.0).abs().powi(4);
+
+            assert!((on_shape - 1.0).abs() < 0.02, "{:?} {:?}", pos, on_shape);
+        }
+    }
+}
+
+#[test]
+fn concave_superellipse_keeps_its_points() {
+    let shape   = shape::superellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 10.0, 0.5, 0.01);
+    let points  = shape.points().map(|(_, _, point)| point).collect::<Vec<_>>();
+
+    assert_closed_clockwise_from(&shape, Coord2(0.0, 10.0));
+    assert!(points.contains(&Coord2(10.0, 0.0)));
+    assert!(points.contains(&Coord2(0.0, -10.0)));
+    assert!(points.contains(&Coord2(-10.0, 0.0)));
+}
+
+#[test]
+fn quarter_pie_wedge() {
+    let wedge = shape::pie_wedge::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 0.0, f64::consts::PI/2.0);
+
+    assert_closed_clockwise_from(&wedge, Coord2(0.0, 0.0));
+    assert_area(&wedge, f64::consts::PI*25.0, 0.05);
+
+    let points = wedge.points().map(|(_, _, point)| point).collect::<Vec<_>>();
+    assert!(points[0].distance_to(&Coord2(0.0, 10.0)) < 1e-9);
+    assert!(points[1].distance_to(&Coord2(10.0, 0.0)) < 1e-9);
+}
+
+#[test]
+fn pie_wedge_angle_order_does_not_matter() {
+    let wedge1 = shape::pie_wedge::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 1.0, 4.0);
+    let wedge2 = shape::pie_wedge::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 4.0, 1.0);
+
+    assert!(wedge1.points().collect::<Vec<_>>() == wedge2.points().collect::<Vec<_>>());
+    assert!(wedge1.is_clockwise());
+    assert_area(&wedge1, 150.0, 0.5);
+}
+
+#[test]
+fn full_pie_wedge_is_circle() {
+    let wedge = shape::pie_wedge::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 0.0, 7.0);
+
+    assert_closed_clockwise_from(&wedge, Coord2(0.0, 10.0));
+    assert!(wedge.points().count() == 4);
+    assert_area(&wedge, f64::consts::PI*100.0, 0.2);
+}
+
+#[test]
+fn superellipse_with_non_positive_exponent_is_empty() {
+    for exponent in [0.0, -1.0, f64::NAN] {
+        let shape = shape::superellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 10.0, 10.0, exponent, 0.01);
+
+        assert!(shape.points().count() == 0, "{:?}", exponent);
+    }
+}
+
+#[test]
+fn thin_superellipse_does_not_turn_inside_out() {
+    for exponent in [0.05, 0.1, 0.15, 0.2] {
+        for max_error in [0.01, 0.1] {
+            let shape = shape::superellipse::<SimpleBezierPath>(Coord2(0.0, 0.0), 1.0, 1.0, exponent, max_error);
+
+            assert_closed_clockwise_from(&shape, Coord2(0.0, 1.0));
+            assert!(path_signed_area(&shape) <= 0.0, "{:?} {:?}", exponent, max_error);
+        }
+    }
+}